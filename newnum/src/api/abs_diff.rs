@@ -1,10 +1,15 @@
-use std::ops::Sub;
-
 /// Trait for `abs_diff` method, which computes the absolute difference between two numbers.
 /// `abs_diff` is equivalent to `(a - b).abs()`, not `a.abs() - b.abs()`.
 ///
 /// This trait is generic over `Rhs` which is default to `Self`, like multi-side operator traits.
-/// It is required that `Self` is `Sub<Rhs>` and `Rhs` is `Sub<Self>` where they both have the same output type.
+///
+/// `Output` is usually `Self`,
+/// but can be a different type when the difference may not be representable by `Self`
+/// (for example `NonZeroU32` has an `Output` of `u32` because the difference may be zero).
+///
+/// `AbsDiff` used to require `Self: Sub<Rhs>` and take its `Output` from `Sub`.
+/// It doesn't require `Sub` anymore, so implementations must set `Output` themselves
+/// (usually `type Output = Self;`).
 ///
 /// ### Example
 ///
@@ -12,17 +17,21 @@ use std::ops::Sub;
 /// use newnum::*;
 ///
 /// fn main() {
-///     assert(10.abs_diff(20) == 10);
-///     assert((-10).abs_diff(20) == 30);
+///     assert!(10.abs_diff(20) == 10);
+///     assert!((-10).abs_diff(20) == 30);
 /// }
 /// ```
-pub trait AbsDiff<Rhs: Sub<Self, Output = Self::Output> = Self>: Sized + Sub<Rhs> {
+pub trait AbsDiff<Rhs = Self>: Sized {
+    type Output;
+
     fn abs_diff(self, rhs: Rhs) -> Self::Output;
 }
 
 macro_rules! uint_impl {
     ($type:ident) => {
         impl AbsDiff for $type {
+            type Output = Self;
+
             #[inline(always)]
             fn abs_diff(self, rhs: Self) -> Self::Output {
                 if self > rhs {
@@ -45,6 +54,8 @@ macro_rules! signed_impl {
     ($($type:ty)*) => {
         $(
             impl AbsDiff for $type {
+                type Output = Self;
+
                 #[inline(always)]
                 fn abs_diff(self, rhs: Self) -> Self::Output {
                     (self - rhs).abs()
@@ -0,0 +1,144 @@
+use crate::Signed;
+
+/// Trait for bit-manipulation methods (`count_ones`, `leading_zeros`, `rotate_left`...) on integers.
+///
+/// The methods follow the std integer methods with the same names.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn bitset_len<T: IntPrim>(words: &[T]) -> u32 {
+///     words.iter().map(|word| word.count_ones()).sum()
+/// }
+///
+/// fn bitset_capacity<T: IntPrim>(words: &[T]) -> usize {
+///     words.len() * T::BITS as usize
+/// }
+///
+/// assert_eq!(bitset_len(&[0b1011_u8, 0b1]), 4);
+/// assert_eq!(bitset_capacity(&[0_u64; 3]), 192);
+/// ```
+pub trait Bits: Sized {
+    /// The size of the type in bits.
+    const BITS: u32;
+
+    fn count_ones(self) -> u32;
+    fn count_zeros(self) -> u32;
+    fn leading_zeros(self) -> u32;
+    fn trailing_zeros(self) -> u32;
+    fn leading_ones(self) -> u32;
+    fn trailing_ones(self) -> u32;
+
+    fn rotate_left(self, n: u32) -> Self;
+    fn rotate_right(self, n: u32) -> Self;
+
+    fn swap_bytes(self) -> Self;
+    fn reverse_bits(self) -> Self;
+
+    /// Returns `true` if `self` is a power of two. Negative numbers are never powers of two.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_power_of_two(self) -> bool;
+
+    /// Returns the smallest power of two greater than or equal to `self` (`1` for non-positive values).
+    ///
+    /// For unsigned types an overflow follows the std overflow behavior
+    /// (panics in debug mode and returns `0` in release mode).
+    /// For signed types an overflow always panics.
+    fn next_power_of_two(self) -> Self;
+
+    /// Returns the smallest power of two greater than or equal to `self` (`1` for non-positive values),
+    /// or `None` if it doesn't fit in `Self`.
+    fn checked_next_power_of_two(self) -> Option<Self>;
+}
+
+macro_rules! int_impl {
+    ($type:ident => $unsigned:ident) => {
+        impl Bits for $type {
+            const BITS: u32 = $type::BITS;
+
+            #[inline(always)]
+            fn count_ones(self) -> u32 {
+                $type::count_ones(self)
+            }
+            #[inline(always)]
+            fn count_zeros(self) -> u32 {
+                $type::count_zeros(self)
+            }
+            #[inline(always)]
+            fn leading_zeros(self) -> u32 {
+                $type::leading_zeros(self)
+            }
+            #[inline(always)]
+            fn trailing_zeros(self) -> u32 {
+                $type::trailing_zeros(self)
+            }
+            #[inline(always)]
+            fn leading_ones(self) -> u32 {
+                $type::leading_ones(self)
+            }
+            #[inline(always)]
+            fn trailing_ones(self) -> u32 {
+                $type::trailing_ones(self)
+            }
+
+            #[inline(always)]
+            fn rotate_left(self, n: u32) -> Self {
+                $type::rotate_left(self, n)
+            }
+            #[inline(always)]
+            fn rotate_right(self, n: u32) -> Self {
+                $type::rotate_right(self, n)
+            }
+
+            #[inline(always)]
+            fn swap_bytes(self) -> Self {
+                $type::swap_bytes(self)
+            }
+            #[inline(always)]
+            fn reverse_bits(self) -> Self {
+                $type::reverse_bits(self)
+            }
+
+            #[inline(always)]
+            fn is_power_of_two(self) -> bool {
+                Signed::is_positive(&self) && (self as $unsigned).is_power_of_two()
+            }
+
+            #[inline(always)]
+            fn next_power_of_two(self) -> Self {
+                if $type::MIN == 0 {
+                    (self as $unsigned).next_power_of_two() as $type
+                } else {
+                    Bits::checked_next_power_of_two(self)
+                        .expect("attempt to compute the next power of two with overflow")
+                }
+            }
+
+            #[inline(always)]
+            fn checked_next_power_of_two(self) -> Option<Self> {
+                if Signed::is_positive(&self) {
+                    (self as $unsigned)
+                        .checked_next_power_of_two()
+                        .filter(|power| *power <= $type::MAX as $unsigned)
+                        .map(|power| power as $type)
+                } else {
+                    Some(1)
+                }
+            }
+        }
+    };
+}
+int_impl!(u8 => u8);
+int_impl!(u16 => u16);
+int_impl!(u32 => u32);
+int_impl!(u64 => u64);
+int_impl!(u128 => u128);
+int_impl!(usize => usize);
+int_impl!(i8 => u8);
+int_impl!(i16 => u16);
+int_impl!(i32 => u32);
+int_impl!(i64 => u64);
+int_impl!(i128 => u128);
+int_impl!(isize => usize);
@@ -0,0 +1,180 @@
+use std::{error::Error, fmt::Display, slice};
+
+/// Trait for converting a number into its memory representation as a byte array,
+/// in little-endian (`to_le_bytes`), big-endian (`to_be_bytes`) or native (`to_ne_bytes`) byte order.
+///
+/// `Bytes` is a byte array (`[u8; N]`), or a [`ConcatBytes`] of byte arrays for generic number-containers
+/// because array lengths cannot depend on generic parameters.
+///
+/// For number-containers (like `Vec2`) the bytes of each element should be concatenated in field order,
+/// where each element is converted with the same byte order.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn write_all<T: Prim>(values: &[T], output: &mut Vec<u8>) {
+///     for value in values {
+///         output.extend_from_slice(value.to_le_bytes().as_ref());
+///     }
+/// }
+///
+/// let mut output = Vec::new();
+/// write_all(&[1_u16, 0x0203], &mut output);
+///
+/// assert_eq!(output, [1, 0, 3, 2]);
+/// ```
+pub trait ToBytes: Sized {
+    type Bytes: ByteArray;
+
+    fn to_le_bytes(self) -> Self::Bytes;
+    fn to_be_bytes(self) -> Self::Bytes;
+    fn to_ne_bytes(self) -> Self::Bytes;
+}
+
+/// Trait for creating a number from its memory representation as a byte array,
+/// in little-endian (`from_le_bytes`), big-endian (`from_be_bytes`) or native (`from_ne_bytes`) byte order.
+///
+/// For number-containers (like `Vec2`) the bytes should follow the layout of `ToBytes`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn read<T: Prim>(input: &[u8]) -> Option<T> {
+///     let bytes = input.get(..size_of::<T::Bytes>())?;
+///
+///     T::Bytes::try_from(bytes).ok().map(T::from_le_bytes)
+/// }
+///
+/// assert_eq!(read::<u16>(&[3, 2, 1]), Some(0x0203));
+/// assert_eq!(read::<f32>(&[0, 0, 128, 63]), Some(1.0));
+/// assert_eq!(read::<u64>(&[1, 2]), None);
+/// ```
+pub trait FromBytes: ToBytes {
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+}
+
+/// Trait for types that can be used as [`ToBytes::Bytes`]:
+/// `[u8; N]` and [`ConcatBytes`] of byte arrays.
+///
+/// # Safety
+///
+/// The type must have an alignment of `1`, no padding, and every bit pattern must be valid.
+/// `as_ref` and `as_mut` must return the entire memory of the value.
+pub unsafe trait ByteArray:
+    Copy + AsRef<[u8]> + AsMut<[u8]> + for<'a> TryFrom<&'a [u8]>
+{
+}
+
+unsafe impl<const N: usize> ByteArray for [u8; N] {}
+
+/// Two byte arrays laid out one after the other, which is itself a [`ByteArray`].
+///
+/// Used as the `Bytes` type of the `ToBytes` derive for generic number-containers,
+/// where `[u8; N]` can't be used because `N` would depend on generic parameters.
+/// For example the bytes of `Vec2<T> { x: T, y: T }` are `ConcatBytes<T::Bytes, ConcatBytes<T::Bytes, [u8; 0]>>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(C)]
+pub struct ConcatBytes<A, B>(pub A, pub B);
+
+/// The error returned when converting a slice with the wrong length into a [`ConcatBytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BytesLenError;
+
+impl Display for BytesLenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the slice length doesn't match the byte array length")
+    }
+}
+
+impl Error for BytesLenError {}
+
+// `A` and `B` have an alignment of `1` and no padding, so the `repr(C)` struct has neither.
+unsafe impl<A: ByteArray, B: ByteArray> ByteArray for ConcatBytes<A, B> {}
+
+impl<A: ByteArray, B: ByteArray> AsRef<[u8]> for ConcatBytes<A, B> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: `Self` is a `ByteArray` so its memory is `size_of::<Self>()` initialized bytes.
+        unsafe { slice::from_raw_parts(self as *const Self as *const u8, size_of::<Self>()) }
+    }
+}
+
+impl<A: ByteArray, B: ByteArray> AsMut<[u8]> for ConcatBytes<A, B> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `Self` is a `ByteArray` so its memory is `size_of::<Self>()` bytes, and any bit pattern is valid.
+        unsafe { slice::from_raw_parts_mut(self as *mut Self as *mut u8, size_of::<Self>()) }
+    }
+}
+
+impl<'a, A: ByteArray, B: ByteArray> TryFrom<&'a [u8]> for ConcatBytes<A, B> {
+    type Error = BytesLenError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        if value.len() != size_of::<Self>() {
+            return Err(BytesLenError);
+        }
+
+        let (a, b) = value.split_at(size_of::<A>());
+        match (A::try_from(a), B::try_from(b)) {
+            (Ok(a), Ok(b)) => Ok(Self(a, b)),
+            _ => Err(BytesLenError),
+        }
+    }
+}
+
+macro_rules! prim_impl {
+    ($type:ident) => {
+        impl ToBytes for $type {
+            type Bytes = [u8; size_of::<$type>()];
+
+            #[inline(always)]
+            fn to_le_bytes(self) -> Self::Bytes {
+                $type::to_le_bytes(self)
+            }
+            #[inline(always)]
+            fn to_be_bytes(self) -> Self::Bytes {
+                $type::to_be_bytes(self)
+            }
+            #[inline(always)]
+            fn to_ne_bytes(self) -> Self::Bytes {
+                $type::to_ne_bytes(self)
+            }
+        }
+
+        impl FromBytes for $type {
+            #[inline(always)]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                $type::from_le_bytes(bytes)
+            }
+            #[inline(always)]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                $type::from_be_bytes(bytes)
+            }
+            #[inline(always)]
+            fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+                $type::from_ne_bytes(bytes)
+            }
+        }
+    };
+}
+prim_impl!(u8);
+prim_impl!(u16);
+prim_impl!(u32);
+prim_impl!(u64);
+prim_impl!(u128);
+prim_impl!(usize);
+prim_impl!(i8);
+prim_impl!(i16);
+prim_impl!(i32);
+prim_impl!(i64);
+prim_impl!(i128);
+prim_impl!(isize);
+prim_impl!(f32);
+prim_impl!(f64);
@@ -0,0 +1,186 @@
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// Trait for the `checked_add` method, which returns `None` instead of overflowing.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn checked_sum<T: Int>(values: &[T]) -> Option<T> {
+///     values
+///         .iter()
+///         .try_fold(num!(0), |sum: T, value| CheckedAdd::checked_add(sum, value.clone()))
+/// }
+///
+/// assert_eq!(checked_sum(&[100_u8, 100]), Some(200));
+/// assert_eq!(checked_sum(&[100_u8, 100, 100]), None);
+/// ```
+pub trait CheckedAdd: Sized + Add<Output = Self> {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
+
+/// Trait for the `checked_sub` method, which returns `None` instead of overflowing.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+pub trait CheckedSub: Sized + Sub<Output = Self> {
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+}
+
+/// Trait for the `checked_mul` method, which returns `None` instead of overflowing.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+pub trait CheckedMul: Sized + Mul<Output = Self> {
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// Trait for the `checked_div` method,
+/// which returns `None` instead of overflowing or panicking when dividing by zero.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+pub trait CheckedDiv: Sized + Div<Output = Self> {
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+/// Trait for the `checked_rem` method,
+/// which returns `None` instead of overflowing or panicking when dividing by zero.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+pub trait CheckedRem: Sized + Rem<Output = Self> {
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+/// Trait for the `checked_neg` method, which returns `None` instead of overflowing.
+///
+/// Doesn't require `Neg` so it can be implemented by unsigned types,
+/// which return `None` for any non-zero value.
+///
+/// For floats `None` is returned if the result isn't finite.
+///
+/// For number-containers (like `Vec2`) `None` is returned if any of the elements overflows.
+pub trait CheckedNeg: Sized {
+    fn checked_neg(self) -> Option<Self>;
+}
+
+/// Trait for the `checked_shl` method,
+/// which returns `None` if `rhs` is larger than or equal to the number of bits in `self`.
+///
+/// For number-containers (like `Vec2`) each element is shifted by `rhs`,
+/// and `None` is returned if any of the elements overflows.
+pub trait CheckedShl: Sized {
+    fn checked_shl(self, rhs: u32) -> Option<Self>;
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl CheckedAdd for $type {
+            #[inline(always)]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                $type::checked_add(self, rhs)
+            }
+        }
+        impl CheckedSub for $type {
+            #[inline(always)]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                $type::checked_sub(self, rhs)
+            }
+        }
+        impl CheckedMul for $type {
+            #[inline(always)]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                $type::checked_mul(self, rhs)
+            }
+        }
+        impl CheckedDiv for $type {
+            #[inline(always)]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $type::checked_div(self, rhs)
+            }
+        }
+        impl CheckedRem for $type {
+            #[inline(always)]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                $type::checked_rem(self, rhs)
+            }
+        }
+        impl CheckedNeg for $type {
+            #[inline(always)]
+            fn checked_neg(self) -> Option<Self> {
+                $type::checked_neg(self)
+            }
+        }
+        impl CheckedShl for $type {
+            #[inline(always)]
+            fn checked_shl(self, rhs: u32) -> Option<Self> {
+                $type::checked_shl(self, rhs)
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl CheckedAdd for $type {
+            #[inline(always)]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs).filter(|output| output.is_finite())
+            }
+        }
+        impl CheckedSub for $type {
+            #[inline(always)]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(self - rhs).filter(|output| output.is_finite())
+            }
+        }
+        impl CheckedMul for $type {
+            #[inline(always)]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs).filter(|output| output.is_finite())
+            }
+        }
+        impl CheckedDiv for $type {
+            #[inline(always)]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                Some(self / rhs).filter(|output| output.is_finite())
+            }
+        }
+        impl CheckedRem for $type {
+            #[inline(always)]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                Some(self % rhs).filter(|output| output.is_finite())
+            }
+        }
+        impl CheckedNeg for $type {
+            #[inline(always)]
+            fn checked_neg(self) -> Option<Self> {
+                Some(-self).filter(|output| output.is_finite())
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
@@ -0,0 +1,130 @@
+use crate::Positive;
+
+/// Trait for the `conj` method, which computes the complex conjugate (`re - im * i` for `re + im * i`).
+///
+/// Real numbers are their own conjugate.
+///
+/// For non-numbers types (number-containers like `Vec2`) each element should be mapped seperately.
+pub trait Conj {
+    fn conj(self) -> Self;
+}
+
+/// Trait for the `norm` and `norm_sqr` methods,
+/// which compute the magnitude of a number (its distance from zero in the complex plane).
+///
+/// For real numbers `norm` is `abs` and `norm_sqr` is `self * self`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// assert_eq!(Complex::new(3.0, -4.0).norm(), 5.0);
+/// assert_eq!(Complex::new(3.0, -4.0).norm_sqr(), 25.0);
+/// assert_eq!((-3.0).norm(), 3.0);
+/// ```
+///
+/// For non-numbers types (number-containers like `Vec2`) each element should be mapped seperately.
+pub trait Norm {
+    /// The output of `norm` and `norm_sqr`.
+    ///
+    /// For real numbers `Output` is expected to be `Self`,
+    /// and for complex numbers it is expected to be the real type.
+    type Output;
+
+    /// Computes `|self|`.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn norm(self) -> Self::Output;
+
+    /// Computes `|self|^2`, which doesn't need a square root.
+    fn norm_sqr(self) -> Self::Output;
+}
+
+/// Trait for the `arg` method, which computes the argument of a number
+/// (its angle from the positive real axis in the complex plane, in radians).
+///
+/// The argument is in the range `[-π, π]`.
+/// For real numbers it is `0` for positive numbers and `π` for negative numbers.
+///
+/// For non-numbers types (number-containers like `Vec2`) each element should be mapped seperately.
+pub trait Arg {
+    /// The output of `arg`.
+    ///
+    /// For real numbers `Output` is expected to be `Self`,
+    /// and for complex numbers it is expected to be the real type.
+    type Output;
+
+    /// Computes the argument of `self` (in radians).
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn arg(self) -> Self::Output;
+}
+
+//
+//
+//
+// IMPLEMENTATIONS
+//
+//
+//
+
+macro_rules! real_impl {
+    ($type:ident) => {
+        impl Conj for $type {
+            #[inline(always)]
+            fn conj(self) -> Self {
+                self
+            }
+        }
+
+        impl Norm for $type {
+            type Output = Self;
+
+            #[inline(always)]
+            fn norm(self) -> Self::Output {
+                Positive::abs(self)
+            }
+            #[inline(always)]
+            fn norm_sqr(self) -> Self::Output {
+                self * self
+            }
+        }
+    };
+}
+real_impl!(u8);
+real_impl!(u16);
+real_impl!(u32);
+real_impl!(u64);
+real_impl!(u128);
+real_impl!(usize);
+real_impl!(i8);
+real_impl!(i16);
+real_impl!(i32);
+real_impl!(i64);
+real_impl!(i128);
+real_impl!(isize);
+real_impl!(f32);
+real_impl!(f64);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        /// `-0.0` is treated as negative (like `atan2(0.0, -0.0)`), and `NaN` returns `NaN`.
+        impl Arg for $type {
+            type Output = Self;
+
+            #[inline(always)]
+            fn arg(self) -> Self::Output {
+                if self.is_nan() {
+                    self
+                } else if self.is_sign_negative() {
+                    std::$type::consts::PI
+                } else {
+                    0.0
+                }
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
@@ -0,0 +1,198 @@
+use std::ops::{Div, Rem};
+
+/// Trait for the `div_euclid` method, which computes the euclidean quotient of `self / rhs`.
+///
+/// The euclidean quotient `q` is such that `self = q * rhs + r` where `r = self.rem_euclid(rhs)` is never negative.
+///
+/// For number-containers (like `Vec2`) the logic of `div_euclid` should follow the logic of `Div`.
+/// For example, if `<Vec2 as Div>` divides each component seperately, <Vec2 as DivEuclid> should also `div_euclid` each component seperately.
+pub trait DivEuclid: Sized + Div<Output = Self> {
+    fn div_euclid(self, rhs: Self) -> Self;
+}
+
+/// Trait for the `rem_euclid` method, which computes the non-negative remainder of `self / rhs`.
+///
+/// For number-containers (like `Vec2`) the logic of `rem_euclid` should follow the logic of `Rem`.
+/// For example, if `<Vec2 as Rem>` computes each component seperately, <Vec2 as RemEuclid> should also `rem_euclid` each component seperately.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn wrap_index<T: Prim>(index: T, len: T) -> T {
+///     index.rem_euclid(len)
+/// }
+///
+/// assert_eq!(wrap_index(-1_i32, 4), 3);
+/// assert_eq!(wrap_index(-0.5_f64, 4.0), 3.5);
+/// ```
+pub trait RemEuclid: Sized + Rem<Output = Self> {
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// Trait for the `div_floor` method, which computes the quotient of `self / rhs` rounded towards negative infinity.
+///
+/// For number-containers (like `Vec2`) the logic of `div_floor` should follow the logic of `Div`.
+/// For example, if `<Vec2 as Div>` divides each component seperately, <Vec2 as DivFloor> should also `div_floor` each component seperately.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn tile_coord<T: Prim>(position: T, tile_size: T) -> T {
+///     position.div_floor(tile_size)
+/// }
+///
+/// assert_eq!(tile_coord(-1_i32, 16), -1);
+/// assert_eq!(tile_coord(-1.0_f64, 16.0), -1.0);
+/// assert_eq!(tile_coord(17_i32, 16), 1);
+/// ```
+pub trait DivFloor: Sized + Div<Output = Self> {
+    fn div_floor(self, rhs: Self) -> Self;
+}
+
+/// Trait for the `div_ceil` method, which computes the quotient of `self / rhs` rounded towards positive infinity.
+///
+/// For number-containers (like `Vec2`) the logic of `div_ceil` should follow the logic of `Div`.
+/// For example, if `<Vec2 as Div>` divides each component seperately, <Vec2 as DivCeil> should also `div_ceil` each component seperately.
+pub trait DivCeil: Sized + Div<Output = Self> {
+    fn div_ceil(self, rhs: Self) -> Self;
+}
+
+/// Trait for the `div_rem` method, which computes both the quotient and the remainder of `self / rhs`.
+///
+/// The quotient is truncated like `Div` for integers, so `div_rem` returns `(self / rhs, self % rhs)`.
+/// For floats the quotient is truncated too so that `self = q * rhs + r` still holds (up to rounding).
+///
+/// For number-containers (like `Vec2`) the logic of `div_rem` should follow the logic of `Div` and `Rem`.
+pub trait DivRem: Sized + Div<Output = Self> + Rem<Output = Self> {
+    fn div_rem(self, rhs: Self) -> (Self, Self);
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl DivEuclid for $type {
+            #[inline(always)]
+            fn div_euclid(self, rhs: Self) -> Self {
+                $type::div_euclid(self, rhs)
+            }
+        }
+        impl RemEuclid for $type {
+            #[inline(always)]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $type::rem_euclid(self, rhs)
+            }
+        }
+        impl DivRem for $type {
+            #[inline(always)]
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                (self / rhs, self % rhs)
+            }
+        }
+    };
+}
+
+macro_rules! uint_impl {
+    ($type:ident) => {
+        int_impl!($type);
+
+        impl DivFloor for $type {
+            #[inline(always)]
+            fn div_floor(self, rhs: Self) -> Self {
+                self / rhs
+            }
+        }
+        impl DivCeil for $type {
+            #[inline(always)]
+            fn div_ceil(self, rhs: Self) -> Self {
+                $type::div_ceil(self, rhs)
+            }
+        }
+    };
+}
+uint_impl!(u8);
+uint_impl!(u16);
+uint_impl!(u32);
+uint_impl!(u64);
+uint_impl!(u128);
+uint_impl!(usize);
+
+macro_rules! sint_impl {
+    ($type:ident) => {
+        int_impl!($type);
+
+        impl DivFloor for $type {
+            #[inline(always)]
+            fn div_floor(self, rhs: Self) -> Self {
+                let quotient = self / rhs;
+                let remainder = self % rhs;
+
+                // Division truncates towards zero, which is above the floor when the exact quotient is negative.
+                if remainder != 0 && ((remainder < 0) != (rhs < 0)) {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            }
+        }
+        impl DivCeil for $type {
+            #[inline(always)]
+            fn div_ceil(self, rhs: Self) -> Self {
+                let quotient = self / rhs;
+                let remainder = self % rhs;
+
+                // Division truncates towards zero, which is below the ceil when the exact quotient is positive.
+                if remainder != 0 && ((remainder > 0) == (rhs > 0)) {
+                    quotient + 1
+                } else {
+                    quotient
+                }
+            }
+        }
+    };
+}
+sint_impl!(i8);
+sint_impl!(i16);
+sint_impl!(i32);
+sint_impl!(i64);
+sint_impl!(i128);
+sint_impl!(isize);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl DivEuclid for $type {
+            #[inline(always)]
+            fn div_euclid(self, rhs: Self) -> Self {
+                $type::div_euclid(self, rhs)
+            }
+        }
+        impl RemEuclid for $type {
+            #[inline(always)]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $type::rem_euclid(self, rhs)
+            }
+        }
+        impl DivFloor for $type {
+            #[inline(always)]
+            fn div_floor(self, rhs: Self) -> Self {
+                (self / rhs).floor()
+            }
+        }
+        impl DivCeil for $type {
+            #[inline(always)]
+            fn div_ceil(self, rhs: Self) -> Self {
+                (self / rhs).ceil()
+            }
+        }
+        impl DivRem for $type {
+            #[inline(always)]
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                ((self / rhs).trunc(), self % rhs)
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
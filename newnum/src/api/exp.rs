@@ -0,0 +1,40 @@
+/// Trait for exponential functions (`exp`, `exp2`, `exp_m1`).
+///
+/// For non-numbers types (number-containers like `Vec2`) each element should be mapped seperately.
+pub trait Exp {
+    /// Computes `e^self`.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn exp(self) -> Self;
+
+    /// Computes `2^self`.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn exp2(self) -> Self;
+
+    /// Computes `e^self - 1` in a way that is accurate even if `self` is close to zero.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn exp_m1(self) -> Self;
+}
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl Exp for $type {
+            #[inline(always)]
+            fn exp(self) -> Self {
+                self.exp()
+            }
+            #[inline(always)]
+            fn exp2(self) -> Self {
+                self.exp2()
+            }
+            #[inline(always)]
+            fn exp_m1(self) -> Self {
+                self.exp_m1()
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
@@ -0,0 +1,118 @@
+use std::num::FpCategory;
+
+/// Trait for IEEE-754 classification methods (`is_nan`, `is_finite`, `classify`...).
+///
+/// The methods return mapped types (`Self::FloatBoolMapped`, `Self::CategoryMapped`),
+/// so number-containers can return a result for each element like `Signed` does.
+/// For example `Vec2` could return `BVec2` for `is_nan`.
+///
+/// The mapped types are named differently from `Signed::BoolMapped`,
+/// so that `T::BoolMapped` stays unambiguous for types that implement both traits.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn validate<T: Float>(values: &[T]) -> Result<(), usize> {
+///     match values.iter().position(|value| !value.is_finite()) {
+///         Some(index) => Err(index),
+///         None => Ok(()),
+///     }
+/// }
+///
+/// assert_eq!(validate(&[1.0_f32, 2.0]), Ok(()));
+/// assert_eq!(validate(&[1.0_f64, f64::NAN]), Err(1));
+/// ```
+pub trait FloatClass {
+    type FloatBoolMapped;
+    type CategoryMapped;
+
+    fn is_nan(&self) -> Self::FloatBoolMapped;
+    fn is_finite(&self) -> Self::FloatBoolMapped;
+    fn is_infinite(&self) -> Self::FloatBoolMapped;
+    fn is_normal(&self) -> Self::FloatBoolMapped;
+    fn is_subnormal(&self) -> Self::FloatBoolMapped;
+
+    /// Returns `true` if the sign bit is not set, including for `+0.0` and `NaN`s with a positive sign bit.
+    fn is_sign_positive(&self) -> Self::FloatBoolMapped;
+    /// Returns `true` if the sign bit is set, including for `-0.0` and `NaN`s with a negative sign bit.
+    fn is_sign_negative(&self) -> Self::FloatBoolMapped;
+
+    fn classify(&self) -> Self::CategoryMapped;
+}
+
+/// Trait for the IEEE-754 constants of a float type (`NAN`, `INFINITY`, `EPSILON`...).
+///
+/// For number-containers (like `Vec2`) each constant should have the same value in each element.
+pub trait FloatConsts {
+    const NAN: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    /// The difference between `1.0` and the next larger representable number.
+    const EPSILON: Self;
+    /// The smallest positive normal value.
+    const MIN_POSITIVE: Self;
+    /// The largest finite value.
+    const MAX: Self;
+    /// The smallest finite value.
+    const MIN: Self;
+}
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl FloatClass for $type {
+            type FloatBoolMapped = bool;
+            type CategoryMapped = FpCategory;
+
+            #[inline(always)]
+            fn is_nan(&self) -> Self::FloatBoolMapped {
+                $type::is_nan(*self)
+            }
+            #[inline(always)]
+            fn is_finite(&self) -> Self::FloatBoolMapped {
+                $type::is_finite(*self)
+            }
+            #[inline(always)]
+            fn is_infinite(&self) -> Self::FloatBoolMapped {
+                $type::is_infinite(*self)
+            }
+            #[inline(always)]
+            fn is_normal(&self) -> Self::FloatBoolMapped {
+                $type::is_normal(*self)
+            }
+            #[inline(always)]
+            fn is_subnormal(&self) -> Self::FloatBoolMapped {
+                $type::is_subnormal(*self)
+            }
+
+            #[inline(always)]
+            fn is_sign_positive(&self) -> Self::FloatBoolMapped {
+                $type::is_sign_positive(*self)
+            }
+            #[inline(always)]
+            fn is_sign_negative(&self) -> Self::FloatBoolMapped {
+                $type::is_sign_negative(*self)
+            }
+
+            #[inline(always)]
+            fn classify(&self) -> Self::CategoryMapped {
+                $type::classify(*self)
+            }
+        }
+
+        impl FloatConsts for $type {
+            const NAN: Self = $type::NAN;
+            const INFINITY: Self = $type::INFINITY;
+            const NEG_INFINITY: Self = $type::NEG_INFINITY;
+
+            const EPSILON: Self = $type::EPSILON;
+            const MIN_POSITIVE: Self = $type::MIN_POSITIVE;
+            const MAX: Self = $type::MAX;
+            const MIN: Self = $type::MIN;
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
@@ -36,8 +36,23 @@ pub trait FromIntLiteral: Sized {
     /// but looses integer precision after `16_777_216`.
     const MAX_APPROX_LITERAL: i128 = Self::MAX_LITERAL;
 
+    /// Whether the literal `0` (or `0.0`) can be converted to `Self`.
+    ///
+    /// This is set to `true` on default,
+    /// and should be set to `false` by non-zero types with a literal range around zero (like `NonZero<i32>`),
+    /// so that the [`num`] macro rejects a zero literal at compile time.
+    ///
+    /// ```compile_fail
+    /// use newnum::*;
+    ///
+    /// let value: std::num::NonZero<i32> = num!(0);
+    /// ```
+    const ZERO_LITERAL: bool = true;
+
     /// Used by the [`num`] macro to convert integer literals to `Self`.
     ///
+    /// # Safety
+    ///
     /// Is unsafe because the caller must ensure that the value is between `Self::MIN_LITERAL` and `Self::MAX_LITERAL`.
     /// The fn is still expected to not cause undefined behavior if the value is out of range,
     /// because the fn is only unsafe to prevent it from being called manually.
@@ -45,6 +60,8 @@ pub trait FromIntLiteral: Sized {
 
     /// Used by the [`num_approx`] macro to convert integer literals to `Self`.
     ///
+    /// # Safety
+    ///
     /// Is unsafe because the caller must ensure that the value is between `Self::MIN_APPROX_LITERAL` and `Self::MAX_APPROX_LITERAL`.
     /// The fn is still expected to not cause undefined behavior if the value is out of range,
     /// because the fn is only unsafe to prevent it from being called manually.
@@ -52,20 +69,167 @@ pub trait FromIntLiteral: Sized {
 }
 
 /// Used by the [`num`] macro to convert float literals to `Self`.
+#[allow(clippy::approx_constant)]
 pub trait FromFloatLiteral: FromIntLiteral {
+    /// The minimum float literal that can be converted to `Self`.
+    ///
+    /// This is set to `Self::MIN_LITERAL` on default,
+    /// and should be overridden by types that can represent fractions beyond their integer range.
+    const MIN_FLOAT_LITERAL: f64 = Self::MIN_LITERAL as f64;
+    /// The maximum float literal that can be converted to `Self`.
+    ///
+    /// This is set to `Self::MAX_LITERAL` on default,
+    /// and should be overridden by types that can represent fractions beyond their integer range.
+    const MAX_FLOAT_LITERAL: f64 = Self::MAX_LITERAL as f64;
+
     /// Used by the [`num`] macro to convert float literals to `Self`.
     ///
-    /// Is unsafe because the caller must ensure that the value is between `Self::MIN_LITERAL` and `Self::MAX_LITERAL`.
+    /// # Safety
+    ///
+    /// Is unsafe because the caller must ensure that the value is between `Self::MIN_FLOAT_LITERAL` and `Self::MAX_FLOAT_LITERAL`.
     unsafe fn from_float_literal(value: f64) -> Self;
 
+    /// Used by the [`num`] macro to convert float literals to `Self`,
+    /// given both the `f64` value of the literal and its exact decimal value as `digits * 10^exponent`.
+    /// Literals with more than 38 significant digits are rounded to 38 digits.
+    ///
+    /// This is set to `Self::from_float_literal(value)` on default,
+    /// and should be overridden by types that can represent decimal fractions exactly (like `Ratio`).
+    ///
+    /// # Safety
+    ///
+    /// Is unsafe because the caller must ensure that the value is between `Self::MIN_FLOAT_LITERAL` and `Self::MAX_FLOAT_LITERAL`.
+    #[inline(always)]
+    unsafe fn from_decimal_literal(value: f64, digits: i128, exponent: i32) -> Self {
+        let _ = (digits, exponent);
+
+        Self::from_float_literal(value)
+    }
+
     /// Used by the [`num_approx`] macro to convert float literals to `Self`.
     ///
+    /// # Safety
+    ///
     /// Is unsafe because the caller must ensure that the value is between `Self::MIN_APPROX_LITERAL` and `Self::MAX_APPROX_LITERAL`.
     unsafe fn approx_from_float_literal(value: f64) -> Self;
 
+    /// Archimedes' constant (π).
     fn pi() -> Self {
         internal_num!(3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117067982148086513282306647093844609550582231725359408128481117450284102701938521105559644622948954930381964428810975665933446128475648233786783165271201909145648566923460348610454326648213393607260249141273724587006606315588174881520920962829254091715364367892590360011330530548820466521384146951941511609433057270365759591953092186117381932611793105118548074462379962749567351885752724891227938183011949128831426076896280457)
     }
+
+    /// The full circle constant (τ = 2π).
+    fn tau() -> Self {
+        internal_num!(6.28318530717958647692528676655900576839433879875021)
+    }
+
+    /// π/2.
+    fn frac_pi_2() -> Self {
+        internal_num!(1.57079632679489661923132169163975144209858469968755)
+    }
+
+    /// π/3.
+    fn frac_pi_3() -> Self {
+        internal_num!(1.04719755119659774615421446109316762806572313312503)
+    }
+
+    /// π/4.
+    fn frac_pi_4() -> Self {
+        internal_num!(0.78539816339744830961566084581987572104929234984377)
+    }
+
+    /// π/6.
+    fn frac_pi_6() -> Self {
+        internal_num!(0.52359877559829887307710723054658381403286156656251)
+    }
+
+    /// π/8.
+    fn frac_pi_8() -> Self {
+        internal_num!(0.39269908169872415480783042290993786052464617492188)
+    }
+
+    /// 1/π.
+    fn frac_1_pi() -> Self {
+        internal_num!(0.31830988618379067153776752674502872406891929148091)
+    }
+
+    /// 2/π.
+    fn frac_2_pi() -> Self {
+        internal_num!(0.63661977236758134307553505349005744813783858296182)
+    }
+
+    /// 2/sqrt(π).
+    fn frac_2_sqrt_pi() -> Self {
+        internal_num!(1.12837916709551257389615890312154517168810125865799)
+    }
+
+    /// sqrt(2).
+    fn sqrt_2() -> Self {
+        internal_num!(1.41421356237309504880168872420969807856967187537694)
+    }
+
+    /// 1/sqrt(2).
+    fn frac_1_sqrt_2() -> Self {
+        internal_num!(0.70710678118654752440084436210484903928483593768847)
+    }
+
+    /// Euler's number (e).
+    fn e() -> Self {
+        internal_num!(2.71828182845904523536028747135266249775724709369995)
+    }
+
+    /// ln(2).
+    fn ln_2() -> Self {
+        internal_num!(0.69314718055994530941723212145817656807550013436025)
+    }
+
+    /// ln(10).
+    fn ln_10() -> Self {
+        internal_num!(2.30258509299404568401799145468436420760110148862877)
+    }
+
+    /// log2(e).
+    fn log2_e() -> Self {
+        internal_num!(1.44269504088896340735992468100189213742664595415298)
+    }
+
+    /// log10(e).
+    fn log10_e() -> Self {
+        internal_num!(0.43429448190325182765112891891660508229439700580366)
+    }
+
+    /// log2(10).
+    fn log2_10() -> Self {
+        internal_num!(3.32192809488736234787031942948939017586483139302458)
+    }
+
+    /// log10(2).
+    fn log10_2() -> Self {
+        internal_num!(0.30102999566398119521373889472449302676818988146210)
+    }
+
+    /// The golden ratio (φ = (1 + sqrt(5)) / 2).
+    fn golden_ratio() -> Self {
+        internal_num!(1.61803398874989484820458683436563811772030917980576)
+    }
+}
+
+/// Used by the [`num`] macro to convert integer literals beyond the range of `i128` to `Self`,
+/// which is only possible for unbounded types like `BigInt` (behind the `bigint` feature).
+///
+/// The `num` macro only accepts such literals if `Self::MAX_LITERAL` is `i128::MAX` (for positive literals)
+/// or if `Self::MIN_LITERAL` is `i128::MIN` (for negative literals),
+/// so negative literals for an unsigned big integer are still a compile-time error.
+pub trait FromBigIntLiteral: FromIntLiteral {
+    /// Used by the [`num`] macro to convert integer literals beyond the range of `i128` to `Self`.
+    /// `magnitude` is the absolute value of the literal as little-endian `u64` limbs.
+    ///
+    /// # Safety
+    ///
+    /// Is unsafe because the caller must ensure that the literal is in the range of `Self`, as described in the trait docs.
+    /// The fn is still expected to not cause undefined behavior if the value is out of range,
+    /// because the fn is only unsafe to prevent it from being called manually.
+    unsafe fn from_big_int_literal(negative: bool, magnitude: &[u64]) -> Self;
 }
 
 macro_rules! int_impl {
@@ -110,9 +274,9 @@ impl FromIntLiteral for u128 {
 }
 
 impl FromIntLiteral for f32 {
-    const MIN_LITERAL: i128 = 0;
+    const MIN_LITERAL: i128 = -16_777_216; // -2^24
     const MAX_LITERAL: i128 = 16_777_216; // 2^24
-    const MIN_APPROX_LITERAL: i128 = 0;
+    const MIN_APPROX_LITERAL: i128 = Self::MIN as i128;
     const MAX_APPROX_LITERAL: i128 = Self::MAX as i128;
 
     unsafe fn from_int_literal(value: i128) -> Self {
@@ -125,9 +289,9 @@ impl FromIntLiteral for f32 {
 }
 
 impl FromIntLiteral for f64 {
-    const MIN_LITERAL: i128 = 0;
+    const MIN_LITERAL: i128 = -9_007_199_254_740_992; // -2^53
     const MAX_LITERAL: i128 = 9_007_199_254_740_992; // 2^53
-    const MIN_APPROX_LITERAL: i128 = 0;
+    const MIN_APPROX_LITERAL: i128 = i128::MIN; // f64 can represent all i128 values
     const MAX_APPROX_LITERAL: i128 = i128::MAX; // f64 can represent all u128 values
 
     unsafe fn from_int_literal(value: i128) -> Self {
@@ -140,6 +304,9 @@ impl FromIntLiteral for f64 {
 }
 
 impl FromFloatLiteral for f32 {
+    const MIN_FLOAT_LITERAL: f64 = Self::MIN as f64;
+    const MAX_FLOAT_LITERAL: f64 = Self::MAX as f64;
+
     unsafe fn from_float_literal(value: f64) -> Self {
         value as Self
     }
@@ -150,6 +317,9 @@ impl FromFloatLiteral for f32 {
 }
 
 impl FromFloatLiteral for f64 {
+    const MIN_FLOAT_LITERAL: f64 = Self::MIN;
+    const MAX_FLOAT_LITERAL: f64 = Self::MAX;
+
     unsafe fn from_float_literal(value: f64) -> Self {
         value
     }
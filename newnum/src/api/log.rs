@@ -0,0 +1,147 @@
+/// Trait for logarithm functions (`ln`, `log2`, `log10`...).
+///
+/// For non-numbers types (number-containers like `Vec2`) each element should be mapped seperately.
+///
+/// * For integer logarithms, use [`ILog`].
+pub trait Log {
+    /// Computes the natural logarithm (base `e`).
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn ln(self) -> Self;
+
+    /// Computes the base `2` logarithm.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn log2(self) -> Self;
+
+    /// Computes the base `10` logarithm.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn log10(self) -> Self;
+
+    /// Computes the logarithm with respect to an arbitrary base.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn log(self, base: Self) -> Self;
+
+    /// Computes `ln(1 + self)` in a way that is accurate even if `self` is close to zero.
+    ///
+    /// The precision of this function might be non-deterministic based on the type.
+    fn ln_1p(self) -> Self;
+}
+
+/// Trait for integer logarithms (`ilog2`, `ilog10`...),
+/// which return the logarithm rounded down as a `u32`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn digit_count<T: Int>(value: T) -> u32 {
+///     value.checked_ilog10().map_or(1, |log| log + 1)
+/// }
+///
+/// assert_eq!(digit_count(0_u8), 1);
+/// assert_eq!(digit_count(12345_u32), 5);
+/// ```
+pub trait ILog: Sized {
+    /// Returns the base `2` logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` isn't positive.
+    fn ilog2(self) -> u32;
+
+    /// Returns the base `10` logarithm of `self`, rounded down.
+    ///
+    /// Panics if `self` isn't positive.
+    fn ilog10(self) -> u32;
+
+    /// Returns the logarithm of `self` with respect to an arbitrary base, rounded down.
+    ///
+    /// Panics if `self` isn't positive or if `base` is less than `2`.
+    fn ilog(self, base: Self) -> u32;
+
+    /// Returns the base `2` logarithm of `self`, rounded down,
+    /// or `None` if `self` isn't positive.
+    fn checked_ilog2(self) -> Option<u32>;
+
+    /// Returns the base `10` logarithm of `self`, rounded down,
+    /// or `None` if `self` isn't positive.
+    fn checked_ilog10(self) -> Option<u32>;
+
+    /// Returns the logarithm of `self` with respect to an arbitrary base, rounded down,
+    /// or `None` if `self` isn't positive or if `base` is less than `2`.
+    fn checked_ilog(self, base: Self) -> Option<u32>;
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl ILog for $type {
+            #[inline(always)]
+            fn ilog2(self) -> u32 {
+                $type::ilog2(self)
+            }
+            #[inline(always)]
+            fn ilog10(self) -> u32 {
+                $type::ilog10(self)
+            }
+            #[inline(always)]
+            fn ilog(self, base: Self) -> u32 {
+                $type::ilog(self, base)
+            }
+            #[inline(always)]
+            fn checked_ilog2(self) -> Option<u32> {
+                $type::checked_ilog2(self)
+            }
+            #[inline(always)]
+            fn checked_ilog10(self) -> Option<u32> {
+                $type::checked_ilog10(self)
+            }
+            #[inline(always)]
+            fn checked_ilog(self, base: Self) -> Option<u32> {
+                $type::checked_ilog(self, base)
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl Log for $type {
+            #[inline(always)]
+            fn ln(self) -> Self {
+                self.ln()
+            }
+            #[inline(always)]
+            fn log2(self) -> Self {
+                self.log2()
+            }
+            #[inline(always)]
+            fn log10(self) -> Self {
+                self.log10()
+            }
+            #[inline(always)]
+            fn log(self, base: Self) -> Self {
+                self.log(base)
+            }
+            #[inline(always)]
+            fn ln_1p(self) -> Self {
+                self.ln_1p()
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
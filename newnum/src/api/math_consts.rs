@@ -0,0 +1,92 @@
+/// Mathematical constants as associated constants, for use in `const` contexts.
+///
+/// For generic code that isn't `const`, the constant methods of `FromFloatLiteral` (`pi()`, `e()`...)
+/// also work for custom float types that only implement `FromFloatLiteral`.
+/// Each constant must be equal to the output of the matching `FromFloatLiteral` method.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// const FULL_TURN: f32 = f32::TAU;
+///
+/// fn degrees_to_radians<T: Float>(degrees: T) -> T {
+///     degrees * (T::PI / num!(180))
+/// }
+///
+/// assert_eq!(FULL_TURN, std::f32::consts::TAU);
+/// assert_eq!(degrees_to_radians(180.0_f64), std::f64::consts::PI);
+/// ```
+pub trait MathConsts {
+    /// Archimedes' constant (π).
+    const PI: Self;
+    /// The full circle constant (τ = 2π).
+    const TAU: Self;
+    /// π/2.
+    const FRAC_PI_2: Self;
+    /// π/3.
+    const FRAC_PI_3: Self;
+    /// π/4.
+    const FRAC_PI_4: Self;
+    /// π/6.
+    const FRAC_PI_6: Self;
+    /// π/8.
+    const FRAC_PI_8: Self;
+    /// 1/π.
+    const FRAC_1_PI: Self;
+    /// 2/π.
+    const FRAC_2_PI: Self;
+    /// 2/sqrt(π).
+    const FRAC_2_SQRT_PI: Self;
+    /// sqrt(2).
+    const SQRT_2: Self;
+    /// 1/sqrt(2).
+    const FRAC_1_SQRT_2: Self;
+    /// Euler's number (e).
+    const E: Self;
+    /// ln(2).
+    const LN_2: Self;
+    /// ln(10).
+    const LN_10: Self;
+    /// log2(e).
+    const LOG2_E: Self;
+    /// log10(e).
+    const LOG10_E: Self;
+    /// log2(10).
+    const LOG2_10: Self;
+    /// log10(2).
+    const LOG10_2: Self;
+    /// The golden ratio (φ = (1 + sqrt(5)) / 2).
+    const GOLDEN_RATIO: Self;
+}
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl MathConsts for $type {
+            const PI: Self = std::$type::consts::PI;
+            const TAU: Self = std::$type::consts::TAU;
+            const FRAC_PI_2: Self = std::$type::consts::FRAC_PI_2;
+            const FRAC_PI_3: Self = std::$type::consts::FRAC_PI_3;
+            const FRAC_PI_4: Self = std::$type::consts::FRAC_PI_4;
+            const FRAC_PI_6: Self = std::$type::consts::FRAC_PI_6;
+            const FRAC_PI_8: Self = std::$type::consts::FRAC_PI_8;
+            const FRAC_1_PI: Self = std::$type::consts::FRAC_1_PI;
+            const FRAC_2_PI: Self = std::$type::consts::FRAC_2_PI;
+            const FRAC_2_SQRT_PI: Self = std::$type::consts::FRAC_2_SQRT_PI;
+            const SQRT_2: Self = std::$type::consts::SQRT_2;
+            const FRAC_1_SQRT_2: Self = std::$type::consts::FRAC_1_SQRT_2;
+            const E: Self = std::$type::consts::E;
+            const LN_2: Self = std::$type::consts::LN_2;
+            const LN_10: Self = std::$type::consts::LN_10;
+            const LOG2_E: Self = std::$type::consts::LOG2_E;
+            const LOG10_E: Self = std::$type::consts::LOG10_E;
+            const LOG2_10: Self = std::$type::consts::LOG2_10;
+            const LOG10_2: Self = std::$type::consts::LOG10_2;
+            #[allow(clippy::excessive_precision)]
+            const GOLDEN_RATIO: Self = 1.61803398874989484820458683436563811772030917980576;
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
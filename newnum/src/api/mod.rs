@@ -1,22 +1,54 @@
 mod abs_diff;
+mod bits;
+mod bytes;
+mod checked;
+mod complex;
+mod div;
+mod exp;
+mod float_class;
 mod floating;
 mod from_literal;
+mod log;
+mod math_consts;
 mod min_max;
+mod number_theory;
+mod one;
+mod overflowing;
+mod pow;
 mod root;
 mod round;
+mod saturating;
 mod sign;
 mod signed;
 mod trig;
 mod type_min_max;
 mod whole;
+mod widening;
+mod wrapping;
 pub use abs_diff::*;
+pub use bits::*;
+pub use bytes::*;
+pub use checked::*;
+pub use complex::*;
+pub use div::*;
+pub use exp::*;
+pub use float_class::*;
 pub use floating::*;
 pub use from_literal::*;
+pub use log::*;
+pub use math_consts::*;
 pub use min_max::*;
+pub use number_theory::*;
+pub use one::*;
+pub use overflowing::*;
+pub use pow::*;
 pub use root::*;
 pub use round::*;
+pub use saturating::*;
 pub use sign::*;
 pub use signed::*;
 pub use trig::*;
 pub use type_min_max::*;
 pub use whole::*;
+pub use widening::*;
+pub use wrapping::*;
@@ -0,0 +1,256 @@
+use std::ops::Neg;
+
+use crate::{internal_num, CheckedMul, CheckedRem, Num, One, Positive, Whole, Zero};
+
+/// Trait for number-theory methods (`gcd`, `lcm`, `is_prime`, `mod_pow`...) on integers.
+///
+/// Auto implemented for every whole, totally ordered `Num` with checked multiplication and remainder,
+/// so it works for every `Int`, including non `Copy` integers like `BigInt` and `BigUint`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn reduce<T: Int>(numerator: T, denominator: T) -> (T, T) {
+///     let gcd = numerator.clone().gcd(denominator.clone());
+///     (numerator / gcd.clone(), denominator / gcd)
+/// }
+///
+/// assert_eq!(reduce(12_u32, 18), (2, 3));
+/// assert_eq!(reduce(-12_i64, 18), (-2, 3));
+/// ```
+pub trait NumberTheory:
+    Num + Whole + Zero + One + Positive + CheckedMul + CheckedRem + Clone + Ord
+{
+    /// Computes the greatest common divisor of `self` and `other`, which is never negative.
+    ///
+    /// `gcd(0, 0)` is `0`. Overflows if the result is `-Self::MIN` (for example `gcd(i32::MIN, 0)`).
+    fn gcd(self, other: Self) -> Self {
+        let mut a = self;
+        let mut b = other;
+        while b != Self::zero() {
+            // `checked_rem` only fails for `MIN % -1` which is `0`.
+            let remainder = CheckedRem::checked_rem(a, b.clone()).unwrap_or(Self::zero());
+
+            a = b;
+            b = remainder;
+        }
+
+        a.abs()
+    }
+
+    /// Computes the least common multiple of `self` and `other`, which is never negative.
+    ///
+    /// Returns `0` if either value is `0`, and overflows if the result doesn't fit in `Self`.
+    fn lcm(self, other: Self) -> Self {
+        if self == Self::zero() || other == Self::zero() {
+            return Self::zero();
+        }
+
+        (self.clone() / self.gcd(other.clone()) * other).abs()
+    }
+
+    /// Computes `(gcd, x, y)` where `gcd` is the greatest common divisor of `self` and `other`,
+    /// and `x`, `y` are Bézout coefficients such that `self * x + other * y = gcd`.
+    fn extended_gcd(self, other: Self) -> (Self, Self, Self)
+    where
+        Self: Neg<Output = Self>,
+    {
+        let (mut old_r, mut r) = (self, other);
+        let (mut old_x, mut x) = (Self::one(), Self::zero());
+        let (mut old_y, mut y) = (Self::zero(), Self::one());
+
+        while r != Self::zero() {
+            let quotient = old_r.clone() / r.clone();
+
+            (old_r, r) = (r.clone(), old_r - quotient.clone() * r);
+            (old_x, x) = (x.clone(), old_x - quotient.clone() * x);
+            (old_y, y) = (y.clone(), old_y - quotient * y);
+        }
+
+        if old_r < Self::zero() {
+            (-old_r, -old_x, -old_y)
+        } else {
+            (old_r, old_x, old_y)
+        }
+    }
+
+    /// Returns `true` if `self` is a prime number. Negative numbers are never prime.
+    ///
+    /// Uses trial division followed by Miller-Rabin with the first 13 primes as bases,
+    /// which is deterministic for every value below `3.3 * 10^24` (including every `u64`).
+    /// Above that bound a `true` result means `self` is a strong probable prime.
+    #[allow(clippy::wrong_self_convention)]
+    fn is_prime(self) -> bool {
+        let bases: [Self; 13] = [
+            internal_num!(2),
+            internal_num!(3),
+            internal_num!(5),
+            internal_num!(7),
+            internal_num!(11),
+            internal_num!(13),
+            internal_num!(17),
+            internal_num!(19),
+            internal_num!(23),
+            internal_num!(29),
+            internal_num!(31),
+            internal_num!(37),
+            internal_num!(41),
+        ];
+
+        if self < internal_num!(2) {
+            return false;
+        }
+
+        for base in &bases {
+            if self.clone() % base.clone() == Self::zero() {
+                return self == *base;
+            }
+        }
+
+        // Every composite has a prime factor no larger than its square root,
+        // and every prime below 43 was already checked.
+        if self.clone().trunc_sqrt() < internal_num!(43) {
+            return true;
+        }
+
+        let self_minus_one = self.clone() - Self::one();
+
+        let mut odd_part = self_minus_one.clone();
+        let mut twos = 0;
+        while odd_part.clone() % internal_num!(2) == Self::zero() {
+            odd_part /= internal_num!(2);
+            twos += 1;
+        }
+
+        'bases: for base in bases {
+            let mut x = base.mod_pow(odd_part.clone(), self.clone());
+            if x == Self::one() || x == self_minus_one {
+                continue;
+            }
+
+            for _ in 1..twos {
+                x = mul_mod(x.clone(), x, self.clone());
+                if x == self_minus_one {
+                    continue 'bases;
+                }
+            }
+
+            return false;
+        }
+
+        true
+    }
+
+    /// Computes `self^exp mod modulus` without overflowing, in the range `0..modulus`.
+    ///
+    /// Panics if `exp` is negative or if `modulus` isn't positive.
+    fn mod_pow(self, exp: Self, modulus: Self) -> Self {
+        assert!(exp >= Self::zero(), "negative exponent");
+        assert!(modulus > Self::zero(), "modulus must be positive");
+
+        let mut output = rem_euclid(Self::one(), modulus.clone());
+        let mut base = rem_euclid(self, modulus.clone());
+        let mut exp = exp;
+        while exp != Self::zero() {
+            if exp.clone() % internal_num!(2) == Self::one() {
+                output = mul_mod(output, base.clone(), modulus.clone());
+            }
+
+            base = mul_mod(base.clone(), base, modulus.clone());
+            exp /= internal_num!(2);
+        }
+
+        output
+    }
+
+    /// Computes the modular multiplicative inverse of `self`,
+    /// which is the value `x` in the range `0..modulus` such that `self * x mod modulus = 1`.
+    ///
+    /// Returns `None` if `self` and `modulus` aren't coprime.
+    /// Panics if `modulus` isn't positive.
+    fn mod_inverse(self, modulus: Self) -> Option<Self> {
+        assert!(modulus > Self::zero(), "modulus must be positive");
+
+        // The extended euclidean algorithm with the coefficients kept in `0..modulus`,
+        // so it also works for unsigned types.
+        let (mut old_r, mut r) = (modulus.clone(), rem_euclid(self, modulus.clone()));
+        let (mut old_x, mut x) = (Self::zero(), rem_euclid(Self::one(), modulus.clone()));
+
+        while r != Self::zero() {
+            let quotient = old_r.clone() / r.clone();
+
+            (old_r, r) = (r.clone(), old_r - quotient.clone() * r);
+            (old_x, x) = (
+                x.clone(),
+                sub_mod(
+                    old_x,
+                    mul_mod(quotient, x, modulus.clone()),
+                    modulus.clone(),
+                ),
+            );
+        }
+
+        if old_r == Self::one() {
+            Some(old_x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Num + Whole + Zero + One + Positive + CheckedMul + CheckedRem + Clone + Ord> NumberTheory
+    for T
+{
+}
+
+/// Computes `a mod m` in the range `0..m` for a positive `m`.
+fn rem_euclid<T: NumberTheory>(a: T, m: T) -> T {
+    let remainder = a % m.clone();
+    if remainder < T::zero() {
+        remainder + m
+    } else {
+        remainder
+    }
+}
+
+/// Computes `(a + b) mod m` for `a, b` in `0..m` without overflowing.
+fn add_mod<T: NumberTheory>(a: T, b: T, m: T) -> T {
+    if a >= m.clone() - b.clone() {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Computes `(a - b) mod m` for `a, b` in `0..m` without overflowing.
+fn sub_mod<T: NumberTheory>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Computes `(a * b) mod m` for `a, b` in `0..m` without overflowing,
+/// using double-and-add if the product doesn't fit in `T`.
+fn mul_mod<T: NumberTheory>(a: T, b: T, m: T) -> T {
+    if let Some(product) = CheckedMul::checked_mul(a.clone(), b.clone()) {
+        return product % m;
+    }
+
+    let mut output = T::zero();
+    let mut a = a;
+    let mut b = b;
+    while b != T::zero() {
+        if b.clone() % internal_num!(2) == T::one() {
+            output = add_mod(output, a.clone(), m.clone());
+        }
+
+        a = add_mod(a.clone(), a, m.clone());
+        b /= internal_num!(2);
+    }
+
+    output
+}
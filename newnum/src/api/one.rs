@@ -0,0 +1,70 @@
+/// Trait for types that can represent one, the multiplicative identity (number / number-container).
+///
+/// Unlike `num!(1)`, `one()` doesn't require the type to support literals up to `1`.
+///
+/// For number-containers (like `Vec2`) `one()` should return the value that `Mul` treats as the identity,
+/// for example a `Vec2` with each component multiplied seperately should return `Vec2(1, 1)`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn product<T: Prim>(values: &[T]) -> T {
+///     values.iter().fold(T::one(), |product, value| product * *value)
+/// }
+///
+/// assert_eq!(product(&[2_u8, 3, 4]), 24);
+/// assert_eq!(product::<f32>(&[]), 1.0);
+/// ```
+pub trait One: Sized {
+    fn one() -> Self;
+
+    /// Returns `true` if `self` is equal to `one()`.
+    fn is_one(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        *self == Self::one()
+    }
+}
+
+/// `One` as an associated constant, for use in `const` contexts.
+///
+/// `ONE` must be equal to `one()`.
+pub trait ConstOne: One {
+    const ONE: Self;
+}
+
+macro_rules! prim_impl {
+    ($type:ident: $one:literal) => {
+        impl One for $type {
+            #[inline(always)]
+            fn one() -> Self {
+                $one
+            }
+
+            #[inline(always)]
+            fn is_one(&self) -> bool {
+                *self == $one
+            }
+        }
+        impl ConstOne for $type {
+            const ONE: Self = $one;
+        }
+    };
+}
+prim_impl!(u8: 1);
+prim_impl!(u16: 1);
+prim_impl!(u32: 1);
+prim_impl!(u64: 1);
+prim_impl!(u128: 1);
+prim_impl!(usize: 1);
+prim_impl!(i8: 1);
+prim_impl!(i16: 1);
+prim_impl!(i32: 1);
+prim_impl!(i64: 1);
+prim_impl!(i128: 1);
+prim_impl!(isize: 1);
+prim_impl!(f32: 1.0);
+prim_impl!(f64: 1.0);
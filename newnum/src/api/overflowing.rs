@@ -0,0 +1,54 @@
+/// Trait for overflowing arithmetic (`overflowing_add`, `overflowing_mul`...),
+/// which returns the wrapped result along with a `bool` that indicates whether an overflow occurred.
+///
+/// For number-containers (like `Vec2`) each element should be wrapped seperately,
+/// and the `bool` should be `true` if any of the elements overflowed.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn add_detect<T: IntPrim>(a: T, b: T) -> (T, bool) {
+///     OverflowingOps::overflowing_add(a, b)
+/// }
+///
+/// assert_eq!(add_detect(250_u8, 10), (4, true));
+/// assert_eq!(add_detect(-100_i8, -20), (-120, false));
+/// ```
+pub trait OverflowingOps: Sized {
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl OverflowingOps for $type {
+            #[inline(always)]
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                $type::overflowing_add(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                $type::overflowing_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                $type::overflowing_mul(self, rhs)
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
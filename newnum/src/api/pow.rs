@@ -0,0 +1,84 @@
+/// Trait for the `pow` method, which raises `self` to the power of `exp`.
+///
+/// This trait is generic over the exponent type:
+/// * every `Num` implements `Pow<u32>` (integer exponent),
+/// * floats also implement `Pow<i32>` and `Pow<Self>` (float exponent).
+///
+/// For non-numbers types (number-containers like `Vec2`) the logic of `pow` should follow the logic of `Mul`.
+/// For example, if `<Vec2 as Mul>` multiplies each component seperately, <Vec2 as Pow> should also `pow` each component seperately.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn square<T: Num>(value: T) -> T {
+///     value.pow(2)
+/// }
+///
+/// assert_eq!(square(12_u8), 144);
+/// assert_eq!(square(1.5_f32), 2.25);
+/// ```
+pub trait Pow<E> {
+    fn pow(self, exp: E) -> Self;
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl Pow<u32> for $type {
+            #[inline(always)]
+            fn pow(self, exp: u32) -> Self {
+                $type::pow(self, exp)
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl Pow<u32> for $type {
+            #[inline(always)]
+            fn pow(self, exp: u32) -> Self {
+                match i32::try_from(exp) {
+                    Ok(exp) => self.powi(exp),
+                    Err(_) => {
+                        // `exp as $type` can round an odd exponent to an even one, which would lose the sign.
+                        let half = self.powi((exp / 2) as i32);
+
+                        if exp % 2 == 1 {
+                            half * half * self
+                        } else {
+                            half * half
+                        }
+                    }
+                }
+            }
+        }
+        impl Pow<i32> for $type {
+            #[inline(always)]
+            fn pow(self, exp: i32) -> Self {
+                self.powi(exp)
+            }
+        }
+        impl Pow<$type> for $type {
+            #[inline(always)]
+            fn pow(self, exp: $type) -> Self {
+                self.powf(exp)
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
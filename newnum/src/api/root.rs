@@ -1,110 +1,235 @@
-/// Trait for `trunc_sqrt` and `trunc_cbrt` methods, which compute the truncated square root and cube root of a number.
+use crate::Signed;
+
+/// Trait for truncated root methods (`trunc_sqrt`, `trunc_cbrt`, `trunc_nth_root`...), which compute the truncated roots of a number.
 ///
 /// For non-numbers types (number-containers like `Vec2`) the logic of `sqrt` / `cbrt` should follow the logic of `Mul`.
 /// For example, if `<Vec2 as Mul>` multiplies each component seperately, <Vec2 as TruncRoot> should also `sqrt` each component seperately.
-pub trait TruncRoot {
+pub trait TruncRoot: Sized {
+    /// Computes the truncated square root of `self`.
+    ///
+    /// Integer implementations are exact for every value and panic if `self` is negative.
     fn trunc_sqrt(self) -> Self;
+
+    /// Computes the truncated cube root of `self`.
+    ///
+    /// The root of a negative number is negative and truncated towards zero (`trunc_cbrt(-9) = -2`).
     fn trunc_cbrt(self) -> Self;
+
+    /// Computes the truncated `n`th root of `self`.
+    ///
+    /// Panics if `n` is `0`, or if `n` is even and `self` is negative.
+    fn trunc_nth_root(self, n: u32) -> Self;
+
+    /// Returns the square root of `self` if `self` is a perfect square (the square of a whole number),
+    /// otherwise returns `None`.
+    ///
+    /// For number-containers `None` is returned if any of the elements isn't a perfect square.
+    fn checked_exact_sqrt(self) -> Option<Self>;
 }
 
-/// Trait for `sqrt` and `cbrt` methods, which compute the square root and cube root of a number.
+/// Trait for root methods (`sqrt`, `cbrt`, `nth_root`...), which compute the roots of a number.
 ///
 /// For non-numbers types (number-containers like `Vec2`) the logic of `sqrt` / `cbrt` should follow the logic of `Mul`.
 /// For example, if `<Vec2 as Mul>` multiplies each component seperately, <Vec2 as Root> should also `sqrt` each component seperately.
+///
+/// * Integers only implement [`TruncRoot`], because their roots (and reciprocal roots) usually aren't whole.
 pub trait Root: TruncRoot {
     fn sqrt(self) -> Self;
     fn cbrt(self) -> Self;
+
+    /// Computes the `n`th root of `self`.
+    ///
+    /// For odd `n` the root of a negative number is negative (`nth_root(-8, 3) = -2`).
+    /// The output for `n = 0` or for a negative number with an even `n` is type dependent (`NaN` for floats).
+    fn nth_root(self, n: u32) -> Self;
+
+    /// Computes the reciprocal square root (`1 / sqrt(self)`).
+    fn rsqrt(self) -> Self;
 }
 
-macro_rules! int_impl {
+/// Trait for the `hypot` method,
+/// which computes the length of the hypotenuse of a right-angle triangle given the lengths of its legs (`sqrt(a^2 + b^2)`).
+///
+/// For non-numbers types (number-containers like `Vec2`) the logic of `hypot` should follow the logic of `Mul`.
+/// For example, if `<Vec2 as Mul>` multiplies each component seperately, <Vec2 as Hypot> should also `hypot` each component seperately.
+///
+/// * Integers don't implement `Hypot`,
+///   because `a^2 + b^2` overflows long before the hypotenuse does and the result usually isn't whole.
+///   For a truncated integer hypotenuse use `(a * a + b * b).trunc_sqrt()`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn distance<T: Float>(a: (T, T), b: (T, T)) -> T {
+///     (a.0 - b.0).hypot(a.1 - b.1)
+/// }
+///
+/// assert_eq!(distance((1.0_f32, 1.0), (4.0, 5.0)), 5.0);
+/// ```
+pub trait Hypot {
+    fn hypot(self, other: Self) -> Self;
+}
+
+/// Exact integer root algorithms for unsigned types,
+/// which the signed types use through their absolute value.
+trait UIntRoot: Sized {
+    /// The largest value whose square doesn't overflow.
+    const MAX_SQRT: Self;
+    /// The largest value whose cube doesn't overflow.
+    const MAX_CBRT: Self;
+
+    fn uint_trunc_sqrt(self) -> Self;
+    fn uint_trunc_cbrt(self) -> Self;
+    fn uint_trunc_nth_root(self, n: u32) -> Self;
+}
+
+macro_rules! uint_root_impl {
     ($type:ident) => {
-        impl TruncRoot for $type {
-            fn trunc_sqrt(self) -> Self {
-                const FLOAT_CONVERT_LIMIT: Option<$type> = (1 as $type).checked_shl(52);
-
-                if let Some(convert_lim) = FLOAT_CONVERT_LIMIT {
-                    if self < convert_lim {
-                        (self as f64).sqrt() as Self
-                    } else {
-                        let max_sqrt = (0 as $type..)
-                            .filter(|x| x.checked_mul(*x).is_some())
-                            .last()
-                            .unwrap();
-
-                        let mut low = 1;
-                        let mut high = (self / 2).min(max_sqrt);
-
-                        while low <= high {
-                            let mid = (low + high) / 2;
-                            let mid_squared = mid * mid;
-                            if mid_squared == self {
-                                return mid;
-                            } else if mid_squared < self {
-                                low = mid + 1;
-                            } else {
-                                high = mid - 1;
-                            }
+        impl UIntRoot for $type {
+            const MAX_SQRT: Self = (1 << ($type::BITS / 2)) - 1;
+
+            const MAX_CBRT: Self = {
+                let mut root: $type = 0;
+                let mut bit = $type::BITS / 3 + 1;
+                while bit > 0 {
+                    bit -= 1;
+
+                    let candidate = root | (1 << bit);
+                    if let Some(square) = candidate.checked_mul(candidate) {
+                        if square.checked_mul(candidate).is_some() {
+                            root = candidate;
                         }
+                    }
+                }
+
+                root
+            };
 
-                        high
+            // Newton's method starting from a power of two that is at least the root.
+            // Each step strictly decreases the estimate until it reaches the truncated root,
+            // and the estimate starts within a factor of two of the root so the step count is logarithmic in the bit count.
+            fn uint_trunc_sqrt(self) -> Self {
+                if self < 2 {
+                    return self;
+                }
+
+                let mut root = ((1 as $type) << (self.ilog2() / 2 + 1)).min(Self::MAX_SQRT);
+                loop {
+                    let next = (root + self / root) / 2;
+                    if next >= root {
+                        return root;
                     }
-                } else {
-                    (self as f64).sqrt() as Self
+
+                    root = next;
                 }
             }
 
-            fn trunc_cbrt(self) -> Self {
-                const FLOAT_CONVERT_LIMIT: Option<$type> = (1 as $type).checked_shl(52);
-
-                if let Some(convert_lim) = FLOAT_CONVERT_LIMIT {
-                    if self < convert_lim {
-                        (self as f64).cbrt() as Self
-                    } else {
-                        let max_cbrt = (0 as $type..)
-                            .filter(|x| {
-                                x.checked_mul(*x)
-                                    .map_or(None, |x| x.checked_mul(x))
-                                    .is_some()
-                            })
-                            .last()
-                            .unwrap();
-
-                        let mut low = 1;
-                        let mut high = (self / 2).min(max_cbrt);
-
-                        while low <= high {
-                            let mid = (low + high) / 2;
-                            let mid_cubed = mid * mid * mid;
-                            if mid_cubed == self {
-                                return mid;
-                            } else if mid_cubed < self {
-                                low = mid + 1;
-                            } else {
-                                high = mid - 1;
-                            }
-                        }
+            fn uint_trunc_cbrt(self) -> Self {
+                if self < 2 {
+                    return self;
+                }
+
+                let mut root = ((1 as $type) << (self.ilog2() / 3 + 1)).min(Self::MAX_CBRT);
+                loop {
+                    let next = (2 * root + self / (root * root)) / 3;
+                    if next >= root {
+                        return root;
+                    }
+
+                    root = next;
+                }
+            }
+
+            // Finds the root bit by bit starting from the highest bit the root can have,
+            // which is `ilog2(value) / n` because `root^n <= value`.
+            fn uint_trunc_nth_root(self, n: u32) -> Self {
+                if self == 0 {
+                    return 0;
+                }
+
+                let mut root: $type = 0;
+                for bit in (0..=self.ilog2() / n).rev() {
+                    let candidate = root | (1 << bit);
 
-                        high
+                    if candidate.checked_pow(n).is_some_and(|power| power <= self) {
+                        root = candidate;
                     }
+                }
+
+                root
+            }
+        }
+    };
+}
+uint_root_impl!(u8);
+uint_root_impl!(u16);
+uint_root_impl!(u32);
+uint_root_impl!(u64);
+uint_root_impl!(u128);
+uint_root_impl!(usize);
+
+macro_rules! int_impl {
+    ($type:ident => $unsigned:ident) => {
+        impl TruncRoot for $type {
+            fn trunc_sqrt(self) -> Self {
+                assert!(
+                    !Signed::is_negative(&self),
+                    "square root of a negative number"
+                );
+
+                (self as $unsigned).uint_trunc_sqrt() as $type
+            }
+
+            fn trunc_cbrt(self) -> Self {
+                if Signed::is_negative(&self) {
+                    ((self as $unsigned).wrapping_neg().uint_trunc_cbrt() as $type).wrapping_neg()
+                } else {
+                    (self as $unsigned).uint_trunc_cbrt() as $type
+                }
+            }
+
+            fn trunc_nth_root(self, n: u32) -> Self {
+                assert!(n != 0, "zeroth root is undefined");
+
+                if Signed::is_negative(&self) {
+                    assert!(n % 2 == 1, "even root of a negative number");
+
+                    ((self as $unsigned).wrapping_neg().uint_trunc_nth_root(n) as $type)
+                        .wrapping_neg()
                 } else {
-                    (self as f64).cbrt() as Self
+                    (self as $unsigned).uint_trunc_nth_root(n) as $type
+                }
+            }
+
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                if Signed::is_negative(&self) {
+                    return None;
+                }
+
+                let sqrt = self.trunc_sqrt();
+                if sqrt * sqrt == self {
+                    Some(sqrt)
+                } else {
+                    None
                 }
             }
         }
     };
 }
-int_impl!(u8);
-int_impl!(u16);
-int_impl!(u32);
-int_impl!(u64);
-int_impl!(u128);
-int_impl!(usize);
-int_impl!(i8);
-int_impl!(i16);
-int_impl!(i32);
-int_impl!(i64);
-int_impl!(i128);
-int_impl!(isize);
+int_impl!(u8 => u8);
+int_impl!(u16 => u16);
+int_impl!(u32 => u32);
+int_impl!(u64 => u64);
+int_impl!(u128 => u128);
+int_impl!(usize => usize);
+int_impl!(i8 => u8);
+int_impl!(i16 => u16);
+int_impl!(i32 => u32);
+int_impl!(i64 => u64);
+int_impl!(i128 => u128);
+int_impl!(isize => usize);
 
 macro_rules! float_impl {
     ($type:ident) => {
@@ -115,6 +240,22 @@ macro_rules! float_impl {
             fn trunc_cbrt(self) -> Self {
                 self.cbrt().trunc()
             }
+
+            fn trunc_nth_root(self, n: u32) -> Self {
+                assert!(n != 0, "zeroth root is undefined");
+                assert!(n % 2 == 1 || self >= 0.0, "even root of a negative number");
+
+                Root::nth_root(self, n).trunc()
+            }
+
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                let sqrt = self.sqrt();
+                if sqrt.fract() == 0.0 && sqrt * sqrt == self {
+                    Some(sqrt)
+                } else {
+                    None
+                }
+            }
         }
         impl Root for $type {
             fn sqrt(self) -> Self {
@@ -123,6 +264,27 @@ macro_rules! float_impl {
             fn cbrt(self) -> Self {
                 self.cbrt()
             }
+
+            fn nth_root(self, n: u32) -> Self {
+                match n {
+                    0 => $type::NAN,
+                    1 => self,
+                    2 => self.sqrt(),
+                    3 => self.cbrt(),
+                    n if n % 2 == 1 && self < 0.0 => -(-self).powf((n as $type).recip()),
+                    n => self.powf((n as $type).recip()),
+                }
+            }
+
+            fn rsqrt(self) -> Self {
+                self.sqrt().recip()
+            }
+        }
+
+        impl Hypot for $type {
+            fn hypot(self, other: Self) -> Self {
+                self.hypot(other)
+            }
         }
     };
 }
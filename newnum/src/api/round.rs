@@ -1,5 +1,28 @@
+use std::ops::Add;
+
 use crate::*;
 
+/// A rounding mode for [`Round::round_with`],
+/// for choosing between rounding behaviours at runtime.
+///
+/// Each mode corresponds to a `Round` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Rounds to the closer whole number, and ties away from zero (`round`).
+    #[default]
+    HalfAwayFromZero,
+    /// Rounds to the closer whole number, and ties to the even number (`round_ties_even`).
+    HalfToEven,
+    /// Rounds down (`floor`).
+    Floor,
+    /// Rounds up (`ceil`).
+    Ceil,
+    /// Rounds towards zero (`trunc`).
+    Trunc,
+    /// Rounds away from zero (`atrunc`).
+    ATrunc,
+}
+
 /// Trait for the round API (`round`, `floor`...)
 /// which may be used for numbers, number containers, or anything that makes sense.
 ///
@@ -15,6 +38,11 @@ pub trait Round: Sized + Zero {
     /// For example: `1.2 => 1.0`, `1.8 => 2.0`, `-1.3 => -1.0`.
     fn round(self) -> Self;
 
+    /// Rounds to the closer whole number, and ties to the even number (banker's rounding).
+    ///
+    /// For example: `1.5 => 2.0`, `2.5 => 2.0`, `-0.5 => -0.0`, `1.8 => 2.0`.
+    fn round_ties_even(self) -> Self;
+
     /// Rounds down.
     ///
     /// For example: `1.2 => 1.0`, `1.8 => 1.0`, `-1.3 => -2.0`.
@@ -36,17 +64,84 @@ pub trait Round: Sized + Zero {
     /// For example: `1.2 => 2.0`, `1.8 => 2.0`, `-1.3 => -2.0`.
     fn atrunc(self) -> Self;
 
-    /// Returns the fractional part of self.
+    /// Returns the fractional part of self, which is always `0` for integers.
+    ///
+    /// For example: `1.2 => 0.2`, `-1.5 => -0.5`, `7 => 0`.
     fn fract(self) -> Self;
+
+    /// Rounds to the closer multiple of `step`, and ties away from zero.
+    ///
+    /// For example: `7 => 5` and `8 => 10` for a step of `5`, `0.26 => 0.25` for a step of `0.25`.
+    ///
+    /// For integers a `step` of `0` panics and an out of range result overflows.
+    /// For floats a `step` of `0.0` returns `NaN`.
+    fn round_to_multiple(self, step: Self) -> Self;
+
+    /// Rounds to `decimals` decimal digits after the point, and ties away from zero.
+    /// A negative `decimals` rounds to digits before the point.
+    ///
+    /// For example: `1.2345 => 1.23` for `2` decimals, `1234 => 1200` for `-2` decimals.
+    ///
+    /// Floats can't represent most decimal fractions exactly,
+    /// so values that look like decimal ties (like `2.675`) may round either way.
+    /// For integers a result that doesn't fit in `Self` panics.
+    fn round_to_decimals(self, decimals: i32) -> Self;
+
+    /// Rounds using `mode`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use newnum::*;
+    ///
+    /// assert_eq!(2.5_f32.round_with(RoundingMode::HalfAwayFromZero), 3.0);
+    /// assert_eq!(2.5_f32.round_with(RoundingMode::HalfToEven), 2.0);
+    /// assert_eq!((-2.5_f64).round_with(RoundingMode::Trunc), -2.0);
+    /// assert_eq!(7_u8.round_with(RoundingMode::Ceil), 7);
+    /// ```
+    fn round_with(self, mode: RoundingMode) -> Self {
+        match mode {
+            RoundingMode::HalfAwayFromZero => self.round(),
+            RoundingMode::HalfToEven => self.round_ties_even(),
+            RoundingMode::Floor => self.floor(),
+            RoundingMode::Ceil => self.ceil(),
+            RoundingMode::Trunc => self.trunc(),
+            RoundingMode::ATrunc => self.atrunc(),
+        }
+    }
+
+    /// Rounds stochastically, where the probability of rounding up is the fractional part.
+    ///
+    /// `noise` should be uniformly distributed in `0..1` (`[0.0, 1.0)` for floats),
+    /// so that the result is unbiased on average.
+    /// For number-containers (like `Vec2`) each element should have independent noise.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use newnum::*;
+    ///
+    /// assert_eq!(1.25_f32.round_stochastic(0.5), 1.0);
+    /// assert_eq!(1.25_f32.round_stochastic(0.75), 2.0);
+    /// ```
+    fn round_stochastic(self, noise: Self) -> Self
+    where
+        Self: Add<Output = Self>,
+    {
+        (self + noise).floor()
+    }
 }
 
 macro_rules! int_impl {
-    ($type:ident) => {
+    ($type:ident => $unsigned:ident) => {
         impl Round for $type {
             #[inline(always)]
             fn round(self) -> Self {
                 self
             }
+            fn round_ties_even(self) -> Self {
+                self
+            }
             fn floor(self) -> Self {
                 self
             }
@@ -60,23 +155,76 @@ macro_rules! int_impl {
                 self
             }
             fn fract(self) -> Self {
-                self
+                0
+            }
+
+            fn round_to_multiple(self, step: Self) -> Self {
+                let unsigned_abs = |value: $type| {
+                    if Signed::is_negative(&value) {
+                        (value as $unsigned).wrapping_neg()
+                    } else {
+                        value as $unsigned
+                    }
+                };
+
+                // `wrapping_rem` only wraps for `MIN % -1` which is `0`.
+                let remainder = self.wrapping_rem(step);
+                let remainder_abs = unsigned_abs(remainder);
+                let towards_zero = self - remainder;
+
+                if remainder_abs < unsigned_abs(step) - remainder_abs {
+                    towards_zero
+                } else if Signed::is_negative(&self) == Signed::is_negative(&step) {
+                    towards_zero + step
+                } else {
+                    towards_zero - step
+                }
+            }
+
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                if decimals >= 0 {
+                    return self;
+                }
+
+                let digits = decimals.unsigned_abs();
+                match (10 as $type).checked_pow(digits) {
+                    Some(step) => self.round_to_multiple(step),
+                    None => {
+                        // The result is either `0` or `10^digits` which doesn't fit.
+                        let half = (10 as $unsigned)
+                            .checked_pow(digits - 1)
+                            .and_then(|power| power.checked_mul(5));
+
+                        let self_abs = if Signed::is_negative(&self) {
+                            (self as $unsigned).wrapping_neg()
+                        } else {
+                            self as $unsigned
+                        };
+
+                        match half {
+                            Some(half) if self_abs >= half => {
+                                panic!("attempt to round with overflow")
+                            }
+                            _ => 0,
+                        }
+                    }
+                }
             }
         }
     };
 }
-int_impl!(u8);
-int_impl!(u16);
-int_impl!(u32);
-int_impl!(u64);
-int_impl!(u128);
-int_impl!(usize);
-int_impl!(i8);
-int_impl!(i16);
-int_impl!(i32);
-int_impl!(i64);
-int_impl!(i128);
-int_impl!(isize);
+int_impl!(u8 => u8);
+int_impl!(u16 => u16);
+int_impl!(u32 => u32);
+int_impl!(u64 => u64);
+int_impl!(u128 => u128);
+int_impl!(usize => usize);
+int_impl!(i8 => u8);
+int_impl!(i16 => u16);
+int_impl!(i32 => u32);
+int_impl!(i64 => u64);
+int_impl!(i128 => u128);
+int_impl!(isize => usize);
 
 macro_rules! float_impl {
     ($type:ident) => {
@@ -84,6 +232,9 @@ macro_rules! float_impl {
             fn round(self) -> Self {
                 self.round()
             }
+            fn round_ties_even(self) -> Self {
+                self.round_ties_even()
+            }
             fn floor(self) -> Self {
                 self.floor()
             }
@@ -103,6 +254,37 @@ macro_rules! float_impl {
             fn fract(self) -> Self {
                 self.fract()
             }
+
+            fn round_to_multiple(self, step: Self) -> Self {
+                (self / step).round() * step
+            }
+
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                if !self.is_finite() {
+                    return self;
+                }
+
+                let factor = (10 as $type).powi(decimals.saturating_abs());
+                if decimals >= 0 {
+                    let scaled = self * factor;
+
+                    // Values this large have no digits after the point.
+                    if scaled.is_finite() {
+                        scaled.round() / factor
+                    } else {
+                        self
+                    }
+                } else {
+                    let rounded = (self / factor).round();
+
+                    // Avoids `0.0 * INFINITY` which is `NaN`.
+                    if rounded == 0.0 {
+                        rounded
+                    } else {
+                        rounded * factor
+                    }
+                }
+            }
         }
     };
 }
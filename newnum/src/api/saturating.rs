@@ -0,0 +1,122 @@
+/// Trait for saturating arithmetic (`saturating_add`, `saturating_mul`...),
+/// which clamps the result to the boundaries of the type instead of overflowing.
+///
+/// For number-containers (like `Vec2`) each element should be saturated seperately.
+///
+/// * For a wrapper type that uses saturating arithmetic for its operators, use `std::num::Saturating`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn gain<T: Int>(sample: T, gain: T) -> T {
+///     SaturatingOps::saturating_mul(sample, gain)
+/// }
+///
+/// assert_eq!(gain(100_u8, 3), 255);
+/// assert_eq!(gain(-20_000_i16, 2), -32768);
+/// ```
+pub trait SaturatingOps: Sized {
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_sub(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    /// Computes `-self`, saturating at the boundaries of the type.
+    ///
+    /// For unsigned types this always returns `0`.
+    fn saturating_neg(self) -> Self;
+
+    /// Computes `self << rhs`, saturating at the boundaries of the type if any significant bit is shifted out.
+    ///
+    /// This means that the result is either `self * 2^rhs`, or the closest value to it.
+    fn saturating_shl(self, rhs: u32) -> Self;
+
+    fn saturating_pow(self, exp: u32) -> Self;
+}
+
+macro_rules! uint_impl {
+    ($type:ident) => {
+        impl SaturatingOps for $type {
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                $type::saturating_add(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                $type::saturating_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                $type::saturating_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_neg(self) -> Self {
+                0
+            }
+            fn saturating_shl(self, rhs: u32) -> Self {
+                let output = self.unbounded_shl(rhs);
+
+                if output.unbounded_shr(rhs) == self {
+                    output
+                } else {
+                    $type::MAX
+                }
+            }
+            #[inline(always)]
+            fn saturating_pow(self, exp: u32) -> Self {
+                $type::saturating_pow(self, exp)
+            }
+        }
+    };
+}
+uint_impl!(u8);
+uint_impl!(u16);
+uint_impl!(u32);
+uint_impl!(u64);
+uint_impl!(u128);
+uint_impl!(usize);
+
+macro_rules! sint_impl {
+    ($type:ident) => {
+        impl SaturatingOps for $type {
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                $type::saturating_add(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                $type::saturating_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                $type::saturating_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn saturating_neg(self) -> Self {
+                $type::saturating_neg(self)
+            }
+            fn saturating_shl(self, rhs: u32) -> Self {
+                let output = self.unbounded_shl(rhs);
+
+                if output.unbounded_shr(rhs) == self {
+                    output
+                } else if self < 0 {
+                    $type::MIN
+                } else {
+                    $type::MAX
+                }
+            }
+            #[inline(always)]
+            fn saturating_pow(self, exp: u32) -> Self {
+                $type::saturating_pow(self, exp)
+            }
+        }
+    };
+}
+sint_impl!(i8);
+sint_impl!(i16);
+sint_impl!(i32);
+sint_impl!(i64);
+sint_impl!(i128);
+sint_impl!(isize);
@@ -152,6 +152,12 @@ pub trait Negative: Signed {
 pub trait Zero: Signed {
     fn zero() -> Self;
 }
+/// `Zero` as an associated constant, for use in `const` contexts.
+///
+/// `ZERO` must be equal to `zero()`.
+pub trait ConstZero: Zero {
+    const ZERO: Self;
+}
 
 //
 //
@@ -164,19 +170,19 @@ pub trait Zero: Signed {
 /// Trait for types that are never positive (number / number-container).
 ///
 /// * In the future when rust supports negative traits (`!SomeTrait`),
-/// this trait will be replaced with `!Positive`.
+///   this trait will be replaced with `!Positive`.
 pub trait NotPositive: Signed {}
 
 /// Trait for types that are never negative (number / number-container).
 ///
 /// * In the future when rust supports negative traits (`!SomeTrait`),
-/// this trait will be replaced with `!Negative`.
+///   this trait will be replaced with `!Negative`.
 pub trait NotNegative: Signed {}
 
 /// Trait for types that are never zero (number / number-container).
 ///
 /// * In the future when rust supports negative traits (`!SomeTrait`),
-/// this trait will be replaced with `!Zero`.
+///   this trait will be replaced with `!Zero`.
 pub trait NotZero: Signed {}
 
 //
@@ -278,7 +284,7 @@ pub trait Signum: Signed {
     /// * For a `2-value-sign` use `bin_signum`.
     ///
     /// * This function is named `signumt` and not `signum` because in the standard-library,
-    /// `f32/f64::signum` acts using a `2-value-sign` and not a `3-value-sign`.
+    ///   `f32/f64::signum` acts using a `2-value-sign` and not a `3-value-sign`.
     fn signumt(self) -> Self;
 
     /// Returns either `1` or `-1` based on the number's `2-value-sign`:
@@ -350,6 +356,9 @@ macro_rules! uint_impl {
                 0
             }
         }
+        impl ConstZero for $type {
+            const ZERO: Self = 0;
+        }
         impl NotNegative for $type {}
 
         impl Signum for $type {
@@ -427,6 +436,9 @@ macro_rules! sint_impl {
                 0
             }
         }
+        impl ConstZero for $type {
+            const ZERO: Self = 0;
+        }
 
         impl Signum for $type {
             fn signumt(self) -> Self {
@@ -507,6 +519,9 @@ macro_rules! float_impl {
                 0.0
             }
         }
+        impl ConstZero for $type {
+            const ZERO: Self = 0.0;
+        }
 
         impl Signum for $type {
             fn signumt(self) -> Self {
@@ -1,7 +1,7 @@
 /// For types with a minimum value.
 ///
 /// * Not all `Num`s implement `TypeMin` / `TypeMax`, because they may not have value limits.
-/// For example a heap allocated int could rise infinitly until there is no more heap memory.
+///   For example a heap allocated int could rise infinitly until there is no more heap memory.
 pub trait TypeMin {
     fn type_min() -> Self;
 }
@@ -9,7 +9,7 @@ pub trait TypeMin {
 /// For types with a maximum value.
 ///
 /// * Not all `Num`s implement `MinValue` / `MaxValue`, because they may not have value limits.
-/// For example a heap allocated int could rise infinitly until there is no more heap memory.
+///   For example a heap allocated int could rise infinitly until there is no more heap memory.
 pub trait TypeMax {
     fn type_max() -> Self;
 }
@@ -17,13 +17,13 @@ pub trait TypeMax {
 /// For types without a minimum value.
 ///
 /// * In the future when rust supports negative traits (`!SomeTrait`),
-/// this trait will be replaced with `!TypeMin`.
+///   this trait will be replaced with `!TypeMin`.
 pub trait NoTypeMin: PartialOrd {}
 
 /// For types without a maximum value.
 ///
 /// * In the future when rust supports negative traits (`!SomeTrait`),
-/// this trait will be replaced with `!TypeMax`.
+///   this trait will be replaced with `!TypeMax`.
 pub trait NoTypeMax: PartialOrd {}
 
 /// Trait for types with both minimum and maximum values.
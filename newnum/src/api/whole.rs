@@ -49,6 +49,110 @@ pub trait WholeEquivalent: Round {
     {
         self.trunc_cbrt().iround()
     }
+
+    /// Rounds to the closer whole number, and converts into the integer type `I`.
+    ///
+    /// Returns `None` if the result is out of the range of `I` or if `self` is `NaN`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use newnum::*;
+    ///
+    /// fn pixel<T: Prim>(value: T) -> Option<u8> {
+    ///     (value * num!(255)).round_to()
+    /// }
+    ///
+    /// assert_eq!(pixel(0.5_f32), Some(128));
+    /// assert_eq!(pixel(1.5_f64), None);
+    /// assert_eq!(pixel(f32::NAN), None);
+    /// ```
+    fn round_to<I: IntPrim>(self) -> Option<I>
+    where
+        Self: Prim,
+    {
+        self.round().checked_as()
+    }
+
+    /// Rounds down, and converts into the integer type `I`.
+    ///
+    /// Returns `None` if the result is out of the range of `I` or if `self` is `NaN`.
+    fn floor_to<I: IntPrim>(self) -> Option<I>
+    where
+        Self: Prim,
+    {
+        self.floor().checked_as()
+    }
+
+    /// Rounds up, and converts into the integer type `I`.
+    ///
+    /// Returns `None` if the result is out of the range of `I` or if `self` is `NaN`.
+    fn ceil_to<I: IntPrim>(self) -> Option<I>
+    where
+        Self: Prim,
+    {
+        self.ceil().checked_as()
+    }
+
+    /// Rounds towards zero, and converts into the integer type `I`.
+    ///
+    /// Returns `None` if the result is out of the range of `I` or if `self` is `NaN`.
+    fn trunc_to<I: IntPrim>(self) -> Option<I>
+    where
+        Self: Prim,
+    {
+        self.trunc().checked_as()
+    }
+
+    /// Rounds to the closer whole number, and converts into the integer type `I`,
+    /// clamping to the range of `I`. `NaN` converts to `0`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use newnum::*;
+    ///
+    /// fn index<T: Prim>(position: T, len: usize) -> usize {
+    ///     Ord::min(position.saturating_round_to::<usize>(), len - 1)
+    /// }
+    ///
+    /// assert_eq!(index(-3.0_f32, 10), 0);
+    /// assert_eq!(index(4.6_f64, 10), 5);
+    /// assert_eq!(index(1e20_f64, 10), 9);
+    /// ```
+    fn saturating_round_to<I: IntPrim>(self) -> I
+    where
+        Self: Prim,
+    {
+        self.round().saturating_as()
+    }
+
+    /// Rounds down, and converts into the integer type `I`,
+    /// clamping to the range of `I`. `NaN` converts to `0`.
+    fn saturating_floor_to<I: IntPrim>(self) -> I
+    where
+        Self: Prim,
+    {
+        self.floor().saturating_as()
+    }
+
+    /// Rounds up, and converts into the integer type `I`,
+    /// clamping to the range of `I`. `NaN` converts to `0`.
+    fn saturating_ceil_to<I: IntPrim>(self) -> I
+    where
+        Self: Prim,
+    {
+        self.ceil().saturating_as()
+    }
+
+    /// Rounds towards zero, and converts into the integer type `I`,
+    /// clamping to the range of `I`. `NaN` converts to `0`.
+    fn saturating_trunc_to<I: IntPrim>(self) -> I
+    where
+        Self: Prim,
+    {
+        self.trunc().saturating_as()
+    }
 }
 
 macro_rules! int_impl {
@@ -0,0 +1,176 @@
+use crate::*;
+
+/// Trait for integer primitives that have a primitive twice their size (`u32 => u64`),
+/// which is what multi-precision arithmetic (big-numbers made out of `Self` limbs) is built on.
+///
+/// This is an optional subtrait of [`IntPrim`] (`WideningMul: IntPrim`):
+/// `IntPrim` doesn't require it because the widest integer doesn't have a wider equivalent (`u128`),
+/// so big-number implementations should be generic over `T: WideningMul` instead of `T: IntPrim`.
+///
+/// * `WideningMul` isn't implemented for `u128` and `i128`,
+///   because there is no 256-bit primitive to be their `Wide` type.
+///   This means that types built on `WideningMul` (like [`Fixed`]) don't support 128-bit storage.
+///
+/// For signed integers, a `(low, high)` pair represents `high * 2^BITS + low`,
+/// where `low` is read as unsigned (its bits are the low half of the result, so it may be negative).
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn add_limbs<T: WideningMul>(lhs: &mut [T], rhs: &[T]) -> bool {
+///     let mut carry = false;
+///     for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+///         (*lhs, carry) = WideningMul::carrying_add(*lhs, *rhs, carry);
+///     }
+///
+///     carry
+/// }
+///
+/// let mut value = [u8::MAX, 0];
+/// assert!(!add_limbs(&mut value, &[1, 0]));
+/// assert_eq!(value, [0, 1]);
+/// ```
+pub trait WideningMul: IntPrim {
+    /// The integer twice as wide as `Self`, with the same signedness.
+    type Wide: IntPrim;
+
+    /// Converts `self` into `Self::Wide` without changing its value.
+    fn widen(self) -> Self::Wide;
+
+    /// Converts `Self::Wide` into `Self` by discarding the high half of its bits.
+    fn narrow(wide: Self::Wide) -> Self;
+
+    /// Computes the complete product `self * rhs` without overflowing,
+    /// and returns it as `(low, high)` halves.
+    ///
+    /// For signed integers, `low` holds the bits of the low half and may be negative.
+    fn widening_mul(self, rhs: Self) -> (Self, Self);
+
+    /// Computes `self * rhs + carry` without overflowing,
+    /// and returns it as `(low, high)` halves.
+    ///
+    /// For signed integers, `low` holds the bits of the low half and may be negative.
+    fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self);
+
+    /// Computes `self + rhs + carry` and returns the wrapped sum along with whether an overflow occurred.
+    ///
+    /// For unsigned integers, the output `bool` is the carry into the next limb.
+    fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool);
+
+    /// Computes `self - rhs - borrow` and returns the wrapped difference along with whether an overflow occurred.
+    ///
+    /// For unsigned integers, the output `bool` is the borrow from the next limb.
+    fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool);
+}
+
+macro_rules! uint_impl {
+    ($type:ident => $wide:ident) => {
+        impl WideningMul for $type {
+            type Wide = $wide;
+
+            #[inline(always)]
+            fn widen(self) -> Self::Wide {
+                self as $wide
+            }
+            #[inline(always)]
+            fn narrow(wide: Self::Wide) -> Self {
+                wide as $type
+            }
+
+            #[inline(always)]
+            fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let product = self as $wide * rhs as $wide;
+
+                (product as $type, (product >> $type::BITS) as $type)
+            }
+            #[inline(always)]
+            fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let product = self as $wide * rhs as $wide + carry as $wide;
+
+                (product as $type, (product >> $type::BITS) as $type)
+            }
+
+            #[inline(always)]
+            fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+                let (sum, overflow_a) = self.overflowing_add(rhs);
+                let (sum, overflow_b) = sum.overflowing_add(carry as $type);
+
+                (sum, overflow_a || overflow_b)
+            }
+            #[inline(always)]
+            fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                let (difference, overflow_a) = self.overflowing_sub(rhs);
+                let (difference, overflow_b) = difference.overflowing_sub(borrow as $type);
+
+                (difference, overflow_a || overflow_b)
+            }
+        }
+    };
+}
+uint_impl!(u8 => u16);
+uint_impl!(u16 => u32);
+uint_impl!(u32 => u64);
+uint_impl!(u64 => u128);
+#[cfg(target_pointer_width = "16")]
+uint_impl!(usize => u32);
+#[cfg(target_pointer_width = "32")]
+uint_impl!(usize => u64);
+#[cfg(target_pointer_width = "64")]
+uint_impl!(usize => u128);
+
+macro_rules! sint_impl {
+    ($type:ident => $wide:ident) => {
+        impl WideningMul for $type {
+            type Wide = $wide;
+
+            #[inline(always)]
+            fn widen(self) -> Self::Wide {
+                self as $wide
+            }
+            #[inline(always)]
+            fn narrow(wide: Self::Wide) -> Self {
+                wide as $type
+            }
+
+            #[inline(always)]
+            fn widening_mul(self, rhs: Self) -> (Self, Self) {
+                let product = self as $wide * rhs as $wide;
+
+                (product as $type, (product >> $type::BITS) as $type)
+            }
+            #[inline(always)]
+            fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let product = self as $wide * rhs as $wide + carry as $wide;
+
+                (product as $type, (product >> $type::BITS) as $type)
+            }
+
+            #[inline(always)]
+            fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+                let (sum, overflow_a) = self.overflowing_add(rhs);
+                let (sum, overflow_b) = sum.overflowing_add(carry as $type);
+
+                (sum, overflow_a != overflow_b)
+            }
+            #[inline(always)]
+            fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                let (difference, overflow_a) = self.overflowing_sub(rhs);
+                let (difference, overflow_b) = difference.overflowing_sub(borrow as $type);
+
+                (difference, overflow_a != overflow_b)
+            }
+        }
+    };
+}
+sint_impl!(i8 => i16);
+sint_impl!(i16 => i32);
+sint_impl!(i32 => i64);
+sint_impl!(i64 => i128);
+#[cfg(target_pointer_width = "16")]
+sint_impl!(isize => i32);
+#[cfg(target_pointer_width = "32")]
+sint_impl!(isize => i64);
+#[cfg(target_pointer_width = "64")]
+sint_impl!(isize => i128);
@@ -0,0 +1,76 @@
+/// Trait for wrapping arithmetic (`wrapping_add`, `wrapping_mul`...),
+/// which wraps around at the boundaries of the type instead of overflowing.
+///
+/// For number-containers (like `Vec2`) each element should be wrapped seperately.
+///
+/// * For a wrapper type that uses wrapping arithmetic for its operators, use `std::num::Wrapping`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn hash_step<T: IntPrim>(hash: T, value: T) -> T {
+///     WrappingOps::wrapping_add(WrappingOps::wrapping_mul(hash, num!(31)), value)
+/// }
+///
+/// assert_eq!(hash_step(200_u8, 7), 63);
+/// ```
+pub trait WrappingOps: Sized {
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    /// Computes `-self`, wrapping around at the boundaries of the type.
+    ///
+    /// For unsigned types this returns `MAX - self + 1`.
+    fn wrapping_neg(self) -> Self;
+
+    /// Computes `self << rhs`, where `rhs` is masked to the number of bits in `self`.
+    fn wrapping_shl(self, rhs: u32) -> Self;
+
+    fn wrapping_pow(self, exp: u32) -> Self;
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl WrappingOps for $type {
+            #[inline(always)]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                $type::wrapping_add(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                $type::wrapping_sub(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                $type::wrapping_mul(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_neg(self) -> Self {
+                $type::wrapping_neg(self)
+            }
+            #[inline(always)]
+            fn wrapping_shl(self, rhs: u32) -> Self {
+                $type::wrapping_shl(self, rhs)
+            }
+            #[inline(always)]
+            fn wrapping_pow(self, exp: u32) -> Self {
+                $type::wrapping_pow(self, exp)
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
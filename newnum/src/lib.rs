@@ -2,18 +2,23 @@ use std::ops::*;
 
 mod api;
 mod primitive;
+mod std_num;
+mod types;
 pub use api::*;
 pub use primitive::*;
+pub use types::*;
 
 pub use newnum_proc_macros::{num, num_approx};
 
 /// Derive macros for crate traits.
 pub mod derive {
     pub use newnum_proc_macros::{
-        AHyper, ATrig, AbsDiff, AlwaysNegative, AlwaysPositive, AlwaysZero, FullySigned, Hyper,
-        MinMax, Negative, NegativeOrZero, NotNegative, NotPositive, NotZero, Num, Positive,
-        PositiveOrNegative, PositiveOrZero, Root, Round, Signed, Trig, TruncRoot, TypeMax, TypeMin,
-        Whole, Zero,
+        AHyper, ATrig, AbsDiff, AlwaysNegative, AlwaysPositive, AlwaysZero, Arg, CheckedAdd,
+        CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedSub, Conj, ConstOne,
+        ConstZero, DivCeil, DivEuclid, DivFloor, DivRem, Exp, FloatClass, FloatConsts, FromBytes,
+        FullySigned, Hyper, Hypot, ILog, Log, MinMax, Negative, NegativeOrZero, Norm, NotNegative,
+        NotPositive, NotZero, Num, One, Positive, PositiveOrNegative, PositiveOrZero, Pow,
+        RemEuclid, Root, Round, Signed, ToBytes, Trig, TruncRoot, TypeMax, TypeMin, Whole, Zero,
     };
 }
 
@@ -24,7 +29,7 @@ use newnum_proc_macros::{internal_num, internal_num_approx};
 ///
 /// This includes support for the common arithmetic and comparison operators:
 /// `+`, `-`, `*`, `/`, `%`, `=`, `<`, `>`, as well as traits for absolute
-/// difference, min/max, rounding, and sign detection.
+/// difference, min/max, truncated roots, integer powers (`Pow<u32>`), rounding, and sign detection.
 ///
 /// This trait can be implemented by non-primitive types, such as unit-based
 /// numbers (`Meters`, `Seconds`, etc). For a trait limited to primitive types,
@@ -48,6 +53,7 @@ pub trait Num:
     + AbsDiff<Output = Self>
     + MinMax
     + TruncRoot
+    + Pow<u32>
     + Round
     + Signed<SignMapped = Sign, BitSignMapped = BitSign, BoolMapped = bool>
     + PartialEq
@@ -0,0 +1,267 @@
+use std::{error::Error, fmt::Display};
+
+use crate::*;
+
+/// The value of a primitive in the widest primitive of its kind.
+///
+/// Used by [`TryFromNum`] to convert between any pair of primitives without `as` semantics.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum PrimValue {
+    UInt(u128),
+    SInt(i128),
+    Float(f64),
+}
+
+/// The reason a [`NumConvertError`] occured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumConvertErrorKind {
+    /// The value is outside the range of the output type.
+    Overflow,
+    /// The value is `NaN` and the output type cannot represent `NaN`.
+    NaN,
+    /// The value has a fractional part and the output type cannot represent fractions.
+    Fraction,
+}
+
+/// The error of a failed numeric conversion, returned by [`TryFromNum::try_from_num`] and [`AsPrim::try_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumConvertError {
+    /// The sign of the value that failed to convert.
+    /// For `NaN` the sign follows the sign bit.
+    pub sign: Sign,
+    pub kind: NumConvertErrorKind,
+}
+
+impl Display for NumConvertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.kind, self.sign) {
+            (NumConvertErrorKind::Overflow, Sign::Negative) => {
+                write!(f, "value is too small for the output type")
+            }
+            (NumConvertErrorKind::Overflow, _) => {
+                write!(f, "value is too large for the output type")
+            }
+            (NumConvertErrorKind::NaN, _) => {
+                write!(f, "NaN cannot be represented by the output type")
+            }
+            (NumConvertErrorKind::Fraction, _) => {
+                write!(f, "fractions cannot be represented by the output type")
+            }
+        }
+    }
+}
+
+impl Error for NumConvertError {}
+
+impl NumConvertError {
+    fn overflow(sign: Sign) -> Self {
+        Self {
+            sign,
+            kind: NumConvertErrorKind::Overflow,
+        }
+    }
+}
+
+/// Trait for fallible conversion from any primitive, without `as` semantics.
+///
+/// * For integer outputs, conversion fails if the value is out of range, is `NaN` or has a fractional part.
+/// * For float outputs, conversion rounds to the nearest representable value,
+///   and fails if a finite value is out of range.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// assert_eq!(i32::try_from_num(453_u128), Ok(453));
+/// assert_eq!(
+///     u8::try_from_num(-1_i32),
+///     Err(NumConvertError { sign: Sign::Negative, kind: NumConvertErrorKind::Overflow }),
+/// );
+/// assert_eq!(
+///     u8::try_from_num(1.5_f32),
+///     Err(NumConvertError { sign: Sign::Positive, kind: NumConvertErrorKind::Fraction }),
+/// );
+/// ```
+pub trait TryFromNum: Sized {
+    fn try_from_num<T: AsPrim>(value: T) -> Result<Self, NumConvertError>;
+
+    /// Converts `value` and clamps it to the range of `Self` instead of failing.
+    ///
+    /// Fractions are truncated and `NaN` converts to `0` for integer outputs.
+    fn saturating_from_num<T: AsPrim>(value: T) -> Self;
+}
+
+/// Trait for conversions that are exact for every value of `T` on every platform.
+///
+/// Implemented for every primitive pair where the conversion never loses information,
+/// for example `u8 -> i16`, `i32 -> f64` and `f32 -> f64`, but not `u32 -> usize` or `i32 -> f32`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn mean<T: Prim>(values: &[T]) -> f64
+/// where
+///     f64: LosslessFrom<T>,
+/// {
+///     values.iter().map(|value| value.lossless_as::<f64>()).sum::<f64>() / values.len() as f64
+/// }
+///
+/// assert_eq!(mean(&[1_u32, 2, 6]), 3.0);
+/// ```
+pub trait LosslessFrom<T>: Sized {
+    fn lossless_from(value: T) -> Self;
+}
+
+macro_rules! int_impl {
+    ($type:ident) => {
+        impl TryFromNum for $type {
+            fn try_from_num<T: AsPrim>(value: T) -> Result<Self, NumConvertError> {
+                match value.prim_value() {
+                    PrimValue::UInt(value) => {
+                        if value <= $type::MAX as u128 {
+                            Ok(value as $type)
+                        } else {
+                            Err(NumConvertError::overflow(Sign::Positive))
+                        }
+                    }
+                    PrimValue::SInt(value) => {
+                        if value >= 0 {
+                            Self::try_from_num(value as u128)
+                        } else if value >= $type::MIN as i128 {
+                            Ok(value as $type)
+                        } else {
+                            Err(NumConvertError::overflow(Sign::Negative))
+                        }
+                    }
+                    PrimValue::Float(value) => {
+                        // `2^(BITS - 1)` for signed types and `2^BITS` for unsigned types.
+                        let bound = 2_f64.powi(($type::BITS - ($type::MIN != 0) as u32) as i32);
+                        let min = if $type::MIN == 0 { 0.0 } else { -bound };
+
+                        if value.is_nan() {
+                            Err(NumConvertError {
+                                sign: if value.is_sign_negative() {
+                                    Sign::Negative
+                                } else {
+                                    Sign::Positive
+                                },
+                                kind: NumConvertErrorKind::NaN,
+                            })
+                        } else if value >= bound {
+                            Err(NumConvertError::overflow(Sign::Positive))
+                        } else if value < min && value <= -1.0 {
+                            // Values between `-1` and `0` truncate to `0`, so they fail as fractions instead.
+                            Err(NumConvertError::overflow(Sign::Negative))
+                        } else if value.fract() != 0.0 {
+                            Err(NumConvertError {
+                                sign: value.sign(),
+                                kind: NumConvertErrorKind::Fraction,
+                            })
+                        } else {
+                            Ok(value as $type)
+                        }
+                    }
+                }
+            }
+
+            fn saturating_from_num<T: AsPrim>(value: T) -> Self {
+                match value.prim_value() {
+                    PrimValue::UInt(value) => Ord::min(value, $type::MAX as u128) as $type,
+                    PrimValue::SInt(value) => {
+                        if value >= 0 {
+                            Self::saturating_from_num(value as u128)
+                        } else {
+                            Ord::max(value, $type::MIN as i128) as $type
+                        }
+                    }
+                    // Float to int `as` casts already saturate.
+                    PrimValue::Float(value) => value as $type,
+                }
+            }
+        }
+    };
+}
+int_impl!(u8);
+int_impl!(u16);
+int_impl!(u32);
+int_impl!(u64);
+int_impl!(u128);
+int_impl!(usize);
+int_impl!(i8);
+int_impl!(i16);
+int_impl!(i32);
+int_impl!(i64);
+int_impl!(i128);
+int_impl!(isize);
+
+macro_rules! float_impl {
+    ($type:ident) => {
+        impl TryFromNum for $type {
+            fn try_from_num<T: AsPrim>(value: T) -> Result<Self, NumConvertError> {
+                let (output, sign) = match value.prim_value() {
+                    PrimValue::UInt(value) => (value as $type, Sign::Positive),
+                    PrimValue::SInt(value) => (value as $type, value.sign()),
+                    PrimValue::Float(value) => {
+                        if !value.is_finite() {
+                            return Ok(value as $type);
+                        }
+
+                        (value as $type, value.sign())
+                    }
+                };
+
+                if output.is_finite() {
+                    Ok(output)
+                } else {
+                    Err(NumConvertError::overflow(sign))
+                }
+            }
+
+            fn saturating_from_num<T: AsPrim>(value: T) -> Self {
+                Self::try_from_num(value).unwrap_or_else(|err| match err.sign {
+                    Sign::Negative => $type::MIN,
+                    _ => $type::MAX,
+                })
+            }
+        }
+    };
+}
+float_impl!(f32);
+float_impl!(f64);
+
+macro_rules! lossless_impl {
+    ($type:ident => $($output:ident),* $(,)?) => {
+        impl LosslessFrom<$type> for $type {
+            #[inline(always)]
+            fn lossless_from(value: $type) -> Self {
+                value
+            }
+        }
+
+        $(
+            impl LosslessFrom<$type> for $output {
+                #[inline(always)]
+                fn lossless_from(value: $type) -> Self {
+                    value as $output
+                }
+            }
+        )*
+    };
+}
+lossless_impl!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, f32, f64);
+lossless_impl!(u16 => u32, u64, u128, usize, i32, i64, i128, f32, f64);
+lossless_impl!(u32 => u64, u128, i64, i128, f64);
+lossless_impl!(u64 => u128, i128);
+lossless_impl!(u128 =>);
+lossless_impl!(usize =>);
+lossless_impl!(i8 => i16, i32, i64, i128, isize, f32, f64);
+lossless_impl!(i16 => i32, i64, i128, isize, f32, f64);
+lossless_impl!(i32 => i64, i128, f64);
+lossless_impl!(i64 => i128);
+lossless_impl!(i128 =>);
+lossless_impl!(isize =>);
+lossless_impl!(f32 => f64);
+lossless_impl!(f64 =>);
@@ -1,15 +1,76 @@
+use std::{
+    fmt::{Debug, Display},
+    num::FpCategory,
+};
+
 use crate::*;
 
+/// Trait for floats, including non primitive floats like `DualN`.
+///
+/// `Float` requires the float API traits (`Root`, `Trig`, `Exp`, `Log`, `FloatClass`...),
+/// and `TryFromNum` for converting primitives into the float.
+///
+/// * For float primitives, which have a byte representation and `as` casts, use [`FloatPrim`].
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn mean<T: Float>(values: &[T]) -> T {
+///     let sum = values.iter().fold(T::ZERO, |sum, &value| sum + value);
+///
+///     sum / T::saturating_from_num(values.len())
+/// }
+///
+/// assert_eq!(mean(&[1.0_f32, 2.0, 6.0]), 3.0);
+///
+/// let x = Dual::variable(2.0_f64, 0);
+/// assert_eq!(mean(&[x, x * x]).derivative(), 2.5);
+/// ```
 pub trait Float:
-    FromFloatLiteral
-    + SignedPrim
+    Num
+    + FromFloatLiteral
+    + Positive
+    + Negative
+    + Neg<Output = Self>
+    + Zero
+    + ConstZero
+    + One
+    + ConstOne
+    + Signum
+    + WholeEquivalent
+    + TryFromNum
     + Root
+    + Hypot
     + Trig<Output = Self>
     + ATrig<Output = Self>
     + Hyper<Output = Self>
     + AHyper<Output = Self>
+    + Pow<i32>
+    + Pow<Self>
+    + Exp
+    + Log
+    + FloatClass<FloatBoolMapped = bool, CategoryMapped = FpCategory>
+    + FloatConsts
+    + MathConsts
+    + Send
+    + Sync
+    + Debug
+    + Display
+    + Copy
+    + Default
 {
 }
 
+/// Trait for float primitives (`f32`, `f64`).
+///
+/// Besides `Float`, `FloatPrim` requires `SignedPrim`,
+/// which provides the byte representation, `as` casts and the euclidean / floor / ceil division traits.
+pub trait FloatPrim: Float + SignedPrim {}
+
 impl Float for f32 {}
 impl Float for f64 {}
+
+impl FloatPrim for f32 {}
+impl FloatPrim for f64 {}
@@ -1,11 +1,42 @@
-use std::hash::Hash;
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+};
 
 use crate::*;
 
+/// Trait for integers, including non primitive integers like `BigInt`.
+///
+/// Besides the bit operators, `Int` requires the checked and saturating operation traits and `ILog`,
+/// so non `std` types that implement `Int` need to implement all of them.
+///
+/// * For integer primitives, which are `Copy` and have a fixed bit width, use [`IntPrim`].
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// fn digit_sum<T: Int>(mut value: T) -> T {
+///     let mut sum = T::zero();
+///     while !value.is_zero() {
+///         sum += value.clone() % num!(10);
+///         value /= num!(10);
+///     }
+///
+///     sum
+/// }
+///
+/// assert_eq!(digit_sum(1234_u32), 10);
+/// assert_eq!(digit_sum(-99_i8), -18);
+/// ```
 pub trait Int:
-    Prim
+    Num
     + Whole
-    + Not<Output = Self>
+    + Zero
+    + One
+    + Positive
+    + Signum
     + Shl<Output = Self>
     + Shr<Output = Self>
     + BitAnd<Output = Self>
@@ -16,12 +47,30 @@ pub trait Int:
     + BitAndAssign
     + BitOrAssign
     + BitXorAssign
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + CheckedRem
+    + CheckedNeg
+    + CheckedShl
+    + SaturatingOps
+    + ILog
     + Hash
     + Eq
     + Ord
+    + Clone
+    + Debug
+    + Display
 {
 }
 
+/// Trait for integer primitives (`u8`, `i32`...).
+///
+/// Besides `Int` and `Prim`, `IntPrim` requires the traits that depend on a fixed bit width:
+/// `Not`, the wrapping and overflowing operation traits and `Bits`.
+pub trait IntPrim: Int + Prim + Not<Output = Self> + WrappingOps + OverflowingOps + Bits {}
+
 impl Int for u8 {}
 impl Int for u16 {}
 impl Int for u32 {}
@@ -34,3 +83,16 @@ impl Int for i32 {}
 impl Int for i64 {}
 impl Int for i128 {}
 impl Int for isize {}
+
+impl IntPrim for u8 {}
+impl IntPrim for u16 {}
+impl IntPrim for u32 {}
+impl IntPrim for u64 {}
+impl IntPrim for u128 {}
+impl IntPrim for usize {}
+impl IntPrim for i8 {}
+impl IntPrim for i16 {}
+impl IntPrim for i32 {}
+impl IntPrim for i64 {}
+impl IntPrim for i128 {}
+impl IntPrim for isize {}
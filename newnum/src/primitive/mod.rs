@@ -2,12 +2,14 @@ use std::fmt::{Debug, Display};
 
 use crate::*;
 
+mod convert;
 mod float;
 mod int;
 mod signed;
 mod sint;
 mod uint;
 mod unsigned;
+pub use convert::*;
 pub use float::*;
 pub use int::*;
 pub use signed::*;
@@ -26,10 +28,21 @@ pub trait Prim:
     Num
     + Positive
     + Zero
+    + ConstZero
+    + One
+    + ConstOne
     + Signum
     + AsPrim
     + WholeEquivalent
     + TruncRoot
+    + DivEuclid
+    + RemEuclid
+    + DivFloor
+    + DivCeil
+    + DivRem
+    + ToBytes
+    + FromBytes
+    + TryFromNum
     + Send
     + Sync
     + Debug
@@ -42,18 +55,45 @@ pub trait Prim:
 }
 
 macro_rules! code_for_prims {
-    ($($type:ident($as_fn:ident),)*) => {
-        code_for_prims!($ident $($type($as_fn),)*);
+    ($($type:ident($as_fn:ident, $kind:ident),)*) => {
+        code_for_prims!($ident $($type($as_fn, $kind),)*);
     };
-    ($dollar:tt $_:ident $($type:ident($as_fn:ident),)*) => {
+    ($dollar:tt $_:ident $($type:ident($as_fn:ident, $kind:ident),)*) => {
+        #[allow(clippy::wrong_self_convention)]
         pub trait AsPrim: Sized {
             $(
                 fn $as_fn(self) -> $type;
             )*
 
+            /// Returns the value of `self` in the widest primitive of its kind.
+            ///
+            /// Integer types must return `PrimValue::UInt` / `PrimValue::SInt` and floats must return `PrimValue::Float`,
+            /// because the checked conversions (`try_as`, `checked_as`, `saturating_as`...) are exact only for the value returned here.
+            fn prim_value(self) -> PrimValue;
+
             fn as_num<T: Prim>(self) -> T where Self: Prim {
                 T::from_num(self)
             }
+
+            /// Converts `self` into `T`, failing instead of using `as` semantics.
+            fn try_as<T: TryFromNum>(self) -> Result<T, NumConvertError> {
+                T::try_from_num(self)
+            }
+
+            /// Converts `self` into `T`, returning `None` instead of using `as` semantics.
+            fn checked_as<T: TryFromNum>(self) -> Option<T> {
+                T::try_from_num(self).ok()
+            }
+
+            /// Converts `self` into `T`, clamping to the range of `T` instead of using `as` semantics.
+            fn saturating_as<T: TryFromNum>(self) -> T {
+                T::saturating_from_num(self)
+            }
+
+            /// Converts `self` into `T`, which only compiles if the conversion is always exact.
+            fn lossless_as<T: LosslessFrom<Self>>(self) -> T {
+                T::lossless_from(self)
+            }
         }
 
         $(
@@ -68,6 +108,10 @@ macro_rules! code_for_prims {
         macro_rules! impl_as_Prims {
             ($dollar($type2:ident($as_fn2:ident),)*) => {$(
                 impl AsPrim for $type {
+                    fn prim_value(self) -> PrimValue {
+                        PrimValue::$kind(self as _)
+                    }
+
                     $dollar(
                         fn $as_fn2(self) -> $type2 {
                             self as _
@@ -80,18 +124,18 @@ macro_rules! code_for_prims {
     };
 }
 code_for_prims!(
-    u8(as_u8),
-    u16(as_u16),
-    u32(as_u32),
-    u64(as_u64),
-    u128(as_u128),
-    usize(as_usize),
-    i8(as_i8),
-    i16(as_i16),
-    i32(as_i32),
-    i64(as_i64),
-    i128(as_i128),
-    isize(as_isize),
-    f32(as_f32),
-    f64(as_f64),
+    u8(as_u8, UInt),
+    u16(as_u16, UInt),
+    u32(as_u32, UInt),
+    u64(as_u64, UInt),
+    u128(as_u128, UInt),
+    usize(as_usize, UInt),
+    i8(as_i8, SInt),
+    i16(as_i16, SInt),
+    i32(as_i32, SInt),
+    i64(as_i64, SInt),
+    i128(as_i128, SInt),
+    isize(as_isize, SInt),
+    f32(as_f32, Float),
+    f64(as_f64, Float),
 );
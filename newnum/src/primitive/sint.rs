@@ -1,6 +1,6 @@
 use crate::*;
 
-pub trait SInt: Int + SignedPrim {}
+pub trait SInt: IntPrim + SignedPrim {}
 
 impl SInt for i8 {}
 impl SInt for i16 {}
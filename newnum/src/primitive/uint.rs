@@ -1,6 +1,6 @@
 use crate::*;
 
-pub trait UInt: Int + UnsignedPrim {}
+pub trait UInt: IntPrim + UnsignedPrim {}
 
 impl UInt for u8 {}
 impl UInt for u16 {}
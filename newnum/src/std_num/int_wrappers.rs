@@ -0,0 +1,266 @@
+use std::num::{Saturating, Wrapping};
+
+use crate::*;
+
+macro_rules! wrapper_impl {
+    ($wrapper:ident<$type:ident>, $pow_fn:ident) => {
+        impl Num for $wrapper<$type> {}
+
+        impl FromIntLiteral for $wrapper<$type> {
+            const MIN_LITERAL: i128 = $type::MIN_LITERAL;
+            const MAX_LITERAL: i128 = $type::MAX_LITERAL;
+
+            unsafe fn from_int_literal(value: i128) -> Self {
+                $wrapper($type::from_int_literal(value))
+            }
+
+            unsafe fn approx_from_int_literal(value: i128) -> Self {
+                $wrapper($type::approx_from_int_literal(value))
+            }
+        }
+
+        /// Subtracts the smaller value from the larger one with the wrapper's own subtraction,
+        /// so a difference that doesn't fit in the type wraps (`Wrapping`) or saturates (`Saturating`).
+        /// For example `Wrapping(-100_i8).abs_diff(Wrapping(100))` is `Wrapping(200_u8 as i8)`.
+        impl AbsDiff for $wrapper<$type> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn abs_diff(self, rhs: Self) -> Self::Output {
+                if self > rhs {
+                    self - rhs
+                } else {
+                    rhs - self
+                }
+            }
+        }
+
+        impl MinMax for $wrapper<$type> {
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                $wrapper(MinMax::min(self.0, other.0))
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                $wrapper(MinMax::max(self.0, other.0))
+            }
+
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $wrapper(MinMax::clamp(self.0, min.0, max.0))
+            }
+        }
+
+        impl TypeMin for $wrapper<$type> {
+            fn type_min() -> Self {
+                $wrapper($type::MIN)
+            }
+        }
+
+        impl TypeMax for $wrapper<$type> {
+            fn type_max() -> Self {
+                $wrapper($type::MAX)
+            }
+        }
+
+        impl TruncRoot for $wrapper<$type> {
+            fn trunc_sqrt(self) -> Self {
+                $wrapper(self.0.trunc_sqrt())
+            }
+            fn trunc_cbrt(self) -> Self {
+                $wrapper(self.0.trunc_cbrt())
+            }
+
+            fn trunc_nth_root(self, n: u32) -> Self {
+                $wrapper(self.0.trunc_nth_root(n))
+            }
+
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                self.0.checked_exact_sqrt().map($wrapper)
+            }
+        }
+
+        impl Pow<u32> for $wrapper<$type> {
+            #[inline(always)]
+            fn pow(self, exp: u32) -> Self {
+                $wrapper(self.0.$pow_fn(exp))
+            }
+        }
+
+        impl Round for $wrapper<$type> {
+            #[inline(always)]
+            fn round(self) -> Self {
+                self
+            }
+            fn round_ties_even(self) -> Self {
+                self
+            }
+            fn floor(self) -> Self {
+                self
+            }
+            fn ceil(self) -> Self {
+                self
+            }
+            fn trunc(self) -> Self {
+                self
+            }
+            fn atrunc(self) -> Self {
+                self
+            }
+            fn fract(self) -> Self {
+                $wrapper(0)
+            }
+
+            fn round_to_multiple(self, step: Self) -> Self {
+                $wrapper(self.0.round_to_multiple(step.0))
+            }
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                $wrapper(self.0.round_to_decimals(decimals))
+            }
+        }
+
+        impl WholeEquivalent for $wrapper<$type> {
+            type Whole = Self;
+
+            fn iround(self) -> Self::Whole {
+                self
+            }
+            fn ifloor(self) -> Self::Whole {
+                self
+            }
+            fn iceil(self) -> Self::Whole {
+                self
+            }
+            fn itrunc(self) -> Self::Whole {
+                self
+            }
+            fn iatrunc(self) -> Self::Whole {
+                self
+            }
+        }
+
+        impl Signed for $wrapper<$type> {
+            type SignMapped = Sign;
+            type BitSignMapped = BitSign;
+            type BoolMapped = bool;
+
+            fn sign(&self) -> Sign {
+                self.0.sign()
+            }
+            fn bit_sign(&self) -> BitSign {
+                self.0.bit_sign()
+            }
+
+            fn is_positive(&self) -> Self::BoolMapped {
+                Signed::is_positive(&self.0)
+            }
+            fn is_negative(&self) -> Self::BoolMapped {
+                Signed::is_negative(&self.0)
+            }
+
+            fn is_zero(&self) -> Self::BoolMapped {
+                self.0.is_zero()
+            }
+
+            fn is_bin_positive(&self) -> Self::BoolMapped {
+                self.0.is_bin_positive()
+            }
+            fn is_bin_negative(&self) -> Self::BoolMapped {
+                self.0.is_bin_negative()
+            }
+        }
+
+        impl Zero for $wrapper<$type> {
+            fn zero() -> Self {
+                $wrapper(0)
+            }
+        }
+        impl ConstZero for $wrapper<$type> {
+            const ZERO: Self = $wrapper(0);
+        }
+
+        impl One for $wrapper<$type> {
+            fn one() -> Self {
+                $wrapper(1)
+            }
+        }
+        impl ConstOne for $wrapper<$type> {
+            const ONE: Self = $wrapper(1);
+        }
+
+        impl Signum for $wrapper<$type> {
+            fn signumt(self) -> Self {
+                $wrapper(self.0.signumt())
+            }
+            fn bin_signum(self) -> Self {
+                $wrapper(self.0.bin_signum())
+            }
+        }
+    };
+}
+
+macro_rules! uint_impl {
+    ($type:ident) => {
+        wrapper_impl!(Wrapping<$type>, wrapping_pow);
+        wrapper_impl!(Saturating<$type>, saturating_pow);
+
+        impl Positive for Wrapping<$type> {
+            fn abs(self) -> Self {
+                self
+            }
+        }
+        impl NotNegative for Wrapping<$type> {}
+
+        impl Positive for Saturating<$type> {
+            fn abs(self) -> Self {
+                self
+            }
+        }
+        impl NotNegative for Saturating<$type> {}
+    };
+}
+uint_impl!(u8);
+uint_impl!(u16);
+uint_impl!(u32);
+uint_impl!(u64);
+uint_impl!(u128);
+uint_impl!(usize);
+
+macro_rules! sint_impl {
+    ($type:ident) => {
+        wrapper_impl!(Wrapping<$type>, wrapping_pow);
+        wrapper_impl!(Saturating<$type>, saturating_pow);
+
+        impl Positive for Wrapping<$type> {
+            fn abs(self) -> Self {
+                Wrapping(self.0.wrapping_abs())
+            }
+        }
+        impl Negative for Wrapping<$type> {
+            fn neg_abs(self) -> Self {
+                Wrapping(self.0.wrapping_abs().wrapping_neg())
+            }
+        }
+
+        impl Positive for Saturating<$type> {
+            fn abs(self) -> Self {
+                Saturating(self.0.saturating_abs())
+            }
+        }
+        impl Negative for Saturating<$type> {
+            fn neg_abs(self) -> Self {
+                if self.0 > 0 {
+                    Saturating(-self.0)
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+sint_impl!(i8);
+sint_impl!(i16);
+sint_impl!(i32);
+sint_impl!(i64);
+sint_impl!(i128);
+sint_impl!(isize);
@@ -0,0 +1,2 @@
+mod int_wrappers;
+mod non_zero;
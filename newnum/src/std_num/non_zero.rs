@@ -0,0 +1,178 @@
+use std::num::NonZero;
+
+use crate::*;
+
+macro_rules! non_zero_impl {
+    ($type:ident: MAX_LITERAL = $max_literal:expr) => {
+        impl Signed for NonZero<$type> {
+            type SignMapped = Sign;
+            type BitSignMapped = BitSign;
+            type BoolMapped = bool;
+
+            #[inline(always)]
+            fn sign(&self) -> Sign {
+                self.get().sign()
+            }
+            #[inline(always)]
+            fn bit_sign(&self) -> BitSign {
+                self.get().bit_sign()
+            }
+
+            #[inline(always)]
+            fn is_positive(&self) -> Self::BoolMapped {
+                Signed::is_positive(&self.get())
+            }
+            #[inline(always)]
+            fn is_negative(&self) -> Self::BoolMapped {
+                Signed::is_negative(&self.get())
+            }
+
+            #[inline(always)]
+            fn is_zero(&self) -> Self::BoolMapped {
+                false
+            }
+
+            #[inline(always)]
+            fn is_bin_positive(&self) -> Self::BoolMapped {
+                self.get().is_bin_positive()
+            }
+            #[inline(always)]
+            fn is_bin_negative(&self) -> Self::BoolMapped {
+                self.get().is_bin_negative()
+            }
+        }
+
+        impl NotZero for NonZero<$type> {}
+
+        impl MinMax for NonZero<$type> {
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                Ord::clamp(self, min, max)
+            }
+        }
+
+        impl TypeMin for NonZero<$type> {
+            #[inline(always)]
+            fn type_min() -> Self {
+                Self::MIN
+            }
+        }
+        impl TypeMax for NonZero<$type> {
+            #[inline(always)]
+            fn type_max() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl AbsDiff for NonZero<$type> {
+            type Output = $type;
+
+            #[inline(always)]
+            fn abs_diff(self, rhs: Self) -> Self::Output {
+                AbsDiff::abs_diff(self.get(), rhs.get())
+            }
+        }
+
+        // Roots of non-zero integers are never zero, so unwrapping the results never panics.
+        impl TruncRoot for NonZero<$type> {
+            #[inline(always)]
+            fn trunc_sqrt(self) -> Self {
+                NonZero::new(self.get().trunc_sqrt()).unwrap()
+            }
+            #[inline(always)]
+            fn trunc_cbrt(self) -> Self {
+                NonZero::new(self.get().trunc_cbrt()).unwrap()
+            }
+            #[inline(always)]
+            fn trunc_nth_root(self, n: u32) -> Self {
+                NonZero::new(self.get().trunc_nth_root(n)).unwrap()
+            }
+
+            #[inline(always)]
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                self.get().checked_exact_sqrt().and_then(NonZero::new)
+            }
+        }
+
+        impl FromIntLiteral for NonZero<$type> {
+            const MIN_LITERAL: i128 = if $type::MIN == 0 {
+                1
+            } else {
+                $type::MIN as i128
+            };
+            const MAX_LITERAL: i128 = $max_literal;
+            const ZERO_LITERAL: bool = false;
+
+            unsafe fn from_int_literal(value: i128) -> Self {
+                NonZero::new(value as $type).expect("zero literal for a non-zero type")
+            }
+
+            unsafe fn approx_from_int_literal(value: i128) -> Self {
+                NonZero::new(value as $type).expect("zero literal for a non-zero type")
+            }
+        }
+    };
+}
+
+macro_rules! uint_impl {
+    ($type:ident) => {
+        uint_impl!($type: MAX_LITERAL = $type::MAX as i128);
+    };
+    ($type:ident: MAX_LITERAL = $max_literal:expr) => {
+        non_zero_impl!($type: MAX_LITERAL = $max_literal);
+
+        impl Positive for NonZero<$type> {
+            #[inline(always)]
+            fn abs(self) -> Self {
+                self
+            }
+        }
+
+        impl NotNegative for NonZero<$type> {}
+    };
+}
+uint_impl!(u8);
+uint_impl!(u16);
+uint_impl!(u32);
+uint_impl!(u64);
+uint_impl!(u128: MAX_LITERAL = i128::MAX);
+uint_impl!(usize);
+
+macro_rules! sint_impl {
+    ($type:ident) => {
+        non_zero_impl!($type: MAX_LITERAL = $type::MAX as i128);
+
+        impl Positive for NonZero<$type> {
+            #[inline(always)]
+            fn abs(self) -> Self {
+                NonZero::<$type>::abs(self)
+            }
+        }
+
+        impl Negative for NonZero<$type> {
+            #[inline(always)]
+            fn neg_abs(self) -> Self {
+                if NonZero::<$type>::is_negative(self) {
+                    self
+                } else {
+                    -self
+                }
+            }
+        }
+    };
+}
+sint_impl!(i8);
+sint_impl!(i16);
+sint_impl!(i32);
+sint_impl!(i64);
+sint_impl!(i128);
+sint_impl!(isize);
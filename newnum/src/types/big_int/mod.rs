@@ -0,0 +1,13 @@
+mod sint;
+mod uint;
+pub use sint::*;
+pub use uint::*;
+
+/// Converts the limbs of a shift amount into `u32`, or returns `None` if it is larger than `u32::MAX`.
+fn shift_amount(limbs: &[u64]) -> Option<u32> {
+    match limbs {
+        [] => Some(0),
+        [limb] => u32::try_from(*limb).ok(),
+        _ => None,
+    }
+}
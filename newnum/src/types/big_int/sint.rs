@@ -0,0 +1,700 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use crate::*;
+
+use super::shift_amount;
+
+/// A heap allocated signed integer that grows as needed.
+///
+/// Implements `Int` and the other integer APIs that make sense for an unbounded type,
+/// but not `IntPrim` which requires `Copy` and a fixed bit width.
+/// Division truncates towards zero and bitwise operators behave like an infinite two's complement,
+/// like for the primitive signed integers.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let value: BigInt = num!(-1_000_000_000_000_000_000_000_000_000_000_000_000_000);
+///
+/// assert_eq!(value.clone().abs().trunc_cbrt(), num!(10_000_000_000_000));
+/// assert_eq!(value.clone() % num!(7), num!(-6));
+/// assert!(value < BigInt::from(i128::MIN));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    /// Is never `true` for `0`.
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    /// Creates a `BigInt` from its sign and absolute value.
+    pub fn from_sign_magnitude(negative: bool, magnitude: BigUint) -> Self {
+        Self {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    /// Returns the absolute value of `self` as a `BigUint`.
+    #[inline(always)]
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// Returns the absolute value of `self` as a `BigUint`.
+    #[inline(always)]
+    pub fn into_magnitude(self) -> BigUint {
+        self.magnitude
+    }
+
+    /// Returns `(self / rhs, self % rhs)` where the division truncates towards zero,
+    /// and panics if `rhs` is `0`.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&rhs.magnitude);
+
+        (
+            Self::from_sign_magnitude(self.negative != rhs.negative, quotient),
+            Self::from_sign_magnitude(self.negative, remainder),
+        )
+    }
+
+    /// Returns `magnitude` with `self`'s sign.
+    #[inline(always)]
+    fn with_sign_of(&self, magnitude: BigUint) -> Self {
+        Self::from_sign_magnitude(self.negative, magnitude)
+    }
+
+    /// Returns the infinite two's complement representation of `self` truncated to `len` limbs.
+    fn twos_complement(&self, len: usize) -> Vec<u64> {
+        let mut limbs = if self.negative {
+            (self.magnitude.clone() - BigUint::one()).into()
+        } else {
+            Vec::from(self.magnitude.clone())
+        };
+        limbs.resize(len, 0);
+
+        if self.negative {
+            for limb in &mut limbs {
+                *limb = !*limb;
+            }
+        }
+
+        limbs
+    }
+
+    /// Inverse of `twos_complement` given the sign of the infinite representation.
+    fn from_twos_complement(negative: bool, mut limbs: Vec<u64>) -> Self {
+        if negative {
+            for limb in &mut limbs {
+                *limb = !*limb;
+            }
+
+            Self::from_sign_magnitude(true, BigUint::from_limbs(limbs) + BigUint::one())
+        } else {
+            Self::from_sign_magnitude(false, BigUint::from_limbs(limbs))
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    #[inline(always)]
+    fn from(value: BigUint) -> Self {
+        Self::from_sign_magnitude(false, value)
+    }
+}
+
+macro_rules! from_int_impl {
+    ($type:ident) => {
+        impl From<$type> for BigInt {
+            fn from(value: $type) -> Self {
+                Self::from_sign_magnitude(
+                    value < 0 as $type,
+                    BigUint::from((value as i128).unsigned_abs()),
+                )
+            }
+        }
+    };
+}
+from_int_impl!(u8);
+from_int_impl!(u16);
+from_int_impl!(u32);
+from_int_impl!(u64);
+from_int_impl!(usize);
+from_int_impl!(i8);
+from_int_impl!(i16);
+from_int_impl!(i32);
+from_int_impl!(i64);
+from_int_impl!(i128);
+from_int_impl!(isize);
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        Self::from(BigUint::from(value))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_string())
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Add for BigInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            self.with_sign_of(self.magnitude.clone() + rhs.magnitude)
+        } else if self.magnitude >= rhs.magnitude {
+            self.with_sign_of(self.magnitude.clone() - rhs.magnitude)
+        } else {
+            rhs.with_sign_of(rhs.magnitude.clone() - self.magnitude)
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for BigInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_sign_magnitude(
+            self.negative != rhs.negative,
+            self.magnitude * rhs.magnitude,
+        )
+    }
+}
+
+impl Div for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::from_sign_magnitude(!self.negative, self.magnitude)
+    }
+}
+
+/// `!x = -x - 1`, like for the primitive signed integers.
+impl Not for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self::Output {
+        -self - Self::one()
+    }
+}
+
+macro_rules! bit_op_impl {
+    ($trait:ident($fn:ident), $op:tt) => {
+        impl $trait for BigInt {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                // One extra limb so the sign of the truncated representations is always correct.
+                let len = Ord::max(self.magnitude.limbs().len(), rhs.magnitude.limbs().len()) + 1;
+
+                Self::from_twos_complement(
+                    self.negative $op rhs.negative,
+                    self.twos_complement(len)
+                        .into_iter()
+                        .zip(rhs.twos_complement(len))
+                        .map(|(lhs, rhs)| lhs $op rhs)
+                        .collect(),
+                )
+            }
+        }
+    };
+}
+bit_op_impl!(BitAnd(bitand), &);
+bit_op_impl!(BitOr(bitor), |);
+bit_op_impl!(BitXor(bitxor), ^);
+
+impl Shl<u32> for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: u32) -> Self::Output {
+        self.with_sign_of(self.magnitude.clone() << rhs)
+    }
+}
+
+/// Rounds towards negative infinity, like for the primitive signed integers.
+impl Shr<u32> for BigInt {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        if self.negative {
+            // `floor(-m / 2^n) = -((m - 1) / 2^n) - 1` for positive `m`.
+            Self::from_sign_magnitude(
+                true,
+                ((self.magnitude - BigUint::one()) >> rhs) + BigUint::one(),
+            )
+        } else {
+            Self::from(self.magnitude >> rhs)
+        }
+    }
+}
+
+/// Panics if `rhs` is negative or larger than `u32::MAX`.
+impl Shl for BigInt {
+    type Output = Self;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.negative, "attempt to shift left with overflow");
+
+        self << shift_amount(rhs.magnitude.limbs()).expect("attempt to shift left with overflow")
+    }
+}
+
+/// Panics if `rhs` is negative.
+impl Shr for BigInt {
+    type Output = Self;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.negative, "attempt to shift right with overflow");
+
+        // Shifting by `u32::MAX` already returns `0` or `-1` for every value that fits in memory.
+        self >> shift_amount(rhs.magnitude.limbs()).unwrap_or(u32::MAX)
+    }
+}
+
+macro_rules! assign_impl {
+    ($trait:ident($fn:ident) => $op_trait:ident($op_fn:ident) $(, $rhs:ty)?) => {
+        impl $trait$(<$rhs>)? for BigInt {
+            #[inline(always)]
+            fn $fn(&mut self, rhs: assign_impl!(@rhs $($rhs)?)) {
+                *self = $op_trait::$op_fn(std::mem::take(self), rhs);
+            }
+        }
+    };
+    (@rhs) => { Self };
+    (@rhs $rhs:ty) => { $rhs };
+}
+assign_impl!(AddAssign(add_assign) => Add(add));
+assign_impl!(SubAssign(sub_assign) => Sub(sub));
+assign_impl!(MulAssign(mul_assign) => Mul(mul));
+assign_impl!(DivAssign(div_assign) => Div(div));
+assign_impl!(RemAssign(rem_assign) => Rem(rem));
+assign_impl!(BitAndAssign(bitand_assign) => BitAnd(bitand));
+assign_impl!(BitOrAssign(bitor_assign) => BitOr(bitor));
+assign_impl!(BitXorAssign(bitxor_assign) => BitXor(bitxor));
+assign_impl!(ShlAssign(shl_assign) => Shl(shl));
+assign_impl!(ShrAssign(shr_assign) => Shr(shr));
+assign_impl!(ShlAssign(shl_assign) => Shl(shl), u32);
+assign_impl!(ShrAssign(shr_assign) => Shr(shr), u32);
+
+impl CheckedAdd for BigInt {
+    #[inline(always)]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for BigInt {
+    #[inline(always)]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+}
+
+impl CheckedMul for BigInt {
+    #[inline(always)]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl CheckedDiv for BigInt {
+    #[inline(always)]
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(&rhs).0)
+    }
+}
+
+impl CheckedRem for BigInt {
+    #[inline(always)]
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(&rhs).1)
+    }
+}
+
+impl CheckedNeg for BigInt {
+    #[inline(always)]
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+}
+
+/// Never returns `None` because `BigInt` has no bit limit.
+impl CheckedShl for BigInt {
+    #[inline(always)]
+    fn checked_shl(self, rhs: u32) -> Option<Self> {
+        Some(self << rhs)
+    }
+}
+
+/// Never saturates because `BigInt` has no minimum or maximum.
+impl SaturatingOps for BigInt {
+    #[inline(always)]
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+    #[inline(always)]
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    #[inline(always)]
+    fn saturating_neg(self) -> Self {
+        -self
+    }
+    #[inline(always)]
+    fn saturating_shl(self, rhs: u32) -> Self {
+        self << rhs
+    }
+    #[inline(always)]
+    fn saturating_pow(self, exp: u32) -> Self {
+        self.pow(exp)
+    }
+}
+
+impl ILog for BigInt {
+    fn ilog2(self) -> u32 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+    #[inline(always)]
+    fn ilog10(self) -> u32 {
+        self.ilog(Self::from(10_u8))
+    }
+    fn ilog(self, base: Self) -> u32 {
+        assert!(
+            base >= Self::from(2_u8),
+            "base of integer logarithm must be at least 2"
+        );
+
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    #[inline(always)]
+    fn checked_ilog2(self) -> Option<u32> {
+        if self.negative {
+            None
+        } else {
+            self.magnitude.checked_ilog2()
+        }
+    }
+    #[inline(always)]
+    fn checked_ilog10(self) -> Option<u32> {
+        self.checked_ilog(Self::from(10_u8))
+    }
+    fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self.negative || base.negative {
+            None
+        } else {
+            self.magnitude.checked_ilog(base.magnitude)
+        }
+    }
+}
+
+impl Signed for BigInt {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    fn sign(&self) -> Sign {
+        if self.negative {
+            Sign::Negative
+        } else {
+            self.magnitude.sign()
+        }
+    }
+    fn bit_sign(&self) -> BitSign {
+        if self.negative {
+            BitSign::Negative
+        } else {
+            BitSign::Positive
+        }
+    }
+
+    fn is_positive(&self) -> Self::BoolMapped {
+        !self.negative && !self.magnitude.is_zero()
+    }
+    fn is_negative(&self) -> Self::BoolMapped {
+        self.negative
+    }
+
+    fn is_zero(&self) -> Self::BoolMapped {
+        self.magnitude.is_zero()
+    }
+
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        !self.negative
+    }
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        self.negative
+    }
+}
+
+impl Positive for BigInt {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Self::from(self.magnitude)
+    }
+}
+
+impl Negative for BigInt {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        Self::from_sign_magnitude(true, self.magnitude)
+    }
+}
+
+impl Signum for BigInt {
+    #[inline(always)]
+    fn signumt(self) -> Self {
+        Self::from_sign_magnitude(self.negative, self.magnitude.signumt())
+    }
+
+    #[inline(always)]
+    fn bin_signum(self) -> Self {
+        Self::from_sign_magnitude(self.negative, BigUint::one())
+    }
+}
+
+impl Zero for BigInt {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl ConstZero for BigInt {
+    const ZERO: Self = Self {
+        negative: false,
+        magnitude: BigUint::ZERO,
+    };
+}
+
+impl One for BigInt {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::from(BigUint::one())
+    }
+}
+
+impl NoTypeMin for BigInt {}
+impl NoTypeMax for BigInt {}
+
+impl AbsDiff for BigInt {
+    type Output = Self;
+
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        (self - rhs).abs()
+    }
+}
+
+impl MinMax for BigInt {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+}
+
+impl Pow<u32> for BigInt {
+    #[inline(always)]
+    fn pow(self, exp: u32) -> Self {
+        Self::from_sign_magnitude(self.negative && exp % 2 == 1, self.magnitude.pow(exp))
+    }
+}
+
+/// Exact for every value.
+/// Odd roots of negative numbers are negative and truncated towards zero, like for the primitive signed integers.
+impl TruncRoot for BigInt {
+    fn trunc_sqrt(self) -> Self {
+        assert!(!self.negative, "even root of a negative number");
+
+        Self::from(self.magnitude.trunc_sqrt())
+    }
+    fn trunc_cbrt(self) -> Self {
+        self.with_sign_of(self.magnitude.clone().trunc_cbrt())
+    }
+
+    fn trunc_nth_root(self, n: u32) -> Self {
+        assert!(
+            n % 2 == 1 || !self.negative,
+            "even root of a negative number"
+        );
+
+        self.with_sign_of(self.magnitude.clone().trunc_nth_root(n))
+    }
+
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        if self.negative {
+            return None;
+        }
+
+        self.magnitude.checked_exact_sqrt().map(Self::from)
+    }
+}
+
+impl Round for BigInt {
+    #[inline(always)]
+    fn round(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn atrunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::zero()
+    }
+
+    #[inline(always)]
+    fn round_to_multiple(self, step: Self) -> Self {
+        self.with_sign_of(self.magnitude.clone().round_to_multiple(step.magnitude))
+    }
+
+    #[inline(always)]
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        self.with_sign_of(self.magnitude.clone().round_to_decimals(decimals))
+    }
+}
+
+impl WholeEquivalent for BigInt {
+    type Whole = Self;
+
+    #[inline(always)]
+    fn iround(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn ifloor(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn iceil(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn itrunc(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn iatrunc(self) -> Self::Whole {
+        self
+    }
+}
+
+impl FromIntLiteral for BigInt {
+    const MIN_LITERAL: i128 = i128::MIN;
+    const MAX_LITERAL: i128 = i128::MAX;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::from(value)
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::from(value)
+    }
+}
+
+impl FromBigIntLiteral for BigInt {
+    #[inline(always)]
+    unsafe fn from_big_int_literal(negative: bool, magnitude: &[u64]) -> Self {
+        Self::from_sign_magnitude(negative, BigUint::from_limbs(magnitude))
+    }
+}
+
+impl Num for BigInt {}
+
+impl Int for BigInt {}
@@ -0,0 +1,868 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use crate::*;
+
+use super::shift_amount;
+
+/// A heap allocated unsigned integer that grows as needed.
+///
+/// Implements `Int` and the other integer APIs that make sense for an unbounded type,
+/// but not `IntPrim` which requires `Copy` and a fixed bit width.
+/// Operators are implemented for owned values, and subtraction panics if the result is negative.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let value: BigUint = num!(340282366920938463463374607431768211456); // 2^128
+///
+/// assert_eq!(value.clone() - num!(1), BigUint::from(u128::MAX));
+/// assert_eq!(value.clone().trunc_sqrt(), BigUint::from(1_u128 << 64));
+/// assert_eq!((value * num!(3)).to_string(), "1020847100762815390390123822295304634368");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian limbs without trailing zero limbs, so `0` has no limbs.
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    /// Creates a `BigUint` from little-endian `u64` limbs.
+    pub fn from_limbs(limbs: impl Into<Vec<u64>>) -> Self {
+        let mut output = Self {
+            limbs: limbs.into(),
+        };
+        output.normalize();
+
+        output
+    }
+
+    /// Returns the little-endian `u64` limbs of `self`, without trailing zero limbs.
+    #[inline(always)]
+    pub fn limbs(&self) -> &[u64] {
+        &self.limbs
+    }
+
+    /// Returns the number of bits needed to represent `self`, which is `0` for `0`.
+    pub fn bit_len(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() as u64 * 64 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns `(self / rhs, self % rhs)`, and panics if `rhs` is `0`.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.limbs.is_empty(), "attempt to divide by zero");
+
+        if self < rhs {
+            (Self::zero(), self.clone())
+        } else if let [rhs] = rhs.limbs.as_slice() {
+            let (quotient, remainder) = self.div_rem_limb(*rhs);
+
+            (quotient, Self::from(remainder))
+        } else {
+            self.div_rem_knuth(rhs)
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if the result is negative.
+    pub fn checked_sub_ref(&self, rhs: &Self) -> Option<Self> {
+        if self < rhs {
+            return None;
+        }
+
+        let mut output = self.clone();
+        let mut borrow = false;
+        for (index, limb) in output.limbs.iter_mut().enumerate() {
+            if index >= rhs.limbs.len() && !borrow {
+                break;
+            }
+
+            let rhs = rhs.limbs.get(index).copied().unwrap_or(0);
+            (*limb, borrow) = WideningMul::borrowing_sub(*limb, rhs, borrow);
+        }
+        output.normalize();
+
+        Some(output)
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    fn add_ref(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut limbs = Vec::with_capacity(long.limbs.len() + 1);
+        let mut carry = false;
+        for (index, long) in long.limbs.iter().enumerate() {
+            let short = short.limbs.get(index).copied().unwrap_or(0);
+
+            let limb;
+            (limb, carry) = WideningMul::carrying_add(*long, short, carry);
+            limbs.push(limb);
+        }
+        if carry {
+            limbs.push(1);
+        }
+
+        Self { limbs }
+    }
+
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        if self.limbs.is_empty() || rhs.limbs.is_empty() {
+            return Self::zero();
+        }
+
+        let mut limbs = vec![0; self.limbs.len() + rhs.limbs.len()];
+        for (i, lhs) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, rhs) in rhs.limbs.iter().enumerate() {
+                // `(2^64 - 1)^2 + 2 * (2^64 - 1) = 2^128 - 1` so this never overflows.
+                let value = limbs[i + j] as u128 + *lhs as u128 * *rhs as u128 + carry;
+                limbs[i + j] = value as u64;
+                carry = value >> 64;
+            }
+
+            limbs[i + rhs.limbs.len()] = carry as u64;
+        }
+
+        Self::from_limbs(limbs)
+    }
+
+    fn div_rem_limb(&self, rhs: u64) -> (Self, u64) {
+        let mut limbs = vec![0; self.limbs.len()];
+        let mut remainder = 0_u128;
+        for (index, limb) in self.limbs.iter().enumerate().rev() {
+            let value = remainder << 64 | *limb as u128;
+            limbs[index] = (value / rhs as u128) as u64;
+            remainder = value % rhs as u128;
+        }
+
+        (Self::from_limbs(limbs), remainder as u64)
+    }
+
+    /// Knuth's algorithm D, for `rhs` of at least two limbs and `self >= rhs`.
+    fn div_rem_knuth(&self, rhs: &Self) -> (Self, Self) {
+        // Normalize so the most significant bit of the divisor is set,
+        // which makes the estimated quotient digits off by at most 2.
+        let shift = rhs.limbs.last().unwrap().leading_zeros();
+        let divisor = (rhs.clone() << shift).limbs;
+        let mut remainder = (self.clone() << shift).limbs;
+        remainder.resize(self.limbs.len() + 1, 0);
+
+        let n = divisor.len();
+        let m = remainder.len() - n;
+        let divisor_high = divisor[n - 1] as u128;
+        let divisor_second = divisor[n - 2] as u128;
+
+        let mut quotient = vec![0; m];
+        for j in (0..m).rev() {
+            let numerator = (remainder[j + n] as u128) << 64 | remainder[j + n - 1] as u128;
+            let mut quotient_digit = numerator / divisor_high;
+            let mut remainder_digit = numerator % divisor_high;
+            while quotient_digit >> 64 != 0
+                || quotient_digit * divisor_second
+                    > (remainder_digit << 64 | remainder[j + n - 2] as u128)
+            {
+                quotient_digit -= 1;
+                remainder_digit += divisor_high;
+                if remainder_digit >> 64 != 0 {
+                    break;
+                }
+            }
+
+            // Multiply and subtract.
+            let mut borrow = 0_i128;
+            let mut carry = 0_u128;
+            for i in 0..n {
+                let product = quotient_digit * divisor[i] as u128 + carry;
+                carry = product >> 64;
+
+                let value = remainder[i + j] as i128 - borrow - (product as u64) as i128;
+                remainder[i + j] = value as u64;
+                borrow = (value < 0) as i128;
+            }
+            let value = remainder[j + n] as i128 - borrow - carry as i128;
+            remainder[j + n] = value as u64;
+
+            // The estimate was one too large, so add the divisor back.
+            if value < 0 {
+                quotient_digit -= 1;
+
+                let mut carry = false;
+                for i in 0..n {
+                    (remainder[i + j], carry) =
+                        WideningMul::carrying_add(remainder[i + j], divisor[i], carry);
+                }
+                remainder[j + n] = remainder[j + n].wrapping_add(carry as u64);
+            }
+
+            quotient[j] = quotient_digit as u64;
+        }
+
+        remainder.truncate(n);
+
+        (
+            Self::from_limbs(quotient),
+            Self::from_limbs(remainder) >> shift,
+        )
+    }
+
+    /// Computes the truncated `n`th root with Newton's method, starting above the root so the iterations decrease.
+    fn nth_root_ref(&self, n: u32) -> Self {
+        assert!(n != 0, "zeroth root is undefined");
+        if self.limbs.is_empty() || n == 1 {
+            return self.clone();
+        }
+
+        // `self < 2^n`, so the root is below `2`.
+        if n as u64 >= self.bit_len() {
+            return Self::one();
+        }
+
+        let n_big = Self::from(n);
+        let n_minus_1 = Self::from(n - 1);
+
+        let mut root = Self::one() << self.bit_len().div_ceil(n as u64) as u32;
+        loop {
+            let next = (n_minus_1.clone() * root.clone()
+                + self.div_rem(&root.clone().pow(n - 1)).0)
+                .div_rem(&n_big)
+                .0;
+            if next >= root {
+                return root;
+            }
+
+            root = next;
+        }
+    }
+}
+
+impl From<BigUint> for Vec<u64> {
+    fn from(value: BigUint) -> Self {
+        value.limbs
+    }
+}
+
+macro_rules! from_uint_impl {
+    ($type:ident) => {
+        impl From<$type> for BigUint {
+            fn from(value: $type) -> Self {
+                let value = value as u128;
+
+                Self::from_limbs([value as u64, (value >> 64) as u64])
+            }
+        }
+    };
+}
+from_uint_impl!(u8);
+from_uint_impl!(u16);
+from_uint_impl!(u32);
+from_uint_impl!(u64);
+from_uint_impl!(u128);
+from_uint_impl!(usize);
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u64 = 10_000_000_000_000_000_000; // 10^19
+
+        let mut chunks = Vec::new();
+        let mut value = self.clone();
+        while !value.limbs.is_empty() {
+            let chunk;
+            (value, chunk) = value.div_rem_limb(CHUNK);
+            chunks.push(chunk);
+        }
+
+        let mut output = match chunks.pop() {
+            Some(chunk) => chunk.to_string(),
+            None => "0".to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            output += &format!("{chunk:019}");
+        }
+
+        f.pad_integral(true, "", &output)
+    }
+}
+
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Add for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_ref(&rhs)
+    }
+}
+
+impl Sub for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub_ref(&rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_ref(&rhs)
+    }
+}
+
+impl Div for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+macro_rules! bit_op_impl {
+    ($trait:ident($fn:ident), $op:tt, $len:ident) => {
+        impl $trait for BigUint {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                let len = Ord::$len(self.limbs.len(), rhs.limbs.len());
+
+                Self::from_limbs(
+                    (0..len)
+                        .map(|index| {
+                            self.limbs.get(index).copied().unwrap_or(0)
+                                $op rhs.limbs.get(index).copied().unwrap_or(0)
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            }
+        }
+    };
+}
+bit_op_impl!(BitAnd(bitand), &, min);
+bit_op_impl!(BitOr(bitor), |, max);
+bit_op_impl!(BitXor(bitxor), ^, max);
+
+impl Shl<u32> for BigUint {
+    type Output = Self;
+
+    fn shl(self, rhs: u32) -> Self::Output {
+        if self.limbs.is_empty() {
+            return self;
+        }
+
+        let limb_shift = (rhs / 64) as usize;
+        let bit_shift = rhs % 64;
+
+        let mut limbs = vec![0; limb_shift];
+        let mut carry = 0;
+        for limb in self.limbs {
+            limbs.push(limb << bit_shift | carry);
+            carry = if bit_shift == 0 {
+                0
+            } else {
+                limb >> (64 - bit_shift)
+            };
+        }
+        limbs.push(carry);
+
+        Self::from_limbs(limbs)
+    }
+}
+
+impl Shr<u32> for BigUint {
+    type Output = Self;
+
+    fn shr(self, rhs: u32) -> Self::Output {
+        let limb_shift = (rhs / 64) as usize;
+        let bit_shift = rhs % 64;
+        if limb_shift >= self.limbs.len() {
+            return Self::zero();
+        }
+
+        let limbs = &self.limbs[limb_shift..];
+        Self::from_limbs(
+            (0..limbs.len())
+                .map(|index| {
+                    let high = limbs.get(index + 1).copied().unwrap_or(0);
+                    if bit_shift == 0 {
+                        limbs[index]
+                    } else {
+                        limbs[index] >> bit_shift | high << (64 - bit_shift)
+                    }
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Panics if `rhs` is larger than `u32::MAX`.
+impl Shl for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shl(self, rhs: Self) -> Self::Output {
+        self << shift_amount(&rhs.limbs).expect("attempt to shift left with overflow")
+    }
+}
+
+impl Shr for BigUint {
+    type Output = Self;
+
+    #[inline(always)]
+    fn shr(self, rhs: Self) -> Self::Output {
+        // Shifting by `u32::MAX` already returns `0` for every value that fits in memory.
+        self >> shift_amount(&rhs.limbs).unwrap_or(u32::MAX)
+    }
+}
+
+macro_rules! assign_impl {
+    ($trait:ident($fn:ident) => $op_trait:ident($op_fn:ident) $(, $rhs:ty)?) => {
+        impl $trait$(<$rhs>)? for BigUint {
+            #[inline(always)]
+            fn $fn(&mut self, rhs: assign_impl!(@rhs $($rhs)?)) {
+                *self = $op_trait::$op_fn(std::mem::take(self), rhs);
+            }
+        }
+    };
+    (@rhs) => { Self };
+    (@rhs $rhs:ty) => { $rhs };
+}
+assign_impl!(AddAssign(add_assign) => Add(add));
+assign_impl!(SubAssign(sub_assign) => Sub(sub));
+assign_impl!(MulAssign(mul_assign) => Mul(mul));
+assign_impl!(DivAssign(div_assign) => Div(div));
+assign_impl!(RemAssign(rem_assign) => Rem(rem));
+assign_impl!(BitAndAssign(bitand_assign) => BitAnd(bitand));
+assign_impl!(BitOrAssign(bitor_assign) => BitOr(bitor));
+assign_impl!(BitXorAssign(bitxor_assign) => BitXor(bitxor));
+assign_impl!(ShlAssign(shl_assign) => Shl(shl));
+assign_impl!(ShrAssign(shr_assign) => Shr(shr));
+assign_impl!(ShlAssign(shl_assign) => Shl(shl), u32);
+assign_impl!(ShrAssign(shr_assign) => Shr(shr), u32);
+
+impl CheckedAdd for BigUint {
+    #[inline(always)]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+
+impl CheckedSub for BigUint {
+    #[inline(always)]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub_ref(&rhs)
+    }
+}
+
+impl CheckedMul for BigUint {
+    #[inline(always)]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+
+impl CheckedDiv for BigUint {
+    #[inline(always)]
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(&rhs).0)
+    }
+}
+
+impl CheckedRem for BigUint {
+    #[inline(always)]
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        (!rhs.is_zero()).then(|| self.div_rem(&rhs).1)
+    }
+}
+
+impl CheckedNeg for BigUint {
+    #[inline(always)]
+    fn checked_neg(self) -> Option<Self> {
+        self.is_zero().then_some(self)
+    }
+}
+
+/// Never returns `None` because `BigUint` has no bit limit.
+impl CheckedShl for BigUint {
+    #[inline(always)]
+    fn checked_shl(self, rhs: u32) -> Option<Self> {
+        Some(self << rhs)
+    }
+}
+
+/// Only subtraction and negation saturate (at `0`), because `BigUint` has no maximum.
+impl SaturatingOps for BigUint {
+    #[inline(always)]
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub_ref(&rhs).unwrap_or_default()
+    }
+    #[inline(always)]
+    fn saturating_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+    #[inline(always)]
+    fn saturating_neg(self) -> Self {
+        Self::zero()
+    }
+    #[inline(always)]
+    fn saturating_shl(self, rhs: u32) -> Self {
+        self << rhs
+    }
+    #[inline(always)]
+    fn saturating_pow(self, exp: u32) -> Self {
+        self.pow(exp)
+    }
+}
+
+impl ILog for BigUint {
+    fn ilog2(self) -> u32 {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+    #[inline(always)]
+    fn ilog10(self) -> u32 {
+        self.ilog(Self::from(10_u8))
+    }
+    fn ilog(self, base: Self) -> u32 {
+        assert!(
+            base >= Self::from(2_u8),
+            "base of integer logarithm must be at least 2"
+        );
+
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+
+    #[inline(always)]
+    fn checked_ilog2(self) -> Option<u32> {
+        self.bit_len().checked_sub(1).map(|log| log as u32)
+    }
+    #[inline(always)]
+    fn checked_ilog10(self) -> Option<u32> {
+        self.checked_ilog(Self::from(10_u8))
+    }
+    fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self.is_zero() || base < Self::from(2_u8) {
+            return None;
+        }
+
+        // The largest `log` where `base^log <= self`.
+        let mut log = 0;
+        let mut power = base.clone();
+        while power <= self {
+            power = power.mul_ref(&base);
+            log += 1;
+        }
+
+        Some(log)
+    }
+}
+
+impl Signed for BigUint {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    fn sign(&self) -> Sign {
+        if self.limbs.is_empty() {
+            Sign::Zero
+        } else {
+            Sign::Positive
+        }
+    }
+    fn bit_sign(&self) -> BitSign {
+        BitSign::Positive
+    }
+
+    fn is_positive(&self) -> Self::BoolMapped {
+        !self.limbs.is_empty()
+    }
+    fn is_negative(&self) -> Self::BoolMapped {
+        false
+    }
+
+    fn is_zero(&self) -> Self::BoolMapped {
+        self.limbs.is_empty()
+    }
+
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        true
+    }
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        false
+    }
+}
+
+impl Positive for BigUint {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        self
+    }
+}
+
+impl NotNegative for BigUint {}
+
+impl Signum for BigUint {
+    #[inline(always)]
+    fn signumt(self) -> Self {
+        if self.is_zero() {
+            self
+        } else {
+            Self::one()
+        }
+    }
+
+    #[inline(always)]
+    fn bin_signum(self) -> Self {
+        Self::one()
+    }
+}
+
+impl Zero for BigUint {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl ConstZero for BigUint {
+    const ZERO: Self = Self { limbs: Vec::new() };
+}
+
+impl One for BigUint {
+    #[inline(always)]
+    fn one() -> Self {
+        Self { limbs: vec![1] }
+    }
+}
+
+impl TypeMin for BigUint {
+    #[inline(always)]
+    fn type_min() -> Self {
+        Self::ZERO
+    }
+}
+
+impl NoTypeMax for BigUint {}
+
+impl AbsDiff for BigUint {
+    type Output = Self;
+
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        if self >= rhs {
+            self - rhs
+        } else {
+            rhs - self
+        }
+    }
+}
+
+impl MinMax for BigUint {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+}
+
+impl Pow<u32> for BigUint {
+    fn pow(self, exp: u32) -> Self {
+        let mut output = Self::one();
+        let mut base = self;
+        let mut exp = exp;
+        while exp != 0 {
+            if exp % 2 == 1 {
+                output = output.mul_ref(&base);
+            }
+
+            exp /= 2;
+            if exp != 0 {
+                base = base.mul_ref(&base);
+            }
+        }
+
+        output
+    }
+}
+
+/// Exact for every value.
+impl TruncRoot for BigUint {
+    #[inline(always)]
+    fn trunc_sqrt(self) -> Self {
+        self.nth_root_ref(2)
+    }
+    #[inline(always)]
+    fn trunc_cbrt(self) -> Self {
+        self.nth_root_ref(3)
+    }
+    #[inline(always)]
+    fn trunc_nth_root(self, n: u32) -> Self {
+        self.nth_root_ref(n)
+    }
+
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        let sqrt = self.nth_root_ref(2);
+
+        (sqrt.mul_ref(&sqrt) == self).then_some(sqrt)
+    }
+}
+
+impl Round for BigUint {
+    #[inline(always)]
+    fn round(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn atrunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::zero()
+    }
+
+    fn round_to_multiple(self, step: Self) -> Self {
+        let remainder = self.div_rem(&step).1;
+        let rounded_down = self - remainder.clone();
+
+        if remainder.clone() >= step.clone() - remainder {
+            rounded_down + step
+        } else {
+            rounded_down
+        }
+    }
+
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        if decimals >= 0 {
+            self
+        } else {
+            self.round_to_multiple(Self::from(10_u8).pow(decimals.unsigned_abs()))
+        }
+    }
+}
+
+impl WholeEquivalent for BigUint {
+    type Whole = Self;
+
+    #[inline(always)]
+    fn iround(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn ifloor(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn iceil(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn itrunc(self) -> Self::Whole {
+        self
+    }
+    #[inline(always)]
+    fn iatrunc(self) -> Self::Whole {
+        self
+    }
+}
+
+impl FromIntLiteral for BigUint {
+    const MIN_LITERAL: i128 = 0;
+    const MAX_LITERAL: i128 = i128::MAX;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::from(value as u128)
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::from(value as u128)
+    }
+}
+
+impl FromBigIntLiteral for BigUint {
+    #[inline(always)]
+    unsafe fn from_big_int_literal(_negative: bool, magnitude: &[u64]) -> Self {
+        Self::from_limbs(magnitude)
+    }
+}
+
+impl Num for BigUint {}
+
+impl Int for BigUint {}
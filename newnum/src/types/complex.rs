@@ -0,0 +1,547 @@
+use std::fmt::Display;
+
+use crate::*;
+
+/// A complex number `re + im * i`.
+///
+/// Arithmetic is implemented for every `T: Num + Copy` (so `Complex<Ratio<i64>>` is exact),
+/// and roots, trigonometric, exponential and logarithmic functions are implemented for `T: Float`,
+/// returning the principal value (for example `sqrt(-4) = 2i` and `cbrt(-8) = 1 + 1.732i`).
+///
+/// Complex numbers have no ordering,
+/// so `Complex` doesn't implement `Num` or the traits that need an ordering (`Signed`, `MinMax`, `Round`...),
+/// but it implements every other API trait it can,
+/// so it works with code that is generic over only the traits it needs (like `T: Trig<Output = T>`).
+///
+/// Literals (`num!(2.5)`) create complex numbers with a zero imaginary part.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let z = Complex::new(3.0, 4.0);
+///
+/// assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+/// assert_eq!(z.norm(), 5.0);
+/// assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+/// assert_eq!(Complex::new(1.0, -2.5).to_string(), "1-2.5i");
+///
+/// let exact: Complex<Ratio<i64>> = Complex::new(num!(0.5), num!(1));
+/// assert_eq!(exact * exact, Complex::new(num!(-0.75), num!(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Complex<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// Creates the complex number `re + im * i`.
+    #[inline(always)]
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: ConstZero + ConstOne> Complex<T> {
+    /// The imaginary unit `i`.
+    pub const I: Self = Self::new(T::ZERO, T::ONE);
+}
+
+impl<T: Float> Complex<T> {
+    /// Creates a complex number from its norm and its argument (in radians).
+    #[inline(always)]
+    pub fn from_polar(norm: T, arg: T) -> Self {
+        Self::new(norm * arg.cos(), norm * arg.sin())
+    }
+
+    /// Returns `(self.norm(), self.arg())`.
+    #[inline(always)]
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    #[inline(always)]
+    fn scale(self, factor: T) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+
+    /// Adds the real `value`, keeping the sign of a zero imaginary part which decides the side of branch cuts.
+    #[inline(always)]
+    fn add_real(self, value: T) -> Self {
+        Self::new(self.re + value, self.im)
+    }
+
+    /// Computes `self * i`.
+    #[inline(always)]
+    fn mul_i(self) -> Self {
+        Self::new(-self.im, self.re)
+    }
+
+    /// Computes `self * -i`.
+    #[inline(always)]
+    fn mul_neg_i(self) -> Self {
+        Self::new(self.im, -self.re)
+    }
+
+    #[inline(always)]
+    fn trunc_parts(self) -> Self {
+        Self::new(self.re.trunc(), self.im.trunc())
+    }
+}
+
+/// Computes the angle of `(x, y)` from the positive x axis in the range `[-π, π]`,
+/// which `Float` doesn't provide, using `atan` and the quadrant of `(x, y)`.
+fn atan2<T: Float>(y: T, x: T) -> T {
+    if x.is_nan() || y.is_nan() {
+        x + y
+    } else if Signed::is_positive(&x) {
+        (y / x).atan()
+    } else if Signed::is_negative(&x) {
+        if y.is_bin_negative() {
+            (y / x).atan() - T::PI
+        } else {
+            (y / x).atan() + T::PI
+        }
+    } else if Signed::is_positive(&y) {
+        T::FRAC_PI_2
+    } else if Signed::is_negative(&y) {
+        -T::FRAC_PI_2
+    } else if x.is_bin_negative() {
+        if y.is_bin_negative() {
+            -T::PI
+        } else {
+            T::PI
+        }
+    } else {
+        y
+    }
+}
+
+/// Writes `re+imi` or `re-imi`.
+///
+/// The sign of the imaginary part is taken from its `Display` output,
+/// so it doesn't rely on `T` supporting the `+` flag.
+impl<T: Display> Display for Complex<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let im = self.im.to_string();
+        match im.strip_prefix('-') {
+            Some(abs) => write!(f, "{}-{abs}i", self.re),
+            None => write!(f, "{}+{im}i", self.re),
+        }
+    }
+}
+
+impl<T: Num> Add for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Num> Sub for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Num + Copy> Mul for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+/// Computes `(a + bi) / (c + di) = ((ac + bd) + (bc - ad)i) / (c^2 + d^2)`,
+/// which is exact for exact types but can overflow for floats with a very large `rhs`.
+impl<T: Num + Copy> Div for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let denom = rhs.re * rhs.re + rhs.im * rhs.im;
+
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+macro_rules! assign_op_impl {
+    ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident)) => {
+        impl<T: Num + Copy> $assign_trait for Complex<T> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+assign_op_impl!(Add(add), AddAssign(add_assign));
+assign_op_impl!(Sub(sub), SubAssign(sub_assign));
+assign_op_impl!(Mul(mul), MulAssign(mul_assign));
+assign_op_impl!(Div(div), DivAssign(div_assign));
+
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Neg<Output = T>> Conj for Complex<T> {
+    #[inline(always)]
+    fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+}
+
+impl<T: Float> Norm for Complex<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn norm(self) -> Self::Output {
+        self.re.hypot(self.im)
+    }
+    #[inline(always)]
+    fn norm_sqr(self) -> Self::Output {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Float> Arg for Complex<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn arg(self) -> Self::Output {
+        atan2(self.im, self.re)
+    }
+}
+
+impl<T: One + Zero> One for Complex<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T: ConstOne + ConstZero> ConstOne for Complex<T> {
+    const ONE: Self = Self::new(T::ONE, T::ZERO);
+}
+
+impl<T: Num + Copy + One + Zero> Pow<u32> for Complex<T> {
+    fn pow(self, exp: u32) -> Self {
+        let mut output = Self::one();
+        let mut base = self;
+        let mut exp = exp;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                output *= base;
+            }
+
+            exp >>= 1;
+            if exp != 0 {
+                base *= base;
+            }
+        }
+
+        output
+    }
+}
+
+/// Truncated roots truncate both parts of the principal root.
+impl<T: Float> TruncRoot for Complex<T> {
+    #[inline(always)]
+    fn trunc_sqrt(self) -> Self {
+        self.sqrt().trunc_parts()
+    }
+    #[inline(always)]
+    fn trunc_cbrt(self) -> Self {
+        self.cbrt().trunc_parts()
+    }
+
+    #[inline(always)]
+    fn trunc_nth_root(self, n: u32) -> Self {
+        self.nth_root(n).trunc_parts()
+    }
+
+    /// Returns the principal square root if it is a Gaussian integer (both parts are whole).
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        let sqrt = self.sqrt();
+        if sqrt.re.fract().is_zero() && sqrt.im.fract().is_zero() && sqrt * sqrt == self {
+            Some(sqrt)
+        } else {
+            None
+        }
+    }
+}
+
+/// Every root is the principal root, which has the smallest argument,
+/// so the cube root of a negative real number is not real (`cbrt(-8) = 1 + 1.732i`).
+///
+/// `nth_root` returns `NaN` parts for `n = 0`.
+impl<T: Float> Root for Complex<T> {
+    fn sqrt(self) -> Self {
+        if self.re.is_zero() && self.im.is_zero() {
+            return Self::new(T::ZERO, self.im);
+        }
+
+        // Computes the larger part first and derives the smaller one from it, to avoid cancellation.
+        let larger = ((self.re.abs() + self.norm()) * internal_num!(0.5)).sqrt();
+        let smaller = self.im.abs() / (larger + larger);
+
+        if self.re.is_bin_negative() {
+            Self::new(
+                smaller,
+                if self.im.is_bin_negative() {
+                    -larger
+                } else {
+                    larger
+                },
+            )
+        } else {
+            Self::new(
+                larger,
+                if self.im.is_bin_negative() {
+                    -smaller
+                } else {
+                    smaller
+                },
+            )
+        }
+    }
+
+    fn cbrt(self) -> Self {
+        if self.im.is_zero() && !self.re.is_bin_negative() {
+            Self::new(self.re.cbrt(), self.im)
+        } else {
+            Self::from_polar(self.norm().cbrt(), self.arg() / internal_num!(3))
+        }
+    }
+
+    fn nth_root(self, n: u32) -> Self {
+        Self::from_polar(
+            self.norm().nth_root(n),
+            self.arg() / T::saturating_from_num(n),
+        )
+    }
+
+    #[inline(always)]
+    fn rsqrt(self) -> Self {
+        Self::one() / self.sqrt()
+    }
+}
+
+impl<T: Float> Exp for Complex<T> {
+    #[inline(always)]
+    fn exp(self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        self.scale(T::LN_2).exp()
+    }
+
+    /// Uses `e^(a + bi) - 1 = (e^a - 1) * cos(b) - 2 * sin(b / 2)^2 + e^a * sin(b) * i`,
+    /// which is accurate for small values.
+    fn exp_m1(self) -> Self {
+        let half_sin = (self.im * internal_num!(0.5)).sin();
+
+        Self::new(
+            self.re.exp_m1() * self.im.cos() - (half_sin + half_sin) * half_sin,
+            self.re.exp() * self.im.sin(),
+        )
+    }
+}
+
+/// Every logarithm is the principal logarithm, which has an imaginary part in the range `[-π, π]`.
+impl<T: Float> Log for Complex<T> {
+    #[inline(always)]
+    fn ln(self) -> Self {
+        Self::new(self.norm().ln(), self.arg())
+    }
+
+    #[inline(always)]
+    fn log2(self) -> Self {
+        self.ln().scale(T::LOG2_E)
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Self {
+        self.ln().scale(T::LOG10_E)
+    }
+
+    #[inline(always)]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Uses `ln|1 + z| = ln_1p(2a + a^2 + b^2) / 2`, which is accurate for small values.
+    fn ln_1p(self) -> Self {
+        Self::new(
+            (self.re + self.re + self.re * self.re + self.im * self.im).ln_1p()
+                * internal_num!(0.5),
+            atan2(self.im, T::ONE + self.re),
+        )
+    }
+}
+
+impl<T: Float> Trig for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sin(self) -> Self::Output {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self::Output {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -(self.re.sin() * self.im.sinh()),
+        )
+    }
+
+    /// Uses `tan(a + bi) = (sin(2a) + sinh(2b) * i) / (cos(2a) + cosh(2b))`.
+    fn tan(self) -> Self::Output {
+        let (re, im) = (self.re + self.re, self.im + self.im);
+        let denom = re.cos() + im.cosh();
+
+        Self::new(re.sin() / denom, im.sinh() / denom)
+    }
+}
+
+/// Uses `asin(z) = -i * asinh(i * z)`, `acos(z) = π/2 - asin(z)` and `atan(z) = -i * atanh(i * z)`.
+impl<T: Float> ATrig for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn asin(self) -> Self::Output {
+        self.mul_i().asinh().mul_neg_i()
+    }
+
+    #[inline(always)]
+    fn acos(self) -> Self::Output {
+        let asin = self.asin();
+
+        Self::new(T::FRAC_PI_2 - asin.re, -asin.im)
+    }
+
+    #[inline(always)]
+    fn atan(self) -> Self::Output {
+        self.mul_i().atanh().mul_neg_i()
+    }
+}
+
+impl<T: Float> Hyper for Complex<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sinh(self) -> Self::Output {
+        Self::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
+    }
+
+    #[inline(always)]
+    fn cosh(self) -> Self::Output {
+        Self::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
+
+    /// Uses `tanh(a + bi) = (sinh(2a) + sin(2b) * i) / (cosh(2a) + cos(2b))`.
+    fn tanh(self) -> Self::Output {
+        let (re, im) = (self.re + self.re, self.im + self.im);
+        let denom = re.cosh() + im.cos();
+
+        Self::new(re.sinh() / denom, im.sin() / denom)
+    }
+}
+
+/// Uses the logarithmic formulas of the inverse hyperbolic functions with the principal `ln` and `sqrt`.
+impl<T: Float> AHyper for Complex<T> {
+    type Output = Self;
+
+    fn asinh(self) -> Self::Output {
+        // `asinh` is odd, and `z + sqrt(z^2 + 1)` cancels for a negative real part.
+        if self.re.is_bin_negative() {
+            return -(-self).asinh();
+        }
+
+        // `z^2 + 1` computed without adding a zero imaginary part.
+        let square_plus_one = Self::new(
+            T::ONE + (self.re - self.im) * (self.re + self.im),
+            (self.re + self.re) * self.im,
+        );
+
+        (self + square_plus_one.sqrt()).ln()
+    }
+
+    fn acosh(self) -> Self::Output {
+        (self + self.add_real(T::ONE).sqrt() * self.add_real(-T::ONE).sqrt()).ln()
+    }
+
+    fn atanh(self) -> Self::Output {
+        (self.add_real(T::ONE).ln() - (-self).add_real(T::ONE).ln()).scale(internal_num!(0.5))
+    }
+}
+
+impl<T: FromIntLiteral + Zero> FromIntLiteral for Complex<T> {
+    const MIN_LITERAL: i128 = T::MIN_LITERAL;
+    const MAX_LITERAL: i128 = T::MAX_LITERAL;
+    const MIN_APPROX_LITERAL: i128 = T::MIN_APPROX_LITERAL;
+    const MAX_APPROX_LITERAL: i128 = T::MAX_APPROX_LITERAL;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::new(T::from_int_literal(value), T::zero())
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::new(T::approx_from_int_literal(value), T::zero())
+    }
+}
+
+impl<T: FromFloatLiteral + Zero> FromFloatLiteral for Complex<T> {
+    const MIN_FLOAT_LITERAL: f64 = T::MIN_FLOAT_LITERAL;
+    const MAX_FLOAT_LITERAL: f64 = T::MAX_FLOAT_LITERAL;
+
+    #[inline(always)]
+    unsafe fn from_float_literal(value: f64) -> Self {
+        Self::new(T::from_float_literal(value), T::zero())
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_float_literal(value: f64) -> Self {
+        Self::new(T::approx_from_float_literal(value), T::zero())
+    }
+
+    #[inline(always)]
+    unsafe fn from_decimal_literal(value: f64, digits: i128, exponent: i32) -> Self {
+        Self::new(T::from_decimal_literal(value, digits, exponent), T::zero())
+    }
+}
@@ -0,0 +1,840 @@
+use std::{cmp::Ordering, fmt::Display, num::FpCategory};
+
+use crate::*;
+
+/// A dual number for forward-mode automatic differentiation,
+/// which is a value together with its derivatives by `N` variables (its gradient).
+///
+/// Every operation computes its result's value,
+/// and propagates the derivatives with the chain rule (`f(x)' = f'(x) * x'`),
+/// so calling a generic function with a `DualN` computes both the function and its gradient.
+/// Use [`Dual`] for a single variable.
+///
+/// `DualN` implements `Float` (but not `FloatPrim`, which requires a byte representation and `as` casts),
+/// so functions that are generic over `Float` can be differentiated without changes.
+/// `T` can be a `DualN` itself for higher order derivatives.
+///
+/// Comparisons (`==`, `<`, `Signed`, `MinMax`) and `FloatClass` only look at values, so branches in generic code pick the same path for `T` and `DualN<T, N>`.
+/// Rounding functions have a zero derivative, and `fract` has the derivative of `self`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// // f(x, y) = x * sin(y) + e^x
+/// fn f<T: Float>(x: T, y: T) -> T {
+///     x * y.sin() + x.exp()
+/// }
+///
+/// let x = DualN::<f64, 2>::variable(1.0, 0);
+/// let y = DualN::<f64, 2>::variable(0.5, 1);
+/// let output = f(x, y);
+///
+/// assert_eq!(output.value(), f(1.0, 0.5));
+/// assert_eq!(output.gradient(), [0.5_f64.sin() + 1_f64.exp(), 0.5_f64.cos()]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DualN<T, const N: usize> {
+    value: T,
+    gradient: [T; N],
+}
+
+/// A dual number with the derivative by a single variable (see [`DualN`]).
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let x = Dual::variable(2.0, 0);
+/// let output = x * x * x + x.sqrt();
+///
+/// assert_eq!(output.value(), 8.0 + 2_f64.sqrt());
+/// assert_eq!(output.derivative(), 12.0 + 0.5 / 2_f64.sqrt());
+/// ```
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T: Float, const N: usize> DualN<T, N> {
+    /// Creates a dual number from its value and its derivatives by each variable.
+    #[inline(always)]
+    pub const fn new(value: T, gradient: [T; N]) -> Self {
+        Self { value, gradient }
+    }
+
+    /// Creates a constant, which has a zero derivative by every variable.
+    #[inline(always)]
+    pub const fn constant(value: T) -> Self {
+        Self::new(value, [T::ZERO; N])
+    }
+
+    /// Creates the variable at `index`, which has a derivative of `1` by itself and `0` by every other variable.
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn variable(value: T, index: usize) -> Self {
+        assert!(index < N, "variable index out of range");
+
+        let mut gradient = [T::ZERO; N];
+        gradient[index] = T::ONE;
+
+        Self::new(value, gradient)
+    }
+
+    /// Returns the value.
+    #[inline(always)]
+    pub const fn value(self) -> T {
+        self.value
+    }
+
+    /// Returns the derivatives of the value by each variable.
+    #[inline(always)]
+    pub const fn gradient(self) -> [T; N] {
+        self.gradient
+    }
+
+    /// Applies a function with the value `value` and the derivative `derivative` at `self.value`,
+    /// scaling the gradient by `derivative` (the chain rule).
+    #[inline(always)]
+    fn chain(self, value: T, derivative: T) -> Self {
+        Self::new(value, self.gradient.map(|partial| partial * derivative))
+    }
+
+    #[inline(always)]
+    fn zip_gradient(self, rhs: Self, f: impl Fn(T, T) -> T) -> [T; N] {
+        std::array::from_fn(|index| f(self.gradient[index], rhs.gradient[index]))
+    }
+}
+
+impl<T: Float> Dual<T> {
+    /// Returns the derivative of the value by the variable.
+    #[inline(always)]
+    pub const fn derivative(self) -> T {
+        self.gradient[0]
+    }
+}
+
+impl<T: Float, const N: usize> PartialEq for DualN<T, N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Float, const N: usize> PartialOrd for DualN<T, N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// Formats the value followed by each partial derivative, like `3+2ε` for a `Dual`,
+/// or `3+2ε0-1ε1` with the variable index for multiple variables.
+impl<T: Float, const N: usize> Display for DualN<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)?;
+
+        for (index, partial) in self.gradient.iter().enumerate() {
+            let partial = partial.to_string();
+            match partial.strip_prefix('-') {
+                Some(abs) => write!(f, "-{abs}ε")?,
+                None => write!(f, "+{partial}ε")?,
+            }
+
+            if N > 1 {
+                write!(f, "{index}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Float, const N: usize> Default for DualN<T, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<T: Float, const N: usize> Add for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value + rhs.value,
+            self.zip_gradient(rhs, |lhs, rhs| lhs + rhs),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Sub for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value - rhs.value,
+            self.zip_gradient(rhs, |lhs, rhs| lhs - rhs),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Mul for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.zip_gradient(rhs, |lhs_partial, rhs_partial| {
+                lhs_partial * rhs.value + self.value * rhs_partial
+            }),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Div for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+
+        Self::new(
+            value,
+            self.zip_gradient(rhs, |lhs_partial, rhs_partial| {
+                (lhs_partial - value * rhs_partial) / rhs.value
+            }),
+        )
+    }
+}
+
+/// Uses `a % b = a - trunc(a / b) * b` where `trunc(a / b)` has a zero derivative.
+impl<T: Float, const N: usize> Rem for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quotient = (self.value / rhs.value).trunc();
+
+        Self::new(
+            self.value % rhs.value,
+            self.zip_gradient(rhs, |lhs_partial, rhs_partial| {
+                lhs_partial - quotient * rhs_partial
+            }),
+        )
+    }
+}
+
+macro_rules! assign_op_impl {
+    ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident)) => {
+        impl<T: Float, const N: usize> $assign_trait for DualN<T, N> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+assign_op_impl!(Add(add), AddAssign(add_assign));
+assign_op_impl!(Sub(sub), SubAssign(sub_assign));
+assign_op_impl!(Mul(mul), MulAssign(mul_assign));
+assign_op_impl!(Div(div), DivAssign(div_assign));
+assign_op_impl!(Rem(rem), RemAssign(rem_assign));
+
+impl<T: Float, const N: usize> Neg for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, self.gradient.map(|partial| -partial))
+    }
+}
+
+impl<T: Float, const N: usize> Signed for DualN<T, N> {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    #[inline(always)]
+    fn sign(&self) -> Sign {
+        self.value.sign()
+    }
+    #[inline(always)]
+    fn bit_sign(&self) -> BitSign {
+        self.value.bit_sign()
+    }
+
+    #[inline(always)]
+    fn is_positive(&self) -> Self::BoolMapped {
+        Signed::is_positive(&self.value)
+    }
+    #[inline(always)]
+    fn is_negative(&self) -> Self::BoolMapped {
+        Signed::is_negative(&self.value)
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> Self::BoolMapped {
+        Signed::is_zero(&self.value)
+    }
+
+    #[inline(always)]
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        self.value.is_bin_positive()
+    }
+    #[inline(always)]
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        self.value.is_bin_negative()
+    }
+}
+
+impl<T: Float, const N: usize> Signum for DualN<T, N> {
+    #[inline(always)]
+    fn signumt(self) -> Self {
+        Self::constant(self.value.signumt())
+    }
+
+    #[inline(always)]
+    fn bin_signum(self) -> Self {
+        Self::constant(self.value.bin_signum())
+    }
+}
+
+impl<T: Float, const N: usize> Positive for DualN<T, N> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        if self.value.is_bin_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Negative for DualN<T, N> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        if self.value.is_bin_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Zero for DualN<T, N> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<T: Float, const N: usize> ConstZero for DualN<T, N> {
+    const ZERO: Self = Self::constant(T::ZERO);
+}
+
+impl<T: Float, const N: usize> One for DualN<T, N> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<T: Float, const N: usize> ConstOne for DualN<T, N> {
+    const ONE: Self = Self::constant(T::ONE);
+}
+
+impl<T: Float, const N: usize> AbsDiff for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        (self - rhs).abs()
+    }
+}
+
+impl<T: Float, const N: usize> MinMax for DualN<T, N> {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        if other.value < self.value {
+            other
+        } else {
+            self
+        }
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        if other.value > self.value {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Float, const N: usize> Pow<u32> for DualN<T, N> {
+    #[inline(always)]
+    fn pow(self, exp: u32) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+
+        self.chain(
+            Pow::<u32>::pow(self.value, exp),
+            T::saturating_from_num(exp) * Pow::<u32>::pow(self.value, exp - 1),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Pow<i32> for DualN<T, N> {
+    #[inline(always)]
+    fn pow(self, exp: i32) -> Self {
+        if exp == 0 {
+            return Self::ONE;
+        }
+
+        let value = Pow::<i32>::pow(self.value, exp);
+        // `i32::MIN - 1` overflows, so `x^(MIN - 1)` is computed as `x^MIN / x`
+        let pow_minus_one = match exp.checked_sub(1) {
+            Some(exp_minus_one) => Pow::<i32>::pow(self.value, exp_minus_one),
+            None => value / self.value,
+        };
+
+        self.chain(value, T::saturating_from_num(exp) * pow_minus_one)
+    }
+}
+
+/// Uses `(a^b)' = b * a^(b - 1) * a' + a^b * ln(a) * b'`,
+/// skipping the `ln(a)` term if `b` is constant so that powers of zero and of negative numbers work.
+impl<T: Float, const N: usize> Pow<DualN<T, N>> for DualN<T, N> {
+    fn pow(self, exp: Self) -> Self {
+        let value = Pow::<T>::pow(self.value, exp.value);
+        let base_derivative = exp.value * Pow::<T>::pow(self.value, exp.value - T::ONE);
+        let exp_derivative = value * self.value.ln();
+
+        Self::new(
+            value,
+            self.zip_gradient(exp, |base_partial, exp_partial| {
+                if exp_partial.is_zero() {
+                    base_partial * base_derivative
+                } else {
+                    base_partial * base_derivative + exp_partial * exp_derivative
+                }
+            }),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Round for DualN<T, N> {
+    #[inline(always)]
+    fn round(self) -> Self {
+        Self::constant(self.value.round())
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> Self {
+        Self::constant(self.value.round_ties_even())
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        Self::constant(self.value.floor())
+    }
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        Self::constant(self.value.ceil())
+    }
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        Self::constant(self.value.trunc())
+    }
+    #[inline(always)]
+    fn atrunc(self) -> Self {
+        Self::constant(self.value.atrunc())
+    }
+
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::new(self.value.fract(), self.gradient)
+    }
+
+    #[inline(always)]
+    fn round_to_multiple(self, step: Self) -> Self {
+        (self / step).round() * step
+    }
+
+    #[inline(always)]
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        Self::constant(self.value.round_to_decimals(decimals))
+    }
+}
+
+impl<T: Float, const N: usize> WholeEquivalent for DualN<T, N> {
+    type Whole = T::Whole;
+
+    #[inline(always)]
+    fn iround(self) -> Self::Whole {
+        self.value.iround()
+    }
+
+    #[inline(always)]
+    fn ifloor(self) -> Self::Whole {
+        self.value.ifloor()
+    }
+
+    #[inline(always)]
+    fn iceil(self) -> Self::Whole {
+        self.value.iceil()
+    }
+
+    #[inline(always)]
+    fn itrunc(self) -> Self::Whole {
+        self.value.itrunc()
+    }
+
+    #[inline(always)]
+    fn iatrunc(self) -> Self::Whole {
+        self.value.iatrunc()
+    }
+}
+
+/// Truncated roots have a zero derivative, like the other rounding functions.
+impl<T: Float, const N: usize> TruncRoot for DualN<T, N> {
+    #[inline(always)]
+    fn trunc_sqrt(self) -> Self {
+        Self::constant(self.value.trunc_sqrt())
+    }
+    #[inline(always)]
+    fn trunc_cbrt(self) -> Self {
+        Self::constant(self.value.trunc_cbrt())
+    }
+
+    #[inline(always)]
+    fn trunc_nth_root(self, n: u32) -> Self {
+        Self::constant(self.value.trunc_nth_root(n))
+    }
+
+    #[inline(always)]
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        self.value.checked_exact_sqrt().map(|_| self.sqrt())
+    }
+}
+
+impl<T: Float, const N: usize> Root for DualN<T, N> {
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        let sqrt = self.value.sqrt();
+
+        self.chain(sqrt, T::ONE / (sqrt + sqrt))
+    }
+
+    #[inline(always)]
+    fn cbrt(self) -> Self {
+        let cbrt = self.value.cbrt();
+
+        self.chain(cbrt, T::ONE / (internal_num!(3: T) * cbrt * cbrt))
+    }
+
+    #[inline(always)]
+    fn nth_root(self, n: u32) -> Self {
+        let root = self.value.nth_root(n);
+
+        self.chain(root, root / (T::saturating_from_num(n) * self.value))
+    }
+
+    #[inline(always)]
+    fn rsqrt(self) -> Self {
+        let rsqrt = self.value.rsqrt();
+
+        self.chain(rsqrt, -(rsqrt * internal_num!(0.5)) / self.value)
+    }
+}
+
+impl<T: Float, const N: usize> Hypot for DualN<T, N> {
+    #[inline(always)]
+    fn hypot(self, other: Self) -> Self {
+        let hypot = self.value.hypot(other.value);
+
+        Self::new(
+            hypot,
+            self.zip_gradient(other, |lhs_partial, rhs_partial| {
+                (self.value * lhs_partial + other.value * rhs_partial) / hypot
+            }),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Exp for DualN<T, N> {
+    #[inline(always)]
+    fn exp(self) -> Self {
+        let exp = self.value.exp();
+
+        self.chain(exp, exp)
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Self {
+        let exp2 = self.value.exp2();
+
+        self.chain(exp2, exp2 * T::LN_2)
+    }
+
+    #[inline(always)]
+    fn exp_m1(self) -> Self {
+        self.chain(self.value.exp_m1(), self.value.exp())
+    }
+}
+
+impl<T: Float, const N: usize> Log for DualN<T, N> {
+    #[inline(always)]
+    fn ln(self) -> Self {
+        self.chain(self.value.ln(), T::ONE / self.value)
+    }
+
+    #[inline(always)]
+    fn log2(self) -> Self {
+        self.chain(self.value.log2(), T::LOG2_E / self.value)
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Self {
+        self.chain(self.value.log10(), T::LOG10_E / self.value)
+    }
+
+    #[inline(always)]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline(always)]
+    fn ln_1p(self) -> Self {
+        self.chain(self.value.ln_1p(), T::ONE / (T::ONE + self.value))
+    }
+}
+
+impl<T: Float, const N: usize> Trig for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sin(self) -> Self::Output {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Self::Output {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    #[inline(always)]
+    fn tan(self) -> Self::Output {
+        let tan = self.value.tan();
+
+        self.chain(tan, T::ONE + tan * tan)
+    }
+}
+
+impl<T: Float, const N: usize> ATrig for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn asin(self) -> Self::Output {
+        self.chain(
+            self.value.asin(),
+            (T::ONE - self.value * self.value).rsqrt(),
+        )
+    }
+
+    #[inline(always)]
+    fn acos(self) -> Self::Output {
+        self.chain(
+            self.value.acos(),
+            -(T::ONE - self.value * self.value).rsqrt(),
+        )
+    }
+
+    #[inline(always)]
+    fn atan(self) -> Self::Output {
+        self.chain(
+            self.value.atan(),
+            T::ONE / (T::ONE + self.value * self.value),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> Hyper for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sinh(self) -> Self::Output {
+        self.chain(self.value.sinh(), self.value.cosh())
+    }
+
+    #[inline(always)]
+    fn cosh(self) -> Self::Output {
+        self.chain(self.value.cosh(), self.value.sinh())
+    }
+
+    #[inline(always)]
+    fn tanh(self) -> Self::Output {
+        let tanh = self.value.tanh();
+
+        self.chain(tanh, T::ONE - tanh * tanh)
+    }
+}
+
+impl<T: Float, const N: usize> AHyper for DualN<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn asinh(self) -> Self::Output {
+        self.chain(
+            self.value.asinh(),
+            (self.value * self.value + T::ONE).rsqrt(),
+        )
+    }
+
+    #[inline(always)]
+    fn acosh(self) -> Self::Output {
+        self.chain(
+            self.value.acosh(),
+            (self.value * self.value - T::ONE).rsqrt(),
+        )
+    }
+
+    #[inline(always)]
+    fn atanh(self) -> Self::Output {
+        self.chain(
+            self.value.atanh(),
+            T::ONE / (T::ONE - self.value * self.value),
+        )
+    }
+}
+
+impl<T: Float, const N: usize> FromIntLiteral for DualN<T, N> {
+    const MIN_LITERAL: i128 = T::MIN_LITERAL;
+    const MAX_LITERAL: i128 = T::MAX_LITERAL;
+    const MIN_APPROX_LITERAL: i128 = T::MIN_APPROX_LITERAL;
+    const MAX_APPROX_LITERAL: i128 = T::MAX_APPROX_LITERAL;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::constant(T::from_int_literal(value))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::constant(T::approx_from_int_literal(value))
+    }
+}
+
+impl<T: Float, const N: usize> FromFloatLiteral for DualN<T, N> {
+    const MIN_FLOAT_LITERAL: f64 = T::MIN_FLOAT_LITERAL;
+    const MAX_FLOAT_LITERAL: f64 = T::MAX_FLOAT_LITERAL;
+
+    #[inline(always)]
+    unsafe fn from_float_literal(value: f64) -> Self {
+        Self::constant(T::from_float_literal(value))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_float_literal(value: f64) -> Self {
+        Self::constant(T::approx_from_float_literal(value))
+    }
+
+    #[inline(always)]
+    unsafe fn from_decimal_literal(value: f64, digits: i128, exponent: i32) -> Self {
+        Self::constant(T::from_decimal_literal(value, digits, exponent))
+    }
+}
+
+impl<T: Float, const N: usize> MathConsts for DualN<T, N> {
+    const PI: Self = Self::constant(T::PI);
+    const TAU: Self = Self::constant(T::TAU);
+    const FRAC_PI_2: Self = Self::constant(T::FRAC_PI_2);
+    const FRAC_PI_3: Self = Self::constant(T::FRAC_PI_3);
+    const FRAC_PI_4: Self = Self::constant(T::FRAC_PI_4);
+    const FRAC_PI_6: Self = Self::constant(T::FRAC_PI_6);
+    const FRAC_PI_8: Self = Self::constant(T::FRAC_PI_8);
+    const FRAC_1_PI: Self = Self::constant(T::FRAC_1_PI);
+    const FRAC_2_PI: Self = Self::constant(T::FRAC_2_PI);
+    const FRAC_2_SQRT_PI: Self = Self::constant(T::FRAC_2_SQRT_PI);
+    const SQRT_2: Self = Self::constant(T::SQRT_2);
+    const FRAC_1_SQRT_2: Self = Self::constant(T::FRAC_1_SQRT_2);
+    const E: Self = Self::constant(T::E);
+    const LN_2: Self = Self::constant(T::LN_2);
+    const LN_10: Self = Self::constant(T::LN_10);
+    const LOG2_E: Self = Self::constant(T::LOG2_E);
+    const LOG10_E: Self = Self::constant(T::LOG10_E);
+    const LOG2_10: Self = Self::constant(T::LOG2_10);
+    const LOG10_2: Self = Self::constant(T::LOG10_2);
+    const GOLDEN_RATIO: Self = Self::constant(T::GOLDEN_RATIO);
+}
+
+impl<T: Float, const N: usize> Num for DualN<T, N> {}
+
+impl<T: Float, const N: usize> TryFromNum for DualN<T, N> {
+    #[inline(always)]
+    fn try_from_num<U: AsPrim>(value: U) -> Result<Self, NumConvertError> {
+        T::try_from_num(value).map(Self::constant)
+    }
+
+    #[inline(always)]
+    fn saturating_from_num<U: AsPrim>(value: U) -> Self {
+        Self::constant(T::saturating_from_num(value))
+    }
+}
+
+/// Classifies the value, ignoring the gradient.
+impl<T: Float, const N: usize> FloatClass for DualN<T, N> {
+    type FloatBoolMapped = bool;
+    type CategoryMapped = FpCategory;
+
+    #[inline(always)]
+    fn is_nan(&self) -> bool {
+        self.value.is_nan()
+    }
+    #[inline(always)]
+    fn is_finite(&self) -> bool {
+        self.value.is_finite()
+    }
+    #[inline(always)]
+    fn is_infinite(&self) -> bool {
+        self.value.is_infinite()
+    }
+    #[inline(always)]
+    fn is_normal(&self) -> bool {
+        self.value.is_normal()
+    }
+    #[inline(always)]
+    fn is_subnormal(&self) -> bool {
+        self.value.is_subnormal()
+    }
+
+    #[inline(always)]
+    fn is_sign_positive(&self) -> bool {
+        self.value.is_sign_positive()
+    }
+    #[inline(always)]
+    fn is_sign_negative(&self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    #[inline(always)]
+    fn classify(&self) -> FpCategory {
+        self.value.classify()
+    }
+}
+
+impl<T: Float, const N: usize> FloatConsts for DualN<T, N> {
+    const NAN: Self = Self::constant(T::NAN);
+    const INFINITY: Self = Self::constant(T::INFINITY);
+    const NEG_INFINITY: Self = Self::constant(T::NEG_INFINITY);
+
+    const EPSILON: Self = Self::constant(T::EPSILON);
+    const MIN_POSITIVE: Self = Self::constant(T::MIN_POSITIVE);
+    const MAX: Self = Self::constant(T::MAX);
+    const MIN: Self = Self::constant(T::MIN);
+}
+
+impl<T: Float, const N: usize> Float for DualN<T, N> {}
@@ -0,0 +1,856 @@
+use std::fmt::{Debug, Display};
+
+use crate::*;
+
+/// A binary fixed-point number, stored as the integer `T` scaled by `2^FRAC_BITS`.
+///
+/// For example `Fixed<i32, 16>` has 16 integer bits (including the sign bit) and 16 fractional bits,
+/// and represents `1.25` as `0x0001_4000`.
+///
+/// All operations are computed with integer arithmetic,
+/// so results are bit-exact across platforms and build profiles:
+/// arithmetic panics on overflow in both debug and release builds,
+/// multiplication rounds down and division rounds towards zero.
+///
+/// `T` must implement [`WideningMul`] which multiplication and division are computed in,
+/// so `i128` and `u128` are not supported.
+/// `FRAC_BITS` must be smaller than `T::BITS`.
+///
+/// Float literals (`num!(1.25)`) are converted by decoding the bits of the literal's `f64` value with integer arithmetic,
+/// rounding to the nearest representable value (ties away from zero).
+/// Like for every type, `num!` literals out of range are a compile error,
+/// but the conversion itself runs at runtime and isn't a `const` evaluation.
+///
+/// Because the literal is first rounded to `f64`, a literal with more precision than `f64`
+/// that rounds to an exact tie between two `Fixed` values is rounded twice,
+/// and may end up one bit away from the nearest value.
+/// Use [`Fixed::from_bits`] for values that must be exact to the last bit.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// type Fx = Fixed<i32, 16>;
+///
+/// let a: Fx = num!(1.25);
+/// let b: Fx = num!(-2.5);
+///
+/// assert_eq!(a * b, num!(-3.125));
+/// assert_eq!((a * b).floor(), num!(-4));
+/// assert_eq!((a * b).iround(), -3);
+/// assert_eq!(Fx::from_bits(0x0001_4000), a);
+/// assert_eq!(b.to_string(), "-2.5");
+/// ```
+///
+/// ```compile_fail
+/// use newnum::*;
+///
+/// // The largest `Fixed<i64, 32>` is just below `2^31`.
+/// let value: Fixed<i64, 32> = num!(2147483648.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<T, const FRAC_BITS: u32>(T);
+
+impl<T: WideningMul, const FRAC_BITS: u32> Fixed<T, FRAC_BITS> {
+    const VALID_FRAC_BITS: () = assert!(
+        FRAC_BITS < T::BITS,
+        "`FRAC_BITS` must be smaller than the bit count of `T`"
+    );
+
+    /// Creates a fixed-point number from its raw representation (the value scaled by `2^FRAC_BITS`).
+    #[inline(always)]
+    pub const fn from_bits(bits: T) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FRAC_BITS;
+
+        Self(bits)
+    }
+
+    /// Returns the raw representation of `self` (the value scaled by `2^FRAC_BITS`).
+    #[inline(always)]
+    pub const fn to_bits(self) -> T {
+        self.0
+    }
+
+    /// Converts the integer `value` into a fixed-point number, or returns `None` if it is out of range.
+    pub fn checked_from_int(value: T) -> Option<Self> {
+        value
+            .saturating_as::<i128>()
+            .checked_mul(1 << FRAC_BITS)
+            .and_then(Self::checked_from_i128_bits)
+    }
+
+    /// The raw representation of `self` as `i128`, which can represent every `T` because `T: WideningMul`.
+    #[inline(always)]
+    fn i128_bits(self) -> i128 {
+        self.0.saturating_as()
+    }
+
+    #[inline(always)]
+    fn checked_from_i128_bits(bits: i128) -> Option<Self> {
+        bits.checked_as::<T>().map(Self::from_bits)
+    }
+
+    /// Converts `bits` into `Self`, and panics with `msg` if it is out of range.
+    #[inline(always)]
+    fn expect_from_i128_bits(bits: i128, msg: &str) -> Self {
+        Self::checked_from_i128_bits(bits).expect(msg)
+    }
+
+    /// Converts `bits` into `Self`, clamped to the range of `T`.
+    #[inline(always)]
+    fn saturating_from_i128_bits(bits: i128) -> Self {
+        Self::from_bits(T::saturating_from_num(bits))
+    }
+
+    /// `FRAC_BITS` as a shift amount of `T::Wide`.
+    #[inline(always)]
+    fn wide_frac_bits() -> T::Wide {
+        T::Wide::saturating_from_num(FRAC_BITS)
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Display for Fixed<T, FRAC_BITS> {
+    /// Writes the exact decimal value, which is always finite because the denominator is a power of two.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bits = self.i128_bits();
+        let abs = bits.unsigned_abs();
+        let mask = (1_u128 << FRAC_BITS) - 1;
+
+        if bits < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", abs >> FRAC_BITS)?;
+
+        let mut fract = abs & mask;
+        if fract != 0 {
+            write!(f, ".")?;
+        }
+        while fract != 0 {
+            fract *= 10;
+            write!(f, "{}", fract >> FRAC_BITS)?;
+            fract &= mask;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Debug for Fixed<T, FRAC_BITS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+macro_rules! op_impl {
+    ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident), $checked_fn:ident, $msg:literal) => {
+        impl<T: WideningMul, const FRAC_BITS: u32> $trait for Fixed<T, FRAC_BITS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self(self.0.$checked_fn(rhs.0).expect($msg))
+            }
+        }
+
+        impl<T: WideningMul, const FRAC_BITS: u32> $assign_trait for Fixed<T, FRAC_BITS> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+op_impl!(
+    Add(add),
+    AddAssign(add_assign),
+    checked_add,
+    "attempt to add with overflow"
+);
+op_impl!(
+    Sub(sub),
+    SubAssign(sub_assign),
+    checked_sub,
+    "attempt to subtract with overflow"
+);
+op_impl!(
+    Rem(rem),
+    RemAssign(rem_assign),
+    checked_rem,
+    "attempt to calculate the remainder with overflow"
+);
+
+impl<T: WideningMul, const FRAC_BITS: u32> Mul for Fixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let product = (self.0.widen() * rhs.0.widen()) >> Self::wide_frac_bits();
+
+        Self(
+            product
+                .checked_as::<T>()
+                .expect("attempt to multiply with overflow"),
+        )
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Div for Fixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        // `FRAC_BITS < T::BITS` so the shift never overflows `T::Wide`.
+        let quotient = (self.0.widen() << Self::wide_frac_bits()) / rhs.0.widen();
+
+        Self(
+            quotient
+                .checked_as::<T>()
+                .expect("attempt to divide with overflow"),
+        )
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> MulAssign for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> DivAssign for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Neg for Fixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self(
+            self.0
+                .checked_neg()
+                .expect("attempt to negate with overflow"),
+        )
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Signed for Fixed<T, FRAC_BITS> {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    #[inline(always)]
+    fn sign(&self) -> Sign {
+        self.0.sign()
+    }
+    #[inline(always)]
+    fn bit_sign(&self) -> BitSign {
+        self.0.bit_sign()
+    }
+
+    #[inline(always)]
+    fn is_positive(&self) -> Self::BoolMapped {
+        Signed::is_positive(&self.0)
+    }
+    #[inline(always)]
+    fn is_negative(&self) -> Self::BoolMapped {
+        Signed::is_negative(&self.0)
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> Self::BoolMapped {
+        self.0.is_zero()
+    }
+
+    #[inline(always)]
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        self.0.is_bin_positive()
+    }
+    #[inline(always)]
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        self.0.is_bin_negative()
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Positive for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Self(self.0.abs())
+    }
+}
+
+impl<T: WideningMul + Negative, const FRAC_BITS: u32> Negative for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        Self(self.0.neg_abs())
+    }
+}
+
+impl<T: WideningMul + NotNegative, const FRAC_BITS: u32> NotNegative for Fixed<T, FRAC_BITS> {}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Zero for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> ConstZero for Fixed<T, FRAC_BITS> {
+    const ZERO: Self = Self(T::ZERO);
+}
+
+/// Panics if `1` is out of range (for example for `Fixed<i8, 7>`).
+impl<T: WideningMul, const FRAC_BITS: u32> One for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::checked_from_i128_bits(1 << FRAC_BITS).expect("`1` is out of range")
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> TypeMin for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn type_min() -> Self {
+        Self(T::saturating_from_num(i128::MIN))
+    }
+}
+impl<T: WideningMul, const FRAC_BITS: u32> TypeMax for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn type_max() -> Self {
+        Self(T::saturating_from_num(i128::MAX))
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> AbsDiff for Fixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        Self(self.0.abs_diff(rhs.0))
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> MinMax for Fixed<T, FRAC_BITS> {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Pow<u32> for Fixed<T, FRAC_BITS> {
+    fn pow(self, exp: u32) -> Self {
+        let mut output = Self::one();
+        let mut base = self;
+        let mut exp = exp;
+        while exp != 0 {
+            if exp % 2 == 1 {
+                output *= base;
+            }
+
+            exp /= 2;
+            if exp != 0 {
+                base *= base;
+            }
+        }
+
+        output
+    }
+}
+
+/// Divides `lhs` by `rhs` and rounds the quotient to the nearest integer, ties away from zero.
+fn round_div(lhs: i128, rhs: i128) -> i128 {
+    let quotient = lhs / rhs;
+    let remainder = (lhs % rhs).unsigned_abs();
+
+    if remainder != 0 && remainder >= rhs.unsigned_abs() - remainder {
+        quotient + lhs.signum() * rhs.signum()
+    } else {
+        quotient
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Round for Fixed<T, FRAC_BITS> {
+    fn round(self) -> Self {
+        let bits = self.i128_bits();
+
+        Self::expect_from_i128_bits(
+            round_div(bits, 1 << FRAC_BITS) << FRAC_BITS,
+            "attempt to round with overflow",
+        )
+    }
+
+    fn round_ties_even(self) -> Self {
+        let floor = self.floor().i128_bits();
+        let fract = self.i128_bits() - floor;
+        let half = (1 << FRAC_BITS) / 2;
+
+        let round_up = fract > half || fract == half && fract != 0 && (floor >> FRAC_BITS) % 2 != 0;
+        if round_up {
+            Self::expect_from_i128_bits(floor + (1 << FRAC_BITS), "attempt to round with overflow")
+        } else {
+            Self::from_bits(T::saturating_from_num(floor))
+        }
+    }
+
+    fn floor(self) -> Self {
+        // The floor of a value in range is always in range.
+        Self::saturating_from_i128_bits((self.i128_bits() >> FRAC_BITS) << FRAC_BITS)
+    }
+
+    fn ceil(self) -> Self {
+        let bits = self.i128_bits();
+        let ceil = ((bits + (1 << FRAC_BITS) - 1) >> FRAC_BITS) << FRAC_BITS;
+
+        Self::expect_from_i128_bits(ceil, "attempt to round with overflow")
+    }
+
+    fn trunc(self) -> Self {
+        let bits = self.i128_bits();
+
+        Self::saturating_from_i128_bits((bits / (1 << FRAC_BITS)) << FRAC_BITS)
+    }
+
+    fn atrunc(self) -> Self {
+        if Signed::is_negative(&self) {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    fn fract(self) -> Self {
+        let bits = self.i128_bits();
+
+        Self::saturating_from_i128_bits(bits % (1 << FRAC_BITS))
+    }
+
+    fn round_to_multiple(self, step: Self) -> Self {
+        let step = step.i128_bits().abs();
+        assert!(step != 0, "attempt to round to a multiple of zero");
+
+        Self::expect_from_i128_bits(
+            round_div(self.i128_bits(), step) * step,
+            "attempt to round with overflow",
+        )
+    }
+
+    /// Decimals finer than the resolution of `2^-FRAC_BITS` return `self`.
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        let bits = self.i128_bits();
+
+        if decimals > 0 {
+            match 10_i128.checked_pow(decimals as u32) {
+                Some(scale) if scale <= 1 << FRAC_BITS => {
+                    let decimal_bits = round_div(bits * scale, 1 << FRAC_BITS);
+
+                    Self::saturating_from_i128_bits(round_div(decimal_bits << FRAC_BITS, scale))
+                }
+                _ => self,
+            }
+        } else {
+            match 10_i128
+                .checked_pow(decimals.unsigned_abs())
+                .and_then(|scale| scale.checked_mul(1 << FRAC_BITS))
+            {
+                Some(step) => Self::expect_from_i128_bits(
+                    round_div(bits, step) * step,
+                    "attempt to round with overflow",
+                ),
+                None => Self::zero(),
+            }
+        }
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> WholeEquivalent for Fixed<T, FRAC_BITS> {
+    type Whole = T;
+
+    #[inline(always)]
+    fn iround(self) -> Self::Whole {
+        self.round().0 >> T::saturating_from_num(FRAC_BITS)
+    }
+
+    #[inline(always)]
+    fn ifloor(self) -> Self::Whole {
+        self.0 >> T::saturating_from_num(FRAC_BITS)
+    }
+
+    #[inline(always)]
+    fn iceil(self) -> Self::Whole {
+        self.ceil().0 >> T::saturating_from_num(FRAC_BITS)
+    }
+
+    #[inline(always)]
+    fn itrunc(self) -> Self::Whole {
+        self.trunc().0 >> T::saturating_from_num(FRAC_BITS)
+    }
+
+    #[inline(always)]
+    fn iatrunc(self) -> Self::Whole {
+        self.atrunc().0 >> T::saturating_from_num(FRAC_BITS)
+    }
+}
+
+/// Checks `y^n <= bits * 2^shift` exactly, by computing `y^n` in 64-bit limbs.
+fn pow_le_shifted(y: u64, n: u32, bits: u128, shift: u32) -> bool {
+    // Little-endian limbs of `y^n`.
+    let mut pow = vec![1_u64];
+    for _ in 0..n {
+        let mut carry = 0;
+        for limb in &mut pow {
+            let wide = *limb as u128 * y as u128 + carry;
+            *limb = wide as u64;
+            carry = wide >> 64;
+        }
+        if carry != 0 {
+            pow.push(carry as u64);
+        }
+
+        // `pow` never shrinks, so once `pow >= 2^(shift + 128)` it stays larger than `bits * 2^shift`.
+        if pow.len() as u64 * 64 > shift as u64 + 192 {
+            return false;
+        }
+    }
+
+    // Splits `pow` into `pow / 2^shift` and whether `pow % 2^shift` is zero.
+    let limb = |index: usize| pow.get(index).copied().unwrap_or(0) as u128;
+    let (first_limb, bit_shift) = ((shift / 64) as usize, shift % 64);
+    if pow.len() > first_limb + 3 {
+        return false;
+    }
+
+    let is_exact = pow.iter().take(first_limb).all(|limb| *limb == 0)
+        && limb(first_limb) & ((1 << bit_shift) - 1) == 0;
+
+    let low = limb(first_limb) | limb(first_limb + 1) << 64;
+    let high = limb(first_limb + 2);
+    let quotient = if bit_shift == 0 {
+        if high != 0 {
+            return false;
+        }
+        low
+    } else {
+        if high >> bit_shift != 0 {
+            return false;
+        }
+        low >> bit_shift | high << (128 - bit_shift)
+    };
+
+    quotient < bits || quotient == bits && is_exact
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Fixed<T, FRAC_BITS> {
+    /// Computes the `n`th root of the absolute value of `self` rounded down, as raw bits.
+    fn abs_nth_root_bits(self, n: u32) -> u128 {
+        let bits = self.i128_bits().unsigned_abs();
+        if n == 2 {
+            // Exact because `bits < 2^64` and `FRAC_BITS < 64`.
+            return (bits << FRAC_BITS).trunc_sqrt();
+        }
+
+        // The largest `y` where `y^n / 2^((n - 1) * FRAC_BITS) <= bits`,
+        // using that roots are never larger than `max(self, 1)` so `y < 2^64`.
+        let shift = (n - 1).saturating_mul(FRAC_BITS);
+        let mut low = 0;
+        let mut high = Ord::max(bits, 1 << FRAC_BITS) + 1;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if pow_le_shifted(mid as u64, n, bits, shift) {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        low
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> TruncRoot for Fixed<T, FRAC_BITS> {
+    fn trunc_sqrt(self) -> Self {
+        Root::sqrt(self).trunc()
+    }
+    fn trunc_cbrt(self) -> Self {
+        Root::cbrt(self).trunc()
+    }
+
+    fn trunc_nth_root(self, n: u32) -> Self {
+        Root::nth_root(self, n).trunc()
+    }
+
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        if Signed::is_negative(&self) {
+            return None;
+        }
+
+        let sqrt = Root::sqrt(self);
+        let whole_sqrt = sqrt.i128_bits() >> FRAC_BITS;
+        if sqrt.fract().is_zero() && (whole_sqrt * whole_sqrt) << FRAC_BITS == self.i128_bits() {
+            Some(sqrt)
+        } else {
+            None
+        }
+    }
+}
+
+/// Roots are exact to the last bit, rounded towards zero.
+///
+/// Panics for roots of negative numbers with an even `n`, and for `n = 0`.
+impl<T: WideningMul, const FRAC_BITS: u32> Root for Fixed<T, FRAC_BITS> {
+    fn sqrt(self) -> Self {
+        Root::nth_root(self, 2)
+    }
+    fn cbrt(self) -> Self {
+        Root::nth_root(self, 3)
+    }
+
+    fn nth_root(self, n: u32) -> Self {
+        assert!(n != 0, "zeroth root is undefined");
+        assert!(
+            n % 2 == 1 || !Signed::is_negative(&self),
+            "even root of a negative number"
+        );
+
+        let root = self.abs_nth_root_bits(n) as i128;
+        if Signed::is_negative(&self) {
+            Self::saturating_from_i128_bits(-root)
+        } else {
+            Self::saturating_from_i128_bits(root)
+        }
+    }
+
+    fn rsqrt(self) -> Self {
+        Self::one() / Root::sqrt(self)
+    }
+}
+
+/// The number of fractional bits trigonometric functions are computed with.
+const CORDIC_FRAC_BITS: u32 = 62;
+
+/// π scaled by `2^CORDIC_FRAC_BITS`.
+const CORDIC_PI: i128 = 14488038916154245685;
+/// π/2 scaled by `2^CORDIC_FRAC_BITS`.
+const CORDIC_FRAC_PI_2: i128 = 7244019458077122842;
+/// τ scaled by `2^CORDIC_FRAC_BITS`.
+const CORDIC_TAU: i128 = 28976077832308491370;
+
+/// The inverse of the CORDIC gain (`prod(1 / sqrt(1 + 2^-2i))`) scaled by `2^CORDIC_FRAC_BITS`.
+const CORDIC_INV_GAIN: i128 = 2800459870029452954;
+
+/// `atan(2^-i)` scaled by `2^CORDIC_FRAC_BITS`.
+/// From `i = 21` onwards `atan(2^-i)` rounds to `2^-i`.
+const CORDIC_ATAN: [i128; 21] = [
+    3622009729038561421,
+    2138197195906305897,
+    1129764675555192497,
+    573486189672913778,
+    287855953345232185,
+    144068303048368715,
+    72051730834756822,
+    36028064038054493,
+    18014306884351854,
+    9007187801521084,
+    4503598195715550,
+    2251799634728303,
+    1125899884473003,
+    562949950625109,
+    281474976361131,
+    140737488311637,
+    70368744172203,
+    35184372088149,
+    17592186044331,
+    8796093022197,
+    4398046511103,
+];
+
+/// Computes `(sin, cos)` of an angle with `frac_bits` fractional bits,
+/// scaled by `2^CORDIC_FRAC_BITS`.
+fn cordic_sin_cos(angle: i128, frac_bits: u32) -> (i128, i128) {
+    // `angle < 2^64` and `frac_bits < 64` so neither shift overflows.
+    let angle = if frac_bits <= CORDIC_FRAC_BITS {
+        angle << (CORDIC_FRAC_BITS - frac_bits)
+    } else {
+        angle >> (frac_bits - CORDIC_FRAC_BITS)
+    };
+
+    // Reduce to `[-π, π]`, then to `[-π/2, π/2]` which is where CORDIC converges.
+    let mut angle = angle.rem_euclid(CORDIC_TAU);
+    if angle > CORDIC_PI {
+        angle -= CORDIC_TAU;
+    }
+
+    let mut cos_sign = 1;
+    if angle > CORDIC_FRAC_PI_2 {
+        angle = CORDIC_PI - angle;
+        cos_sign = -1;
+    } else if angle < -CORDIC_FRAC_PI_2 {
+        angle = -CORDIC_PI - angle;
+        cos_sign = -1;
+    }
+
+    let mut x = CORDIC_INV_GAIN;
+    let mut y = 0;
+    for i in 0..CORDIC_FRAC_BITS {
+        let atan = CORDIC_ATAN
+            .get(i as usize)
+            .copied()
+            .unwrap_or(1 << (CORDIC_FRAC_BITS - i));
+
+        let (dx, dy) = (y >> i, x >> i);
+        if angle >= 0 {
+            x -= dx;
+            y += dy;
+            angle -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            angle += atan;
+        }
+    }
+
+    (y, x * cos_sign)
+}
+
+/// Converts a value scaled by `2^CORDIC_FRAC_BITS` to `frac_bits` fractional bits, rounding to the nearest.
+fn from_cordic_bits(value: i128, frac_bits: u32) -> i128 {
+    if frac_bits >= CORDIC_FRAC_BITS {
+        value << (frac_bits - CORDIC_FRAC_BITS)
+    } else {
+        round_div(value, 1 << (CORDIC_FRAC_BITS - frac_bits))
+    }
+}
+
+/// Computed with CORDIC using 62 fractional bits, so results are bit-exact on every platform.
+/// Results outside the range of `T` saturate (for example `cos(0)` of `Fixed<i8, 7>`).
+impl<T: WideningMul, const FRAC_BITS: u32> Trig for Fixed<T, FRAC_BITS> {
+    type Output = Self;
+
+    fn sin(self) -> Self::Output {
+        let (sin, _) = cordic_sin_cos(self.i128_bits(), FRAC_BITS);
+
+        Self::saturating_from_i128_bits(from_cordic_bits(sin, FRAC_BITS))
+    }
+
+    fn cos(self) -> Self::Output {
+        let (_, cos) = cordic_sin_cos(self.i128_bits(), FRAC_BITS);
+
+        Self::saturating_from_i128_bits(from_cordic_bits(cos, FRAC_BITS))
+    }
+
+    fn tan(self) -> Self::Output {
+        let (sin, cos) = cordic_sin_cos(self.i128_bits(), FRAC_BITS);
+        if cos == 0 {
+            return Self::saturating_from_i128_bits(sin.signum() * i128::MAX);
+        }
+
+        // `|sin| <= 2^62` and `FRAC_BITS < 64` so the shift never overflows.
+        Self::saturating_from_i128_bits(round_div(sin << FRAC_BITS, cos))
+    }
+}
+
+/// Converts `value` to the closest `f64` that isn't further from zero,
+/// so float literal bounds never exceed the range of the bits (`i64::MAX as f64` rounds up to `2^63`).
+const fn i128_to_f64_towards_zero(value: i128) -> f64 {
+    let output = value as f64;
+
+    // `output as i128` saturates, so `2^127` is checked separately.
+    let rounded_away = if value >= 0 {
+        output >= i128::MAX as f64 || output as i128 > value
+    } else {
+        (output as i128) < value
+    };
+
+    if rounded_away {
+        // The previous float towards zero, because the bits of floats are ordered by magnitude.
+        f64::from_bits(output.to_bits() - 1)
+    } else {
+        output
+    }
+}
+
+/// Converts an `f64` to a value with `frac_bits` fractional bits, rounding to the nearest and ties away from zero,
+/// using integer arithmetic on the bits of the float.
+fn float_literal_bits(value: f64, frac_bits: u32) -> i128 {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let mantissa = bits & ((1 << 52) - 1);
+
+    // `value = mantissa * 2^exponent`, with the implicit bit of normal floats.
+    let (mantissa, exponent) = if exponent == 0 {
+        (mantissa, -1074)
+    } else {
+        (mantissa | 1 << 52, exponent - 1075)
+    };
+
+    let shift = exponent + frac_bits as i32;
+    let abs = if shift >= 0 {
+        // `mantissa < 2^53`, so larger shifts overflow `i128`.
+        if shift >= 75 {
+            i128::MAX
+        } else {
+            (mantissa as i128) << shift
+        }
+    } else if shift < -64 {
+        0
+    } else {
+        let shift = shift.unsigned_abs();
+
+        ((mantissa as i128) + (1 << (shift - 1))) >> shift
+    };
+
+    if value.is_sign_negative() {
+        -abs
+    } else {
+        abs
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> FromIntLiteral for Fixed<T, FRAC_BITS> {
+    const MIN_LITERAL: i128 = {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_FRAC_BITS;
+
+        T::MIN_LITERAL >> FRAC_BITS
+    };
+    const MAX_LITERAL: i128 = T::MAX_LITERAL >> FRAC_BITS;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::from_bits(T::from_int_literal(value << FRAC_BITS))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::from_bits(T::approx_from_int_literal(value << FRAC_BITS))
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> FromFloatLiteral for Fixed<T, FRAC_BITS> {
+    const MIN_FLOAT_LITERAL: f64 =
+        i128_to_f64_towards_zero(T::MIN_LITERAL) / (1_u128 << FRAC_BITS) as f64;
+    const MAX_FLOAT_LITERAL: f64 =
+        i128_to_f64_towards_zero(T::MAX_LITERAL) / (1_u128 << FRAC_BITS) as f64;
+
+    #[inline(always)]
+    unsafe fn from_float_literal(value: f64) -> Self {
+        Self::saturating_from_i128_bits(float_literal_bits(value, FRAC_BITS))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_float_literal(value: f64) -> Self {
+        Self::saturating_from_i128_bits(float_literal_bits(value, FRAC_BITS))
+    }
+}
+
+impl<T: WideningMul, const FRAC_BITS: u32> Num for Fixed<T, FRAC_BITS> {}
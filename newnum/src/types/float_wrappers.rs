@@ -0,0 +1,633 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+};
+
+use crate::*;
+
+/// A float that is never `NaN`.
+///
+/// Construction with [`NotNan::new`] fails for `NaN`,
+/// and operations that produce `NaN` (like `0.0 / 0.0` or `sqrt(-1.0)`) panic.
+///
+/// Unlike the underlying float, `NotNan` implements `Eq`, `Ord` and `Hash`,
+/// so it can be sorted or used as a `BTreeMap` / `HashMap` key.
+/// `-0.0` and `+0.0` are equal.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let mut values = [2.5, -1.0, 0.0].map(|value| NotNan::new(value).unwrap());
+/// values.sort();
+///
+/// assert_eq!(values.map(NotNan::get), [-1.0, 0.0, 2.5]);
+/// assert_eq!(NotNan::new(f32::NAN), Err(InvalidFloatError::NaN));
+/// assert_eq!(num!(1.5: NotNan<f64>).get(), 1.5);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NotNan<T>(T);
+
+/// A float that is never `NaN` or infinite.
+///
+/// Construction with [`Finite::new`] fails for non-finite values,
+/// and operations that produce non-finite values (like overflows or division by zero) panic.
+///
+/// Unlike the underlying float, `Finite` implements `Eq`, `Ord` and `Hash`,
+/// so it can be sorted or used as a `BTreeMap` / `HashMap` key.
+/// `-0.0` and `+0.0` are equal.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let value = Finite::new(3.0_f64).unwrap();
+///
+/// assert_eq!((value * value).get(), 9.0);
+/// assert_eq!(Finite::new(f64::INFINITY), Err(InvalidFloatError::Infinite));
+/// assert!(std::panic::catch_unwind(|| value / Finite::new(0.0).unwrap()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Finite<T>(T);
+
+/// A float with a total order, that accepts every value including `NaN`.
+///
+/// `NaN`s are equal to each other and greater than every other value (including `INFINITY`),
+/// and `-0.0` and `+0.0` are equal.
+/// With that order, `OrderedFloat` implements `Eq`, `Ord` and `Hash`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let mut values = [OrderedFloat(f32::NAN), OrderedFloat(1.0), OrderedFloat(f32::NEG_INFINITY)];
+/// values.sort();
+///
+/// assert_eq!(values[0], OrderedFloat(f32::NEG_INFINITY));
+/// assert_eq!(values[1], OrderedFloat(1.0));
+/// assert!(values[2].get().is_nan());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedFloat<T>(pub T);
+
+/// The error of constructing a float wrapper (like [`NotNan`] or [`Finite`]) from a value that breaks its invariant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InvalidFloatError {
+    /// The value is `NaN`.
+    NaN,
+    /// The value is infinite.
+    Infinite,
+}
+
+impl Display for InvalidFloatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NaN => write!(f, "value is NaN"),
+            Self::Infinite => write!(f, "value is infinite"),
+        }
+    }
+}
+
+impl Error for InvalidFloatError {}
+
+impl<T: FloatPrim> NotNan<T> {
+    /// Wraps `value`, or returns an error if it is `NaN`.
+    pub fn new(value: T) -> Result<Self, InvalidFloatError> {
+        if value.is_nan() {
+            Err(InvalidFloatError::NaN)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Wraps the result of an operation, and panics if it is `NaN`.
+    #[inline(always)]
+    fn wrap(value: T) -> Self {
+        match Self::new(value) {
+            Ok(output) => output,
+            Err(err) => panic!("`NotNan` operation result is invalid: {err}"),
+        }
+    }
+}
+
+impl<T: FloatPrim> Finite<T> {
+    /// Wraps `value`, or returns an error if it is `NaN` or infinite.
+    pub fn new(value: T) -> Result<Self, InvalidFloatError> {
+        if value.is_nan() {
+            Err(InvalidFloatError::NaN)
+        } else if value.is_infinite() {
+            Err(InvalidFloatError::Infinite)
+        } else {
+            Ok(Self(value))
+        }
+    }
+
+    /// Wraps the result of an operation, and panics if it is `NaN` or infinite.
+    #[inline(always)]
+    fn wrap(value: T) -> Self {
+        match Self::new(value) {
+            Ok(output) => output,
+            Err(err) => panic!("`Finite` operation result is invalid: {err}"),
+        }
+    }
+}
+
+impl<T: FloatPrim> OrderedFloat<T> {
+    /// Wraps `value`, which can be any float including `NaN`.
+    #[inline(always)]
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Wraps the result of an operation, which is always valid.
+    #[inline(always)]
+    fn wrap(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: FloatPrim> From<T> for OrderedFloat<T> {
+    #[inline(always)]
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+/// The total order shared by the float wrappers, where `NaN`s are equal and greater than every other value.
+fn total_cmp<T: FloatPrim>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap(),
+    }
+}
+
+macro_rules! float_wrapper_impl {
+    ($wrapper:ident) => {
+        impl<T: FloatPrim> $wrapper<T> {
+            /// Returns the wrapped float.
+            #[inline(always)]
+            pub fn get(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T: FloatPrim> Display for $wrapper<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: FloatPrim> PartialEq for $wrapper<T> {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                total_cmp(self.0, other.0) == Ordering::Equal
+            }
+        }
+        impl<T: FloatPrim> Eq for $wrapper<T> {}
+
+        impl<T: FloatPrim> PartialOrd for $wrapper<T> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl<T: FloatPrim> Ord for $wrapper<T> {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                total_cmp(self.0, other.0)
+            }
+        }
+
+        impl<T: FloatPrim> Hash for $wrapper<T> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                // Equal values must have equal hashes, so every `NaN` and both zeros are hashed the same.
+                let canonical = if self.0.is_nan() {
+                    T::NAN
+                } else if self.0 == T::ZERO {
+                    T::ZERO
+                } else {
+                    self.0
+                };
+
+                canonical.to_ne_bytes().as_ref().hash(state);
+            }
+        }
+
+        impl<T: FloatPrim> Neg for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                Self::wrap(-self.0)
+            }
+        }
+
+        float_wrapper_impl!($wrapper: Add(add), AddAssign(add_assign), +);
+        float_wrapper_impl!($wrapper: Sub(sub), SubAssign(sub_assign), -);
+        float_wrapper_impl!($wrapper: Mul(mul), MulAssign(mul_assign), *);
+        float_wrapper_impl!($wrapper: Div(div), DivAssign(div_assign), /);
+        float_wrapper_impl!($wrapper: Rem(rem), RemAssign(rem_assign), %);
+
+        impl<T: FloatPrim> Signed for $wrapper<T> {
+            type SignMapped = Sign;
+            type BitSignMapped = BitSign;
+            type BoolMapped = bool;
+
+            #[inline(always)]
+            fn sign(&self) -> Sign {
+                self.0.sign()
+            }
+            #[inline(always)]
+            fn bit_sign(&self) -> BitSign {
+                self.0.bit_sign()
+            }
+
+            #[inline(always)]
+            fn is_positive(&self) -> Self::BoolMapped {
+                self.0.is_positive()
+            }
+            #[inline(always)]
+            fn is_negative(&self) -> Self::BoolMapped {
+                self.0.is_negative()
+            }
+
+            #[inline(always)]
+            fn is_zero(&self) -> Self::BoolMapped {
+                self.0.is_zero()
+            }
+
+            #[inline(always)]
+            fn is_bin_positive(&self) -> Self::BoolMapped {
+                self.0.is_bin_positive()
+            }
+            #[inline(always)]
+            fn is_bin_negative(&self) -> Self::BoolMapped {
+                self.0.is_bin_negative()
+            }
+        }
+
+        impl<T: FloatPrim> Positive for $wrapper<T> {
+            #[inline(always)]
+            fn abs(self) -> Self {
+                Self::wrap(self.0.abs())
+            }
+        }
+        impl<T: FloatPrim> Negative for $wrapper<T> {
+            #[inline(always)]
+            fn neg_abs(self) -> Self {
+                Self::wrap(self.0.neg_abs())
+            }
+        }
+        impl<T: FloatPrim> Zero for $wrapper<T> {
+            #[inline(always)]
+            fn zero() -> Self {
+                Self(T::ZERO)
+            }
+        }
+        impl<T: FloatPrim> ConstZero for $wrapper<T> {
+            const ZERO: Self = Self(T::ZERO);
+        }
+
+        impl<T: FloatPrim> Signum for $wrapper<T> {
+            #[inline(always)]
+            fn signumt(self) -> Self {
+                Self::wrap(self.0.signumt())
+            }
+            #[inline(always)]
+            fn bin_signum(self) -> Self {
+                Self::wrap(self.0.bin_signum())
+            }
+        }
+
+        impl<T: FloatPrim> One for $wrapper<T> {
+            #[inline(always)]
+            fn one() -> Self {
+                Self(T::ONE)
+            }
+        }
+        impl<T: FloatPrim> ConstOne for $wrapper<T> {
+            const ONE: Self = Self(T::ONE);
+        }
+
+        impl<T: FloatPrim> AbsDiff for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn abs_diff(self, rhs: Self) -> Self::Output {
+                Self::wrap(self.0.abs_diff(rhs.0))
+            }
+        }
+
+        impl<T: FloatPrim> MinMax for $wrapper<T> {
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                Ord::min(self, other)
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                Ord::max(self, other)
+            }
+        }
+
+        impl<T: FloatPrim> TruncRoot for $wrapper<T> {
+            #[inline(always)]
+            fn trunc_sqrt(self) -> Self {
+                Self::wrap(self.0.trunc_sqrt())
+            }
+            #[inline(always)]
+            fn trunc_cbrt(self) -> Self {
+                Self::wrap(self.0.trunc_cbrt())
+            }
+            #[inline(always)]
+            fn trunc_nth_root(self, n: u32) -> Self {
+                Self::wrap(self.0.trunc_nth_root(n))
+            }
+
+            #[inline(always)]
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                self.0.checked_exact_sqrt().map(Self::wrap)
+            }
+        }
+
+        impl<T: FloatPrim> Root for $wrapper<T> {
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                Self::wrap(self.0.sqrt())
+            }
+            #[inline(always)]
+            fn cbrt(self) -> Self {
+                Self::wrap(self.0.cbrt())
+            }
+            #[inline(always)]
+            fn nth_root(self, n: u32) -> Self {
+                Self::wrap(self.0.nth_root(n))
+            }
+            #[inline(always)]
+            fn rsqrt(self) -> Self {
+                Self::wrap(self.0.rsqrt())
+            }
+        }
+
+        impl<T: FloatPrim> Hypot for $wrapper<T> {
+            #[inline(always)]
+            fn hypot(self, other: Self) -> Self {
+                Self::wrap(self.0.hypot(other.0))
+            }
+        }
+
+        impl<T: FloatPrim> Pow<u32> for $wrapper<T> {
+            #[inline(always)]
+            fn pow(self, exp: u32) -> Self {
+                Self::wrap(<T as Pow<u32>>::pow(self.0, exp))
+            }
+        }
+        impl<T: FloatPrim> Pow<i32> for $wrapper<T> {
+            #[inline(always)]
+            fn pow(self, exp: i32) -> Self {
+                Self::wrap(<T as Pow<i32>>::pow(self.0, exp))
+            }
+        }
+        impl<T: FloatPrim> Pow<$wrapper<T>> for $wrapper<T> {
+            #[inline(always)]
+            fn pow(self, exp: Self) -> Self {
+                Self::wrap(<T as Pow<T>>::pow(self.0, exp.0))
+            }
+        }
+
+        impl<T: FloatPrim> Round for $wrapper<T> {
+            #[inline(always)]
+            fn round(self) -> Self {
+                Self::wrap(self.0.round())
+            }
+            #[inline(always)]
+            fn round_ties_even(self) -> Self {
+                Self::wrap(self.0.round_ties_even())
+            }
+            #[inline(always)]
+            fn floor(self) -> Self {
+                Self::wrap(self.0.floor())
+            }
+            #[inline(always)]
+            fn ceil(self) -> Self {
+                Self::wrap(self.0.ceil())
+            }
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                Self::wrap(self.0.trunc())
+            }
+            #[inline(always)]
+            fn atrunc(self) -> Self {
+                Self::wrap(self.0.atrunc())
+            }
+            #[inline(always)]
+            fn fract(self) -> Self {
+                Self::wrap(self.0.fract())
+            }
+
+            #[inline(always)]
+            fn round_to_multiple(self, step: Self) -> Self {
+                Self::wrap(self.0.round_to_multiple(step.0))
+            }
+            #[inline(always)]
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                Self::wrap(self.0.round_to_decimals(decimals))
+            }
+        }
+
+        impl<T: FloatPrim> Trig for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sin(self) -> Self::Output {
+                Self::wrap(self.0.sin())
+            }
+            #[inline(always)]
+            fn cos(self) -> Self::Output {
+                Self::wrap(self.0.cos())
+            }
+            #[inline(always)]
+            fn tan(self) -> Self::Output {
+                Self::wrap(self.0.tan())
+            }
+        }
+
+        impl<T: FloatPrim> ATrig for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn asin(self) -> Self::Output {
+                Self::wrap(self.0.asin())
+            }
+            #[inline(always)]
+            fn acos(self) -> Self::Output {
+                Self::wrap(self.0.acos())
+            }
+            #[inline(always)]
+            fn atan(self) -> Self::Output {
+                Self::wrap(self.0.atan())
+            }
+        }
+
+        impl<T: FloatPrim> Hyper for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sinh(self) -> Self::Output {
+                Self::wrap(self.0.sinh())
+            }
+            #[inline(always)]
+            fn cosh(self) -> Self::Output {
+                Self::wrap(self.0.cosh())
+            }
+            #[inline(always)]
+            fn tanh(self) -> Self::Output {
+                Self::wrap(self.0.tanh())
+            }
+        }
+
+        impl<T: FloatPrim> AHyper for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn asinh(self) -> Self::Output {
+                Self::wrap(self.0.asinh())
+            }
+            #[inline(always)]
+            fn acosh(self) -> Self::Output {
+                Self::wrap(self.0.acosh())
+            }
+            #[inline(always)]
+            fn atanh(self) -> Self::Output {
+                Self::wrap(self.0.atanh())
+            }
+        }
+
+        impl<T: FloatPrim> Exp for $wrapper<T> {
+            #[inline(always)]
+            fn exp(self) -> Self {
+                Self::wrap(self.0.exp())
+            }
+            #[inline(always)]
+            fn exp2(self) -> Self {
+                Self::wrap(self.0.exp2())
+            }
+            #[inline(always)]
+            fn exp_m1(self) -> Self {
+                Self::wrap(self.0.exp_m1())
+            }
+        }
+
+        impl<T: FloatPrim> Log for $wrapper<T> {
+            #[inline(always)]
+            fn ln(self) -> Self {
+                Self::wrap(self.0.ln())
+            }
+            #[inline(always)]
+            fn log2(self) -> Self {
+                Self::wrap(self.0.log2())
+            }
+            #[inline(always)]
+            fn log10(self) -> Self {
+                Self::wrap(self.0.log10())
+            }
+            #[inline(always)]
+            fn log(self, base: Self) -> Self {
+                Self::wrap(self.0.log(base.0))
+            }
+            #[inline(always)]
+            fn ln_1p(self) -> Self {
+                Self::wrap(self.0.ln_1p())
+            }
+        }
+
+        impl<T: FloatPrim> FromIntLiteral for $wrapper<T> {
+            const MIN_LITERAL: i128 = T::MIN_LITERAL;
+            const MAX_LITERAL: i128 = T::MAX_LITERAL;
+            const MIN_APPROX_LITERAL: i128 = T::MIN_APPROX_LITERAL;
+            const MAX_APPROX_LITERAL: i128 = T::MAX_APPROX_LITERAL;
+
+            #[inline(always)]
+            unsafe fn from_int_literal(value: i128) -> Self {
+                Self::wrap(T::from_int_literal(value))
+            }
+
+            #[inline(always)]
+            unsafe fn approx_from_int_literal(value: i128) -> Self {
+                Self::wrap(T::approx_from_int_literal(value))
+            }
+        }
+
+        impl<T: FloatPrim> FromFloatLiteral for $wrapper<T> {
+            const MIN_FLOAT_LITERAL: f64 = T::MIN_FLOAT_LITERAL;
+            const MAX_FLOAT_LITERAL: f64 = T::MAX_FLOAT_LITERAL;
+
+            #[inline(always)]
+            unsafe fn from_float_literal(value: f64) -> Self {
+                Self::wrap(T::from_float_literal(value))
+            }
+
+            #[inline(always)]
+            unsafe fn from_decimal_literal(value: f64, digits: i128, exponent: i32) -> Self {
+                Self::wrap(T::from_decimal_literal(value, digits, exponent))
+            }
+
+            #[inline(always)]
+            unsafe fn approx_from_float_literal(value: f64) -> Self {
+                Self::wrap(T::approx_from_float_literal(value))
+            }
+        }
+
+        impl<T: FloatPrim> MathConsts for $wrapper<T> {
+            const PI: Self = Self(T::PI);
+            const TAU: Self = Self(T::TAU);
+            const FRAC_PI_2: Self = Self(T::FRAC_PI_2);
+            const FRAC_PI_3: Self = Self(T::FRAC_PI_3);
+            const FRAC_PI_4: Self = Self(T::FRAC_PI_4);
+            const FRAC_PI_6: Self = Self(T::FRAC_PI_6);
+            const FRAC_PI_8: Self = Self(T::FRAC_PI_8);
+            const FRAC_1_PI: Self = Self(T::FRAC_1_PI);
+            const FRAC_2_PI: Self = Self(T::FRAC_2_PI);
+            const FRAC_2_SQRT_PI: Self = Self(T::FRAC_2_SQRT_PI);
+            const SQRT_2: Self = Self(T::SQRT_2);
+            const FRAC_1_SQRT_2: Self = Self(T::FRAC_1_SQRT_2);
+            const E: Self = Self(T::E);
+            const LN_2: Self = Self(T::LN_2);
+            const LN_10: Self = Self(T::LN_10);
+            const LOG2_E: Self = Self(T::LOG2_E);
+            const LOG10_E: Self = Self(T::LOG10_E);
+            const LOG2_10: Self = Self(T::LOG2_10);
+            const LOG10_2: Self = Self(T::LOG10_2);
+            const GOLDEN_RATIO: Self = Self(T::GOLDEN_RATIO);
+        }
+
+        impl<T: FloatPrim> Num for $wrapper<T> {}
+    };
+
+    ($wrapper:ident: $trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident), $op:tt) => {
+        impl<T: FloatPrim> $trait for $wrapper<T> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self::wrap(self.0 $op rhs.0)
+            }
+        }
+
+        impl<T: FloatPrim> $assign_trait for $wrapper<T> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+float_wrapper_impl!(NotNan);
+float_wrapper_impl!(Finite);
+float_wrapper_impl!(OrderedFloat);
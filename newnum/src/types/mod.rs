@@ -0,0 +1,21 @@
+#[cfg(feature = "bigint")]
+mod big_int;
+mod complex;
+mod dual;
+mod fixed;
+mod float_wrappers;
+mod ranged;
+mod ratio;
+#[cfg(feature = "bigint")]
+pub use big_int::*;
+pub use complex::*;
+pub use dual::*;
+pub use fixed::*;
+pub use float_wrappers::*;
+pub use ranged::*;
+pub use ratio::*;
+
+/// Sign-constrained number wrappers ([`Pos`](sign_wrappers::Pos), [`NonNeg`](sign_wrappers::NonNeg)...).
+///
+/// In a separate module because `sign_wrappers::Neg` would conflict with `std::ops::Neg`.
+pub mod sign_wrappers;
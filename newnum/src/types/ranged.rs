@@ -0,0 +1,597 @@
+use std::fmt::{Debug, Display};
+
+use crate::*;
+
+/// An integer restricted to the range `MIN..=MAX`.
+///
+/// The range is intersected with the range of `T`, so `Ranged<u8, -5, 300>` represents `0..=255`.
+///
+/// Construction with [`Ranged::new`] fails for out of range values,
+/// and `num!` literals out of range are a compile error.
+/// Arithmetic operators panic if the result is out of range (even in release mode).
+/// For other behaviours use the checked (`CheckedAdd`...), saturating (`SaturatingOps`)
+/// and wrapping (`WrappingOps`) methods, which saturate / wrap to the bounds of the range.
+///
+/// `Ranged` implements `Num`, so it must implement `Zero` and `One` even if they are out of range,
+/// in which case `zero()` / `one()` panic, and `ZERO` / `ONE` are a compile error.
+/// `fract()` is always `0` for integers, so it panics too if `0` is out of range.
+///
+/// Because stable Rust can't bound trait implementations on const expressions,
+/// the sign marker traits are only implemented for ranges that start or end next to zero:
+/// `NotNegative` for `MIN` of `0` or `1`, `NotPositive` for `MAX` of `0` or `-1`, and `NotZero` for `MIN` of `1`.
+/// The markers of `T` aren't forwarded (`Ranged<u8, 5, 10>` isn't `NotNegative`),
+/// because those impls would overlap with the ones above.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// type Percent = Ranged<u8, 0, 100>;
+///
+/// let progress: Percent = num!(60);
+///
+/// assert_eq!(progress.checked_add(num!(50)), None);
+/// assert_eq!(progress.saturating_add(num!(50)).get(), 100);
+/// assert_eq!(progress.wrapping_add(num!(50)).get(), 9);
+/// assert!(Percent::new(101).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use newnum::*;
+///
+/// let value: Ranged<u16, 100, 356> = num!(400);
+/// ```
+///
+/// ```compile_fail
+/// use newnum::*;
+///
+/// // The range doesn't intersect the range of `u8`.
+/// let value = Ranged::<u8, 300, 400>::new(5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ranged<T, const MIN: i128, const MAX: i128>(T);
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Ranged<T, MIN, MAX> {
+    /// The intersection of `MIN..=MAX` and the range of `T`, as `(start, end)`.
+    /// Using it is a compile error if the intersection is empty.
+    const BOUNDS: (i128, i128) = {
+        assert!(MIN <= MAX, "`Ranged` range is empty");
+
+        let start = if T::MIN_LITERAL > MIN {
+            T::MIN_LITERAL
+        } else {
+            MIN
+        };
+        let end = if T::MAX_LITERAL < MAX {
+            T::MAX_LITERAL
+        } else {
+            MAX
+        };
+        assert!(
+            start <= end,
+            "`Ranged` range doesn't intersect the range of `T`"
+        );
+
+        (start, end)
+    };
+
+    /// Wraps `value`, or returns an error if it is out of range.
+    pub fn new(value: T) -> Result<Self, NumConvertError> {
+        let (start, end) = Self::BOUNDS;
+
+        // Values outside of `i128` are outside of the range too.
+        let sign = match value.try_as::<i128>() {
+            Ok(value_i128) if value_i128 < start => Sign::Negative,
+            Ok(value_i128) if value_i128 > end => Sign::Positive,
+            Ok(_) => return Ok(Self(value)),
+            Err(err) => err.sign,
+        };
+
+        Err(NumConvertError {
+            sign,
+            kind: NumConvertErrorKind::Overflow,
+        })
+    }
+
+    /// Wraps `value`, clamped to the range.
+    pub fn saturating(value: T) -> Self {
+        let (start, end) = Self::BOUNDS;
+
+        Self(T::saturating_from_num(Ord::clamp(
+            value.saturating_as::<i128>(),
+            start,
+            end,
+        )))
+    }
+
+    /// Returns the wrapped integer.
+    #[inline(always)]
+    pub fn get(self) -> T {
+        self.0
+    }
+
+    /// Wraps the result of an operation, and panics if it overflows `T` or the range.
+    #[inline(always)]
+    fn expect_in_range(value: Option<T>) -> Self {
+        value
+            .and_then(|value| Self::new(value).ok())
+            .expect("`Ranged` operation result is out of range")
+    }
+
+    /// Applies a modular operation to the residues of `values` modulo the range size,
+    /// and wraps the result back into the range.
+    fn wrapping_op<const N: usize>(
+        values: [T; N],
+        op: impl FnOnce(Modulus, [u128; N]) -> u128,
+    ) -> Self {
+        let (start, end) = Self::BOUNDS;
+        let modulus = Modulus((end as u128).wrapping_sub(start as u128).wrapping_add(1));
+
+        let residue = op(
+            modulus,
+            values.map(|value| modulus.residue(value.saturating_as::<i128>())),
+        );
+        let offset = modulus.sub(residue, modulus.residue(start));
+
+        Self(T::saturating_from_num(
+            (start as u128).wrapping_add(offset) as i128
+        ))
+    }
+}
+
+/// A modulus for wrapping operations on `u128` residues, where `0` represents `2^128`.
+#[derive(Clone, Copy)]
+struct Modulus(u128);
+
+impl Modulus {
+    fn residue(self, value: i128) -> u128 {
+        if self.0 == 0 {
+            value as u128
+        } else if self.0 <= i128::MAX as u128 {
+            value.rem_euclid(self.0 as i128) as u128
+        } else if value >= 0 {
+            value as u128
+        } else {
+            // `|value| <= 2^127 < self.0`.
+            self.0 - value.unsigned_abs()
+        }
+    }
+
+    fn add(self, a: u128, b: u128) -> u128 {
+        if self.0 == 0 {
+            a.wrapping_add(b)
+        } else if a >= self.0 - b {
+            a - (self.0 - b)
+        } else {
+            a + b
+        }
+    }
+
+    fn sub(self, a: u128, b: u128) -> u128 {
+        if self.0 == 0 || a >= b {
+            a.wrapping_sub(b)
+        } else {
+            self.0 - (b - a)
+        }
+    }
+
+    fn mul(self, a: u128, b: u128) -> u128 {
+        if self.0 == 0 {
+            return a.wrapping_mul(b);
+        }
+        if let Some(product) = a.checked_mul(b) {
+            return product % self.0;
+        }
+
+        let mut output = 0;
+        let mut a = a;
+        let mut b = b;
+        while b != 0 {
+            if b % 2 == 1 {
+                output = self.add(output, a);
+            }
+
+            a = self.add(a, a);
+            b /= 2;
+        }
+
+        output
+    }
+
+    fn pow(self, base: u128, exp: u32) -> u128 {
+        let mut output = self.residue(1);
+        let mut base = base;
+        let mut exp = exp;
+        while exp != 0 {
+            if exp % 2 == 1 {
+                output = self.mul(output, base);
+            }
+
+            base = self.mul(base, base);
+            exp /= 2;
+        }
+
+        output
+    }
+}
+
+/// Computes `base^exp`, or `None` if it overflows `T`.
+fn checked_pow<T: IntPrim>(base: T, exp: u32) -> Option<T> {
+    let mut output = T::one();
+    let mut base = base;
+    let mut exp = exp;
+    while exp != 0 {
+        if exp % 2 == 1 {
+            output = output.checked_mul(base)?;
+        }
+
+        exp /= 2;
+        if exp != 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+
+    Some(output)
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Display for Ranged<T, MIN, MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+macro_rules! op_impl {
+    ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident), $checked_fn:ident) => {
+        impl<T: IntPrim, const MIN: i128, const MAX: i128> $trait for Ranged<T, MIN, MAX> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Self::expect_in_range(self.0.$checked_fn(rhs.0))
+            }
+        }
+
+        impl<T: IntPrim, const MIN: i128, const MAX: i128> $assign_trait for Ranged<T, MIN, MAX> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(*self, rhs);
+            }
+        }
+    };
+}
+op_impl!(Add(add), AddAssign(add_assign), checked_add);
+op_impl!(Sub(sub), SubAssign(sub_assign), checked_sub);
+op_impl!(Mul(mul), MulAssign(mul_assign), checked_mul);
+op_impl!(Div(div), DivAssign(div_assign), checked_div);
+op_impl!(Rem(rem), RemAssign(rem_assign), checked_rem);
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Neg for Ranged<T, MIN, MAX> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::expect_in_range(self.0.checked_neg())
+    }
+}
+
+macro_rules! checked_impl {
+    ($trait:ident($fn:ident)) => {
+        impl<T: IntPrim, const MIN: i128, const MAX: i128> $trait for Ranged<T, MIN, MAX> {
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Option<Self> {
+                self.0.$fn(rhs.0).and_then(|value| Self::new(value).ok())
+            }
+        }
+    };
+}
+checked_impl!(CheckedAdd(checked_add));
+checked_impl!(CheckedSub(checked_sub));
+checked_impl!(CheckedMul(checked_mul));
+checked_impl!(CheckedDiv(checked_div));
+checked_impl!(CheckedRem(checked_rem));
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> CheckedNeg for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().and_then(|value| Self::new(value).ok())
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> CheckedShl for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn checked_shl(self, rhs: u32) -> Option<Self> {
+        self.0
+            .checked_shl(rhs)
+            .and_then(|value| Self::new(value).ok())
+    }
+}
+
+/// Saturates to the bounds of the range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> SaturatingOps for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn saturating_add(self, rhs: Self) -> Self {
+        Self::saturating(self.0.saturating_add(rhs.0))
+    }
+    #[inline(always)]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        Self::saturating(self.0.saturating_sub(rhs.0))
+    }
+    #[inline(always)]
+    fn saturating_mul(self, rhs: Self) -> Self {
+        Self::saturating(self.0.saturating_mul(rhs.0))
+    }
+
+    #[inline(always)]
+    fn saturating_neg(self) -> Self {
+        Self::saturating(self.0.saturating_neg())
+    }
+
+    #[inline(always)]
+    fn saturating_shl(self, rhs: u32) -> Self {
+        Self::saturating(self.0.saturating_shl(rhs))
+    }
+
+    #[inline(always)]
+    fn saturating_pow(self, exp: u32) -> Self {
+        Self::saturating(self.0.saturating_pow(exp))
+    }
+}
+
+/// Wraps around the range, so `MAX + 1` is `MIN`.
+/// `wrapping_shl` multiplies by `2^rhs` and wraps.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> WrappingOps for Ranged<T, MIN, MAX> {
+    fn wrapping_add(self, rhs: Self) -> Self {
+        Self::wrapping_op([self.0, rhs.0], |modulus, [a, b]| modulus.add(a, b))
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::wrapping_op([self.0, rhs.0], |modulus, [a, b]| modulus.sub(a, b))
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::wrapping_op([self.0, rhs.0], |modulus, [a, b]| modulus.mul(a, b))
+    }
+
+    fn wrapping_neg(self) -> Self {
+        Self::wrapping_op([self.0], |modulus, [a]| modulus.sub(0, a))
+    }
+
+    fn wrapping_shl(self, rhs: u32) -> Self {
+        Self::wrapping_op([self.0], |modulus, [a]| {
+            modulus.mul(a, modulus.pow(modulus.residue(2), rhs))
+        })
+    }
+
+    fn wrapping_pow(self, exp: u32) -> Self {
+        Self::wrapping_op([self.0], |modulus, [a]| modulus.pow(a, exp))
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Signed for Ranged<T, MIN, MAX> {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    #[inline(always)]
+    fn sign(&self) -> Sign {
+        self.0.sign()
+    }
+    #[inline(always)]
+    fn bit_sign(&self) -> BitSign {
+        self.0.bit_sign()
+    }
+
+    #[inline(always)]
+    fn is_positive(&self) -> Self::BoolMapped {
+        Signed::is_positive(&self.0)
+    }
+    #[inline(always)]
+    fn is_negative(&self) -> Self::BoolMapped {
+        Signed::is_negative(&self.0)
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> Self::BoolMapped {
+        self.0.is_zero()
+    }
+
+    #[inline(always)]
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        self.0.is_bin_positive()
+    }
+    #[inline(always)]
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        self.0.is_bin_negative()
+    }
+}
+
+/// Panics if the absolute value is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Positive for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Self::expect_in_range(Some(self.0.abs()))
+    }
+}
+
+/// Panics if the negative absolute value is out of range.
+impl<T: IntPrim + Negative, const MIN: i128, const MAX: i128> Negative for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        Self::expect_in_range(Some(self.0.neg_abs()))
+    }
+}
+
+/// Panics if `0` is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Zero for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::expect_in_range(Some(T::zero()))
+    }
+}
+
+/// Using `ZERO` is a compile error if `0` is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> ConstZero for Ranged<T, MIN, MAX> {
+    const ZERO: Self = {
+        assert!(MIN <= 0 && 0 <= MAX, "`0` is out of range");
+
+        Self(T::ZERO)
+    };
+}
+
+/// Panics if `1` is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> One for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::expect_in_range(Some(T::one()))
+    }
+}
+
+/// Using `ONE` is a compile error if `1` is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> ConstOne for Ranged<T, MIN, MAX> {
+    const ONE: Self = {
+        assert!(MIN <= 1 && 1 <= MAX, "`1` is out of range");
+
+        Self(T::ONE)
+    };
+}
+
+impl<T: IntPrim, const MAX: i128> NotNegative for Ranged<T, 0, MAX> {}
+impl<T: IntPrim, const MAX: i128> NotNegative for Ranged<T, 1, MAX> {}
+impl<T: IntPrim, const MAX: i128> NotZero for Ranged<T, 1, MAX> {}
+impl<T: IntPrim, const MIN: i128> NotPositive for Ranged<T, MIN, 0> {}
+impl<T: IntPrim, const MIN: i128> NotPositive for Ranged<T, MIN, -1> {}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> TypeMin for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn type_min() -> Self {
+        Self(T::saturating_from_num(Self::BOUNDS.0))
+    }
+}
+impl<T: IntPrim, const MIN: i128, const MAX: i128> TypeMax for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn type_max() -> Self {
+        Self(T::saturating_from_num(Self::BOUNDS.1))
+    }
+}
+
+/// Panics if the difference is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> AbsDiff for Ranged<T, MIN, MAX> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        Self::expect_in_range(Some(self.0.abs_diff(rhs.0)))
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> MinMax for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+}
+
+/// Panics if the root is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> TruncRoot for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn trunc_sqrt(self) -> Self {
+        Self::expect_in_range(Some(self.0.trunc_sqrt()))
+    }
+    #[inline(always)]
+    fn trunc_cbrt(self) -> Self {
+        Self::expect_in_range(Some(self.0.trunc_cbrt()))
+    }
+    #[inline(always)]
+    fn trunc_nth_root(self, n: u32) -> Self {
+        Self::expect_in_range(Some(self.0.trunc_nth_root(n)))
+    }
+
+    #[inline(always)]
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        self.0
+            .checked_exact_sqrt()
+            .and_then(|value| Self::new(value).ok())
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Pow<u32> for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn pow(self, exp: u32) -> Self {
+        Self::expect_in_range(checked_pow(self.0, exp))
+    }
+}
+
+/// `fract` returns `zero()`, so it panics if `0` is out of range.
+/// `round_to_multiple` and `round_to_decimals` panic if the result is out of range.
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Round for Ranged<T, MIN, MAX> {
+    #[inline(always)]
+    fn round(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn round_ties_even(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn atrunc(self) -> Self {
+        self
+    }
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::zero()
+    }
+
+    #[inline(always)]
+    fn round_to_multiple(self, step: Self) -> Self {
+        Self::expect_in_range(Some(self.0.round_to_multiple(step.0)))
+    }
+    #[inline(always)]
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        Self::expect_in_range(Some(self.0.round_to_decimals(decimals)))
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> FromIntLiteral for Ranged<T, MIN, MAX> {
+    const MIN_LITERAL: i128 = if T::MIN_LITERAL > MIN {
+        T::MIN_LITERAL
+    } else {
+        MIN
+    };
+    const MAX_LITERAL: i128 = if T::MAX_LITERAL < MAX {
+        T::MAX_LITERAL
+    } else {
+        MAX
+    };
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self(T::from_int_literal(value))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self(T::approx_from_int_literal(value))
+    }
+}
+
+impl<T: IntPrim, const MIN: i128, const MAX: i128> Num for Ranged<T, MIN, MAX> {}
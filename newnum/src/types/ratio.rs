@@ -0,0 +1,832 @@
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+};
+
+use crate::*;
+
+/// An exact rational number `numer / denom` of the integer type `T`.
+///
+/// A `Ratio` is always normalized:
+/// the denominator is positive and the numerator and denominator have no common factors,
+/// so equal values have equal representations and `0` is always `0 / 1`.
+///
+/// All operations are exact, and panic if the result (or an intermediate value) overflows `T`,
+/// in both debug and release builds.
+///
+/// Float literals (`num!(0.1)`) are converted from the exact decimal digits of the literal and not through `f64`,
+/// so `num!(0.1: Ratio<i64>)` is exactly `1 / 10`.
+/// Literals that can't be represented exactly are approximated by the last continued-fraction convergent that fits in `T`.
+/// Only the decimal digits of the literal are extracted at compile time,
+/// and the conversion into `T` (including the continued-fraction approximation) runs at runtime.
+///
+/// `T` can be any [`Int`], including non `Copy` integers like `BigInt` (`bigint` feature),
+/// in which case operations never overflow.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::*;
+///
+/// let inch: Ratio<i64> = num!(25.4);
+/// let third = Ratio::new(1, 3);
+///
+/// assert_eq!(inch, Ratio::new(127, 5));
+/// assert_eq!(inch * third, Ratio::new(127, 15));
+/// assert_eq!((inch * third).floor(), num!(8));
+/// assert_eq!((inch * third).fract(), Ratio::new(7, 15));
+/// assert_eq!(Ratio::new(6, -4).to_string(), "-3/2");
+/// assert_eq!(*inch.numer(), 127);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Int> Ratio<T> {
+    /// Creates the normalized ratio `numer / denom`.
+    ///
+    /// Panics if `denom` is zero, or if normalizing overflows (for example `i32::MIN / -1`).
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!Signed::is_zero(&denom), "`Ratio` denominator is zero");
+
+        let gcd = signed_gcd(numer.clone(), denom.clone());
+
+        Self::with_denom_sign(numer / gcd.clone(), denom / gcd)
+    }
+
+    /// Creates the ratio `value / 1`.
+    #[inline(always)]
+    pub fn from_integer(value: T) -> Self {
+        Self {
+            numer: value,
+            denom: T::one(),
+        }
+    }
+
+    /// Returns the numerator, which has the sign of `self`.
+    #[inline(always)]
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    /// Returns the denominator, which is always positive.
+    #[inline(always)]
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    /// Returns `(numer, denom)`.
+    #[inline(always)]
+    pub fn into_parts(self) -> (T, T) {
+        (self.numer, self.denom)
+    }
+
+    /// Returns `1 / self`.
+    ///
+    /// Panics if `self` is zero.
+    pub fn recip(self) -> Self {
+        assert!(!Signed::is_zero(&self.numer), "attempt to divide by zero");
+
+        Self::with_denom_sign(self.denom, self.numer)
+    }
+
+    /// Creates a ratio from a reduced `numer / denom` where `denom` is not zero but may be negative.
+    fn with_denom_sign(numer: T, denom: T) -> Self {
+        if Signed::is_negative(&denom) {
+            Self {
+                numer: numer
+                    .checked_neg()
+                    .expect("attempt to negate with overflow"),
+                denom: denom
+                    .checked_neg()
+                    .expect("attempt to negate with overflow"),
+            }
+        } else {
+            Self { numer, denom }
+        }
+    }
+
+    /// Adds or subtracts `rhs` using `op`,
+    /// keeping intermediate values small by only scaling by the least common multiple of the denominators.
+    fn add_sub(self, rhs: Self, op: fn(T, T) -> Option<T>, msg: &str) -> Self {
+        let gcd = self.denom.clone().gcd(rhs.denom.clone());
+        let lhs_scale = rhs.denom / gcd.clone();
+
+        let numer = self
+            .numer
+            .checked_mul(lhs_scale.clone())
+            .and_then(|lhs| {
+                op(
+                    lhs,
+                    rhs.numer.checked_mul(self.denom.clone() / gcd.clone())?,
+                )
+            })
+            .expect(msg);
+
+        if Signed::is_zero(&numer) {
+            return Self::zero();
+        }
+
+        // The result is already reduced after dividing by `gcd(numer, gcd)` (Knuth, TAOCP 4.5.1).
+        let numer_gcd = numer.clone().gcd(gcd);
+
+        Self {
+            numer: numer / numer_gcd.clone(),
+            denom: (self.denom / numer_gcd).checked_mul(lhs_scale).expect(msg),
+        }
+    }
+
+    /// Computes `(a / b) * (c / d)` for reduced ratios, where `b` and `d` are not zero but may be negative.
+    fn mul_reduced(a: T, b: T, c: T, d: T, msg: &str) -> Self {
+        let ad_gcd = a.clone().gcd(d.clone());
+        let cb_gcd = c.clone().gcd(b.clone());
+
+        Self::with_denom_sign(
+            (a / ad_gcd.clone())
+                .checked_mul(c / cb_gcd.clone())
+                .expect(msg),
+            (b / cb_gcd).checked_mul(d / ad_gcd).expect(msg),
+        )
+    }
+
+    /// Converts `numer / denom` into a ratio of `T`, where `denom` is positive.
+    ///
+    /// The result is exact if the reduced fraction fits in `T`,
+    /// otherwise it is the last continued-fraction convergent of `numer / denom` that fits in `T`,
+    /// or `numer / denom` saturated to the range of `T` if not even the whole part fits.
+    fn from_i128_fraction(numer: i128, denom: i128) -> Self {
+        let negative = numer < 0;
+        let fit = |numer: u128, denom: u128| {
+            let numer = i128::try_from(numer).ok()?;
+            let numer = if negative { -numer } else { numer };
+
+            Some(Self {
+                numer: int_from_i128(numer)?,
+                denom: int_from_i128(i128::try_from(denom).ok()?)?,
+            })
+        };
+
+        let (mut numer, mut denom) = (numer.unsigned_abs(), denom.unsigned_abs());
+        let (mut prev_numer, mut convergent_numer) = (0_u128, 1_u128);
+        let (mut prev_denom, mut convergent_denom) = (1_u128, 0_u128);
+        let mut output = None;
+        while denom != 0 {
+            let term = numer / denom;
+            let next = term
+                .checked_mul(convergent_numer)
+                .and_then(|value| value.checked_add(prev_numer))
+                .zip(
+                    term.checked_mul(convergent_denom)
+                        .and_then(|value| value.checked_add(prev_denom)),
+                );
+
+            match next.and_then(|(next_numer, next_denom)| fit(next_numer, next_denom)) {
+                Some(convergent) => output = Some(convergent),
+                None => break,
+            }
+
+            (prev_numer, convergent_numer) = (convergent_numer, next.unwrap().0);
+            (prev_denom, convergent_denom) = (convergent_denom, next.unwrap().1);
+            (numer, denom) = (denom, numer % denom);
+        }
+
+        output.unwrap_or_else(|| {
+            let bound = if negative {
+                T::MIN_LITERAL
+            } else {
+                T::MAX_LITERAL
+            };
+
+            // Safe because `bound` is one of the literal bounds of `T`.
+            Self::from_integer(unsafe { T::from_int_literal(bound) })
+        })
+    }
+}
+
+/// Converts `value` into `T` through its literal range, or returns `None` if it is out of it.
+fn int_from_i128<T: Int>(value: i128) -> Option<T> {
+    // Safe because `value` is checked to be in the literal range of `T`.
+    (T::MIN_LITERAL..=T::MAX_LITERAL)
+        .contains(&value)
+        .then(|| unsafe { T::from_int_literal(value) })
+}
+
+/// Computes `floor(value / denom)` and `value - floor(value / denom) * denom` for a positive `denom`.
+fn div_rem_floor<T: Int>(value: T, denom: T) -> (T, T) {
+    let quotient = value.clone() / denom.clone();
+    let remainder = value % denom.clone();
+
+    if Signed::is_negative(&remainder) {
+        (quotient - T::one(), remainder + denom)
+    } else {
+        (quotient, remainder)
+    }
+}
+
+/// Writes `numer/denom`, or only `numer` if `self` is whole.
+impl<T: Int> Display for Ratio<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denom == T::one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl<T: Int> Debug for Ratio<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<T: Int> Ord for Ratio<T> {
+    /// Compares the continued-fraction expansions of both ratios, which never overflows.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (mut a, mut b, mut c, mut d) = (
+            self.numer.clone(),
+            self.denom.clone(),
+            other.numer.clone(),
+            other.denom.clone(),
+        );
+        loop {
+            // `a / b` vs `c / d` where `b, d > 0`.
+            let (lhs_whole, lhs_fract) = div_rem_floor(a, b.clone());
+            let (rhs_whole, rhs_fract) = div_rem_floor(c, d.clone());
+            let whole_ordering = lhs_whole.cmp(&rhs_whole);
+            if whole_ordering != Ordering::Equal {
+                return whole_ordering;
+            }
+
+            match (Signed::is_zero(&lhs_fract), Signed::is_zero(&rhs_fract)) {
+                (true, true) => return Ordering::Equal,
+                (true, false) => return Ordering::Less,
+                (false, true) => return Ordering::Greater,
+                // `lhs_fract / b` vs `rhs_fract / d` is the reversed `d / rhs_fract` vs `b / lhs_fract`.
+                (false, false) => (a, b, c, d) = (d, rhs_fract, b, lhs_fract),
+            }
+        }
+    }
+}
+
+impl<T: Int> PartialOrd for Ratio<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Int> Add for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_sub(rhs, T::checked_add, "attempt to add with overflow")
+    }
+}
+
+impl<T: Int> Sub for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add_sub(rhs, T::checked_sub, "attempt to subtract with overflow")
+    }
+}
+
+impl<T: Int> Mul for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::mul_reduced(
+            self.numer,
+            self.denom,
+            rhs.numer,
+            rhs.denom,
+            "attempt to multiply with overflow",
+        )
+    }
+}
+
+impl<T: Int> Div for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!Signed::is_zero(&rhs.numer), "attempt to divide by zero");
+
+        Self::mul_reduced(
+            self.numer,
+            self.denom,
+            rhs.denom,
+            rhs.numer,
+            "attempt to divide with overflow",
+        )
+    }
+}
+
+/// The remainder has the sign of `self`, like for integers and floats.
+impl<T: Int> Rem for Ratio<T> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        assert!(
+            !Signed::is_zero(&rhs.numer),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+
+        let msg = "attempt to calculate the remainder with overflow";
+        let gcd = self.denom.clone().gcd(rhs.denom.clone());
+        let lhs = self
+            .numer
+            .checked_mul(rhs.denom.clone() / gcd.clone())
+            .expect(msg);
+        let rhs_numer = rhs
+            .numer
+            .checked_mul(self.denom.clone() / gcd.clone())
+            .expect(msg);
+
+        // `checked_rem` only fails for `MIN % -1` which is `0`.
+        let numer = lhs.checked_rem(rhs_numer).unwrap_or_else(T::zero);
+
+        Self::new(numer, (self.denom / gcd).checked_mul(rhs.denom).expect(msg))
+    }
+}
+
+macro_rules! assign_op_impl {
+    ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident)) => {
+        impl<T: Int> $assign_trait for Ratio<T> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = $trait::$fn(std::mem::replace(self, Self::zero()), rhs);
+            }
+        }
+    };
+}
+assign_op_impl!(Add(add), AddAssign(add_assign));
+assign_op_impl!(Sub(sub), SubAssign(sub_assign));
+assign_op_impl!(Mul(mul), MulAssign(mul_assign));
+assign_op_impl!(Div(div), DivAssign(div_assign));
+assign_op_impl!(Rem(rem), RemAssign(rem_assign));
+
+impl<T: Int> Neg for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self {
+            numer: self
+                .numer
+                .checked_neg()
+                .expect("attempt to negate with overflow"),
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Int> Signed for Ratio<T> {
+    type SignMapped = Sign;
+    type BitSignMapped = BitSign;
+    type BoolMapped = bool;
+
+    #[inline(always)]
+    fn sign(&self) -> Sign {
+        self.numer.sign()
+    }
+    #[inline(always)]
+    fn bit_sign(&self) -> BitSign {
+        self.numer.bit_sign()
+    }
+
+    #[inline(always)]
+    fn is_positive(&self) -> Self::BoolMapped {
+        Signed::is_positive(&self.numer)
+    }
+    #[inline(always)]
+    fn is_negative(&self) -> Self::BoolMapped {
+        Signed::is_negative(&self.numer)
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> Self::BoolMapped {
+        Signed::is_zero(&self.numer)
+    }
+
+    #[inline(always)]
+    fn is_bin_positive(&self) -> Self::BoolMapped {
+        self.numer.is_bin_positive()
+    }
+    #[inline(always)]
+    fn is_bin_negative(&self) -> Self::BoolMapped {
+        self.numer.is_bin_negative()
+    }
+}
+
+impl<T: Int> Signum for Ratio<T> {
+    #[inline(always)]
+    fn signumt(self) -> Self {
+        Self::from_integer(self.numer.signumt())
+    }
+
+    #[inline(always)]
+    fn bin_signum(self) -> Self {
+        Self::from_integer(self.numer.bin_signum())
+    }
+}
+
+impl<T: Int> Positive for Ratio<T> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        if Signed::is_negative(&self) {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Int + Negative> Negative for Ratio<T> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        if Signed::is_positive(&self) {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+impl<T: Int + NotNegative> NotNegative for Ratio<T> {}
+
+impl<T: Int> Zero for Ratio<T> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::from_integer(T::zero())
+    }
+}
+
+impl<T: Int + ConstZero + ConstOne> ConstZero for Ratio<T> {
+    const ZERO: Self = Self {
+        numer: T::ZERO,
+        denom: T::ONE,
+    };
+}
+
+impl<T: Int> One for Ratio<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::from_integer(T::one())
+    }
+}
+
+impl<T: Int + ConstOne> ConstOne for Ratio<T> {
+    const ONE: Self = Self {
+        numer: T::ONE,
+        denom: T::ONE,
+    };
+}
+
+impl<T: Int + TypeMin> TypeMin for Ratio<T> {
+    #[inline(always)]
+    fn type_min() -> Self {
+        Self::from_integer(T::type_min())
+    }
+}
+impl<T: Int + TypeMax> TypeMax for Ratio<T> {
+    #[inline(always)]
+    fn type_max() -> Self {
+        Self::from_integer(T::type_max())
+    }
+}
+
+impl<T: Int + NoTypeMin> NoTypeMin for Ratio<T> {}
+impl<T: Int + NoTypeMax> NoTypeMax for Ratio<T> {}
+
+impl<T: Int> AbsDiff for Ratio<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn abs_diff(self, rhs: Self) -> Self::Output {
+        if self > rhs {
+            self - rhs
+        } else {
+            rhs - self
+        }
+    }
+}
+
+impl<T: Int> MinMax for Ratio<T> {
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Ord::max(self, other)
+    }
+
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        Ord::clamp(self, min, max)
+    }
+}
+
+/// Computes `value^exp`, or `None` if it overflows.
+fn checked_pow<T: Int>(value: T, exp: u32) -> Option<T> {
+    let mut output = T::one();
+    let mut base = value;
+    let mut exp = exp;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            output = output.checked_mul(base.clone())?;
+        }
+
+        exp >>= 1;
+        if exp != 0 {
+            base = base.clone().checked_mul(base)?;
+        }
+    }
+
+    Some(output)
+}
+
+/// Powers of a normalized ratio are already normalized, so this only overflows if the result doesn't fit in `T`.
+impl<T: Int> Pow<u32> for Ratio<T> {
+    fn pow(self, exp: u32) -> Self {
+        let msg = "attempt to multiply with overflow";
+
+        Self {
+            numer: checked_pow(self.numer, exp).expect(msg),
+            denom: checked_pow(self.denom, exp).expect(msg),
+        }
+    }
+}
+
+/// Negative exponents compute the power of the reciprocal, and panic if `self` is zero.
+impl<T: Int> Pow<i32> for Ratio<T> {
+    fn pow(self, exp: i32) -> Self {
+        if exp < 0 {
+            Pow::<u32>::pow(self.recip(), exp.unsigned_abs())
+        } else {
+            Pow::<u32>::pow(self, exp as u32)
+        }
+    }
+}
+
+impl<T: Int> Round for Ratio<T> {
+    fn round(self) -> Self {
+        let whole = self.numer.clone() / self.denom.clone();
+        let remainder = (self.numer.clone() % self.denom.clone()).abs();
+        if remainder.clone() >= self.denom - remainder {
+            Self::from_integer(whole + self.numer.signumt())
+        } else {
+            Self::from_integer(whole)
+        }
+    }
+
+    fn round_ties_even(self) -> Self {
+        let whole = self.numer.clone() / self.denom.clone();
+        let remainder = (self.numer.clone() % self.denom.clone()).abs();
+        let rest = self.denom - remainder.clone();
+        if remainder > rest || (remainder == rest && whole.clone() & T::one() == T::one()) {
+            Self::from_integer(whole + self.numer.signumt())
+        } else {
+            Self::from_integer(whole)
+        }
+    }
+
+    #[inline(always)]
+    fn floor(self) -> Self {
+        Self::from_integer(self.ifloor())
+    }
+
+    #[inline(always)]
+    fn ceil(self) -> Self {
+        Self::from_integer(self.iceil())
+    }
+
+    #[inline(always)]
+    fn trunc(self) -> Self {
+        Self::from_integer(self.itrunc())
+    }
+
+    #[inline(always)]
+    fn atrunc(self) -> Self {
+        if Signed::is_negative(&self) {
+            self.floor()
+        } else {
+            self.ceil()
+        }
+    }
+
+    /// The fractional part has the sign of `self`, like for floats.
+    #[inline(always)]
+    fn fract(self) -> Self {
+        Self::new(self.numer % self.denom.clone(), self.denom)
+    }
+
+    fn round_to_multiple(self, step: Self) -> Self {
+        assert!(
+            !Signed::is_zero(&step),
+            "attempt to round to a multiple of zero"
+        );
+
+        let step = step.abs();
+
+        (self / step.clone()).round() * step
+    }
+
+    /// Returns `self` if `10^decimals` is out of the range of `T`,
+    /// and zero if `10^-decimals` is.
+    fn round_to_decimals(self, decimals: i32) -> Self {
+        let scale = checked_pow(internal_num!(10), decimals.unsigned_abs());
+
+        match scale {
+            Some(scale) if decimals > 0 => {
+                let scale = Self::from_integer(scale);
+
+                (self * scale.clone()).round() / scale
+            }
+            Some(step) => self.round_to_multiple(Self::from_integer(step)),
+            None if decimals > 0 => self,
+            None => Self::zero(),
+        }
+    }
+}
+
+impl<T: Int> WholeEquivalent for Ratio<T> {
+    type Whole = T;
+
+    #[inline(always)]
+    fn iround(self) -> Self::Whole {
+        self.round().numer
+    }
+
+    #[inline(always)]
+    fn ifloor(self) -> Self::Whole {
+        div_rem_floor(self.numer, self.denom).0
+    }
+
+    #[inline(always)]
+    fn iceil(self) -> Self::Whole {
+        let (floor, remainder) = div_rem_floor(self.numer, self.denom);
+
+        if Signed::is_zero(&remainder) {
+            floor
+        } else {
+            floor + T::one()
+        }
+    }
+
+    #[inline(always)]
+    fn itrunc(self) -> Self::Whole {
+        self.numer / self.denom
+    }
+
+    #[inline(always)]
+    fn iatrunc(self) -> Self::Whole {
+        self.atrunc().numer
+    }
+}
+
+/// Truncated roots are exact, because the truncated root of `self` is the truncated root of `self.trunc()`.
+impl<T: Int> TruncRoot for Ratio<T> {
+    fn trunc_sqrt(self) -> Self {
+        assert!(
+            !Signed::is_negative(&self),
+            "square root of a negative number"
+        );
+
+        Self::from_integer(self.itrunc().trunc_sqrt())
+    }
+
+    fn trunc_cbrt(self) -> Self {
+        Self::from_integer(self.itrunc().trunc_cbrt())
+    }
+
+    fn trunc_nth_root(self, n: u32) -> Self {
+        assert!(n != 0, "zeroth root is undefined");
+        assert!(
+            n & 1 == 1 || !Signed::is_negative(&self),
+            "even root of a negative number"
+        );
+
+        Self::from_integer(self.itrunc().trunc_nth_root(n))
+    }
+
+    fn checked_exact_sqrt(self) -> Option<Self> {
+        if self.denom == T::one() {
+            self.numer.checked_exact_sqrt().map(Self::from_integer)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Int> FromIntLiteral for Ratio<T> {
+    const MIN_LITERAL: i128 = T::MIN_LITERAL;
+    const MAX_LITERAL: i128 = T::MAX_LITERAL;
+
+    #[inline(always)]
+    unsafe fn from_int_literal(value: i128) -> Self {
+        Self::from_integer(T::from_int_literal(value))
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_int_literal(value: i128) -> Self {
+        Self::from_integer(T::approx_from_int_literal(value))
+    }
+}
+
+/// `from_float_literal` converts the exact binary value of the `f64`,
+/// while the `num` macro uses `from_decimal_literal` which converts the exact decimal value of the literal.
+impl<T: Int> FromFloatLiteral for Ratio<T> {
+    unsafe fn from_float_literal(value: f64) -> Self {
+        let bits = value.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let mantissa = bits & ((1 << 52) - 1);
+
+        // `value = mantissa * 2^exponent`, with the implicit bit of normal floats.
+        let (mantissa, exponent) = if exponent == 0 {
+            (mantissa as i128, -1074)
+        } else {
+            ((mantissa | 1 << 52) as i128, exponent - 1075)
+        };
+        let mantissa = if value.is_sign_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+
+        if exponent >= 0 {
+            // `mantissa < 2^53`, so larger shifts overflow `i128`.
+            let numer = if exponent >= 74 {
+                mantissa.signum() * i128::MAX
+            } else {
+                mantissa << exponent
+            };
+
+            Self::from_i128_fraction(numer, 1)
+        } else if exponent >= -126 {
+            Self::from_i128_fraction(mantissa, 1 << -exponent)
+        } else {
+            // Only drops bits far beyond the precision of any `T`.
+            let shift = Ord::min(-126 - exponent, 127) as u32;
+
+            Self::from_i128_fraction(mantissa >> shift, 1 << 126)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn approx_from_float_literal(value: f64) -> Self {
+        Self::from_float_literal(value)
+    }
+
+    unsafe fn from_decimal_literal(_value: f64, digits: i128, exponent: i32) -> Self {
+        if exponent >= 0 {
+            let numer = 10_i128
+                .checked_pow(exponent as u32)
+                .and_then(|scale| digits.checked_mul(scale))
+                .unwrap_or(digits.signum() * i128::MAX);
+
+            Self::from_i128_fraction(numer, 1)
+        } else {
+            // `10^38` is the largest power of ten that fits in `i128`.
+            let mut digits = digits;
+            let mut exponent = exponent;
+            while exponent < -38 {
+                digits = (digits + digits.signum() * 5) / 10;
+                exponent += 1;
+            }
+
+            Self::from_i128_fraction(digits, 10_i128.pow(exponent.unsigned_abs()))
+        }
+    }
+}
+
+impl<T: Int> Num for Ratio<T> {}
+
+/// Computes the greatest common divisor of `a` and `b`, where `b` isn't zero.
+///
+/// Unlike `NumberTheory::gcd` it doesn't overflow if the result is `-T::MIN` (for example `gcd(MIN, MIN)`),
+/// and returns `T::MIN` instead.
+/// `a` and `b` are then both `0` or `MIN`, so dividing them by the result doesn't overflow.
+fn signed_gcd<T: Int>(a: T, b: T) -> T {
+    let mut a = a;
+    let mut b = b;
+    while !Signed::is_zero(&b) {
+        // `checked_rem` only fails for `MIN % -1` which is `0`.
+        let remainder = a.checked_rem(b.clone()).unwrap_or(T::zero());
+
+        a = b;
+        b = remainder;
+    }
+
+    if Signed::is_negative(&a) {
+        a.clone().checked_neg().unwrap_or(a)
+    } else {
+        a
+    }
+}
@@ -0,0 +1,524 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    ops,
+};
+
+use crate::*;
+
+/// A number that is always positive (never zero, negative or `NaN`).
+///
+/// Implements [`AlwaysPositive`].
+/// Only operators that keep the value positive return `Pos` (`+`, `*`),
+/// and the others return `T` (`-`, `/`, `%`).
+/// An operation that breaks the invariant anyway (like an overflow or a float underflow to `0.0`) panics.
+///
+/// Non-positive literals (like `num!(0: Pos<i32>)` and `num!(0.0: Pos<f32>)`) are compile errors.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, sign_wrappers::*};
+///
+/// fn average_speed(distance: Pos<f32>, time: Pos<f32>) -> f32 {
+///     distance.get() / time.get()
+/// }
+///
+/// let distance = Pos::new(10.0).unwrap();
+/// assert_eq!(average_speed(distance + distance, num!(4)), 5.0);
+/// assert_eq!(distance - distance, 0.0);
+/// assert!(Pos::new(0).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let value: Pos<i32> = num!(-1);
+/// ```
+///
+/// ```compile_fail
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let value: Pos<i32> = num!(0);
+/// ```
+///
+/// ```compile_fail
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let value: Pos<f32> = num!(0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos<T>(T);
+
+/// A number that is never negative (or `NaN`).
+///
+/// Implements [`NotNegative`], and [`PositiveOrZero`] if `T: Zero`.
+/// Only operators that keep the value non-negative return `NonNeg` (`+`, `*`, `/`, `%`),
+/// and the others return `T` (`-`).
+/// An operation that breaks the invariant anyway (like an overflow or `0.0 / 0.0`) panics.
+///
+/// `num!(-1: NonNeg<i32>)` is a compile error.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let count = NonNeg::new(5_i32).unwrap();
+///
+/// assert_eq!((count / num!(2)).get(), 2);
+/// assert_eq!(count - num!(7), -2);
+/// assert!(NonNeg::new(-1).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonNeg<T>(T);
+
+/// A number that is always negative (never zero, positive or `NaN`).
+///
+/// Implements [`AlwaysNegative`].
+/// Only operators that keep the value negative return `Neg` (`+`),
+/// and the others return `T` (`-`, `/`, `%`), or `Pos<T>` for `*` and unary `-`.
+/// An operation that breaks the invariant anyway (like an overflow) panics.
+///
+/// Non-negative literals (like `num!(0: Neg<i32>)` and `num!(0.0: Neg<f32>)`) are compile errors.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let debt = Neg::new(-3_i32).unwrap();
+///
+/// assert_eq!((debt + debt).get(), -6);
+/// assert_eq!((debt * debt).get(), 9);
+/// assert_eq!((-debt).get(), 3);
+/// ```
+///
+/// ```compile_fail
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let value: Neg<i32> = num!(0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Neg<T>(T);
+
+/// A number that is never zero (or `NaN`).
+///
+/// Implements [`NotZero`], and [`PositiveOrNegative`] if `T: Positive + Negative`.
+/// Only operators that keep the value non-zero return `NonZero` (`*`, unary `-`),
+/// and the others return `T` (`+`, `-`, `/`, `%`).
+/// An operation that breaks the invariant anyway (like an overflow or a float underflow to `0.0`) panics.
+///
+/// `num!(0: NonZero<i32>)` and `num!(0.0: NonZero<f32>)` are compile errors.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, sign_wrappers::*};
+///
+/// fn ratio(numerator: i32, denominator: NonZero<i32>) -> i32 {
+///     numerator / denominator.get()
+/// }
+///
+/// assert_eq!(ratio(12, NonZero::new(-4).unwrap()), -3);
+/// assert!(NonZero::new(0).is_err());
+/// ```
+///
+/// ```compile_fail
+/// use newnum::{*, sign_wrappers::*};
+///
+/// let value: NonZero<i32> = num!(0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZero<T>(T);
+
+/// The error of constructing a sign wrapper (like [`Pos`]) from a value with the wrong sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidSignError {
+    /// The sign of the value, or `None` if the value is unordered (`NaN`).
+    pub sign: Option<Sign>,
+}
+
+impl Display for InvalidSignError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.sign {
+            Some(Sign::Positive) => write!(f, "value is positive"),
+            Some(Sign::Zero) => write!(f, "value is zero"),
+            Some(Sign::Negative) => write!(f, "value is negative"),
+            None => write!(f, "value is NaN"),
+        }
+    }
+}
+
+impl Error for InvalidSignError {}
+
+/// Returns the sign of `value`, or `None` if `value` is unordered (`NaN`).
+fn ordered_sign<T: Num>(value: &T) -> Option<Sign> {
+    // Only unordered values aren't comparable to themselves.
+    value.partial_cmp(value).map(|_| value.sign())
+}
+
+impl<T: Num> Pos<T> {
+    /// Wraps `value`, or returns an error if it isn't positive.
+    pub fn new(value: T) -> Result<Self, InvalidSignError> {
+        match ordered_sign(&value) {
+            Some(Sign::Positive) => Ok(Self(value)),
+            sign => Err(InvalidSignError { sign }),
+        }
+    }
+}
+
+impl<T: Num> NonNeg<T> {
+    /// Wraps `value`, or returns an error if it is negative.
+    pub fn new(value: T) -> Result<Self, InvalidSignError> {
+        match ordered_sign(&value) {
+            Some(Sign::Positive | Sign::Zero) => Ok(Self(value)),
+            sign => Err(InvalidSignError { sign }),
+        }
+    }
+}
+
+impl<T: Num> Neg<T> {
+    /// Wraps `value`, or returns an error if it isn't negative.
+    pub fn new(value: T) -> Result<Self, InvalidSignError> {
+        match ordered_sign(&value) {
+            Some(Sign::Negative) => Ok(Self(value)),
+            sign => Err(InvalidSignError { sign }),
+        }
+    }
+}
+
+impl<T: Num> NonZero<T> {
+    /// Wraps `value`, or returns an error if it is zero.
+    pub fn new(value: T) -> Result<Self, InvalidSignError> {
+        match ordered_sign(&value) {
+            Some(Sign::Positive | Sign::Negative) => Ok(Self(value)),
+            sign => Err(InvalidSignError { sign }),
+        }
+    }
+}
+
+macro_rules! sign_wrapper_impl {
+    (
+        $wrapper:ident,
+        min_literal: $min:expr,
+        max_literal: $max:expr,
+        min_float_literal: $min_float:expr,
+        max_float_literal: $max_float:expr,
+        zero_literal: $zero:expr $(,)?
+    ) => {
+        sign_wrapper_impl!($wrapper);
+
+        impl<T: Num> FromIntLiteral for $wrapper<T> {
+            const MIN_LITERAL: i128 = if T::MIN_LITERAL > $min { T::MIN_LITERAL } else { $min };
+            const MAX_LITERAL: i128 = if T::MAX_LITERAL < $max { T::MAX_LITERAL } else { $max };
+            const MIN_APPROX_LITERAL: i128 =
+                if T::MIN_APPROX_LITERAL > $min { T::MIN_APPROX_LITERAL } else { $min };
+            const MAX_APPROX_LITERAL: i128 =
+                if T::MAX_APPROX_LITERAL < $max { T::MAX_APPROX_LITERAL } else { $max };
+            const ZERO_LITERAL: bool = T::ZERO_LITERAL && $zero;
+
+            #[inline(always)]
+            unsafe fn from_int_literal(value: i128) -> Self {
+                Self::wrap(T::from_int_literal(value))
+            }
+
+            #[inline(always)]
+            unsafe fn approx_from_int_literal(value: i128) -> Self {
+                Self::wrap(T::approx_from_int_literal(value))
+            }
+        }
+
+        impl<T: Num + FromFloatLiteral> FromFloatLiteral for $wrapper<T> {
+            const MIN_FLOAT_LITERAL: f64 =
+                if T::MIN_FLOAT_LITERAL > $min_float { T::MIN_FLOAT_LITERAL } else { $min_float };
+            const MAX_FLOAT_LITERAL: f64 =
+                if T::MAX_FLOAT_LITERAL < $max_float { T::MAX_FLOAT_LITERAL } else { $max_float };
+
+            #[inline(always)]
+            unsafe fn from_float_literal(value: f64) -> Self {
+                Self::wrap(T::from_float_literal(value))
+            }
+
+            #[inline(always)]
+            unsafe fn from_decimal_literal(value: f64, digits: i128, exponent: i32) -> Self {
+                Self::wrap(T::from_decimal_literal(value, digits, exponent))
+            }
+
+            #[inline(always)]
+            unsafe fn approx_from_float_literal(value: f64) -> Self {
+                Self::wrap(T::approx_from_float_literal(value))
+            }
+        }
+    };
+
+    ($wrapper:ident) => {
+        impl<T: Num> $wrapper<T> {
+            /// Returns the wrapped number.
+            #[inline(always)]
+            pub fn get(self) -> T {
+                self.0
+            }
+
+            /// Wraps the result of an operation, and panics if it breaks the invariant.
+            #[inline(always)]
+            fn wrap(value: T) -> Self {
+                match Self::new(value) {
+                    Ok(output) => output,
+                    Err(err) => panic!("`{}` operation result is invalid: {err}", stringify!($wrapper)),
+                }
+            }
+        }
+
+        impl<T: Num + Display> Display for $wrapper<T> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<T: Num> Signed for $wrapper<T> {
+            type SignMapped = Sign;
+            type BitSignMapped = BitSign;
+            type BoolMapped = bool;
+
+            #[inline(always)]
+            fn sign(&self) -> Sign {
+                self.0.sign()
+            }
+            #[inline(always)]
+            fn bit_sign(&self) -> BitSign {
+                self.0.bit_sign()
+            }
+
+            #[inline(always)]
+            fn is_positive(&self) -> Self::BoolMapped {
+                self.0.is_positive()
+            }
+            #[inline(always)]
+            fn is_negative(&self) -> Self::BoolMapped {
+                self.0.is_negative()
+            }
+
+            #[inline(always)]
+            fn is_zero(&self) -> Self::BoolMapped {
+                self.0.is_zero()
+            }
+
+            #[inline(always)]
+            fn is_bin_positive(&self) -> Self::BoolMapped {
+                self.0.is_bin_positive()
+            }
+            #[inline(always)]
+            fn is_bin_negative(&self) -> Self::BoolMapped {
+                self.0.is_bin_negative()
+            }
+        }
+
+        impl<T: Num> MinMax for $wrapper<T> {
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                Self(self.0.min(other.0))
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                Self(self.0.max(other.0))
+            }
+        }
+    };
+
+    ($wrapper:ident: $trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident), $op:tt) => {
+        sign_wrapper_impl!($wrapper: $trait($fn), $op => $wrapper<T>);
+
+        impl<T: Num + Copy> $assign_trait for $wrapper<T> {
+            #[inline(always)]
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+
+    ($wrapper:ident: $trait:ident($fn:ident), $op:tt => T) => {
+        impl<T: Num> $trait for $wrapper<T> {
+            type Output = T;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                self.0 $op rhs.0
+            }
+        }
+    };
+
+    ($wrapper:ident: $trait:ident($fn:ident), $op:tt => $output:ident<T>) => {
+        impl<T: Num> $trait for $wrapper<T> {
+            type Output = $output<T>;
+
+            #[inline(always)]
+            fn $fn(self, rhs: Self) -> Self::Output {
+                $output::wrap(self.0 $op rhs.0)
+            }
+        }
+    };
+}
+// Float literal ranges are inclusive, so `Pos` and `Neg` exclude `0.0` with `zero_literal` instead.
+sign_wrapper_impl!(
+    Pos,
+    min_literal: 1,
+    max_literal: i128::MAX,
+    min_float_literal: 0.0,
+    max_float_literal: f64::INFINITY,
+    zero_literal: false,
+);
+sign_wrapper_impl!(
+    NonNeg,
+    min_literal: 0,
+    max_literal: i128::MAX,
+    min_float_literal: 0.0,
+    max_float_literal: f64::INFINITY,
+    zero_literal: true,
+);
+sign_wrapper_impl!(
+    Neg,
+    min_literal: i128::MIN,
+    max_literal: -1,
+    min_float_literal: f64::NEG_INFINITY,
+    max_float_literal: 0.0,
+    zero_literal: false,
+);
+sign_wrapper_impl!(
+    NonZero,
+    min_literal: i128::MIN,
+    max_literal: i128::MAX,
+    min_float_literal: f64::NEG_INFINITY,
+    max_float_literal: f64::INFINITY,
+    zero_literal: false,
+);
+
+sign_wrapper_impl!(Pos: Add(add), AddAssign(add_assign), +);
+sign_wrapper_impl!(Pos: Mul(mul), MulAssign(mul_assign), *);
+sign_wrapper_impl!(Pos: Sub(sub), - => T);
+sign_wrapper_impl!(Pos: Div(div), / => T);
+sign_wrapper_impl!(Pos: Rem(rem), % => T);
+
+sign_wrapper_impl!(NonNeg: Add(add), AddAssign(add_assign), +);
+sign_wrapper_impl!(NonNeg: Mul(mul), MulAssign(mul_assign), *);
+sign_wrapper_impl!(NonNeg: Div(div), DivAssign(div_assign), /);
+sign_wrapper_impl!(NonNeg: Rem(rem), RemAssign(rem_assign), %);
+sign_wrapper_impl!(NonNeg: Sub(sub), - => T);
+
+sign_wrapper_impl!(Neg: Add(add), AddAssign(add_assign), +);
+sign_wrapper_impl!(Neg: Mul(mul), * => Pos<T>);
+sign_wrapper_impl!(Neg: Sub(sub), - => T);
+sign_wrapper_impl!(Neg: Div(div), / => T);
+sign_wrapper_impl!(Neg: Rem(rem), % => T);
+
+sign_wrapper_impl!(NonZero: Mul(mul), MulAssign(mul_assign), *);
+sign_wrapper_impl!(NonZero: Add(add), + => T);
+sign_wrapper_impl!(NonZero: Sub(sub), - => T);
+sign_wrapper_impl!(NonZero: Div(div), / => T);
+sign_wrapper_impl!(NonZero: Rem(rem), % => T);
+
+impl<T: Num + ops::Neg<Output = T>> ops::Neg for Pos<T> {
+    type Output = Neg<T>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Neg::wrap(-self.0)
+    }
+}
+
+impl<T: Num + ops::Neg<Output = T>> ops::Neg for Neg<T> {
+    type Output = Pos<T>;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Pos::wrap(-self.0)
+    }
+}
+
+impl<T: Num + ops::Neg<Output = T>> ops::Neg for NonZero<T> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Self::wrap(-self.0)
+    }
+}
+
+impl<T: Num> Positive for Pos<T> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        self
+    }
+}
+impl<T: Num> NotNegative for Pos<T> {}
+impl<T: Num> NotZero for Pos<T> {}
+
+impl<T: Num> Positive for NonNeg<T> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        self
+    }
+}
+impl<T: Num + Zero> Zero for NonNeg<T> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self(T::zero())
+    }
+}
+impl<T: Num + ConstZero> ConstZero for NonNeg<T> {
+    const ZERO: Self = Self(T::ZERO);
+}
+impl<T: Num> NotNegative for NonNeg<T> {}
+
+impl<T: Num> Negative for Neg<T> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        self
+    }
+}
+impl<T: Num> NotPositive for Neg<T> {}
+impl<T: Num> NotZero for Neg<T> {}
+
+impl<T: Num + Positive> Positive for NonZero<T> {
+    #[inline(always)]
+    fn abs(self) -> Self {
+        Self::wrap(self.0.abs())
+    }
+}
+impl<T: Num + Negative> Negative for NonZero<T> {
+    #[inline(always)]
+    fn neg_abs(self) -> Self {
+        Self::wrap(self.0.neg_abs())
+    }
+}
+impl<T: Num> NotZero for NonZero<T> {}
+
+impl<T: Num + One> One for Pos<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self(T::one())
+    }
+}
+impl<T: Num + ConstOne> ConstOne for Pos<T> {
+    const ONE: Self = Self(T::ONE);
+}
+
+impl<T: Num + One> One for NonNeg<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self(T::one())
+    }
+}
+impl<T: Num + ConstOne> ConstOne for NonNeg<T> {
+    const ONE: Self = Self(T::ONE);
+}
+
+impl<T: Num + One> One for NonZero<T> {
+    #[inline(always)]
+    fn one() -> Self {
+        Self(T::one())
+    }
+}
+impl<T: Num + ConstOne> ConstOne for NonZero<T> {
+    const ONE: Self = Self(T::ONE);
+}
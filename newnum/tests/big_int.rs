@@ -0,0 +1,242 @@
+#![cfg(feature = "bigint")]
+
+use newnum::{
+    num, BigInt, BigUint, CheckedNeg, CheckedSub, ILog, Int, Pow, SaturatingOps, Signum, TruncRoot,
+};
+
+/// Deterministic xorshift generator so the randomized tests are reproducible without a `rand` dependency.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a random value with a random bit length so small and large values are both covered.
+    fn next_i64(&mut self) -> i64 {
+        let bits = self.next_u64() % 64;
+        (self.next_u64() >> (63 - bits)) as i64 * if self.next_u64() >> 63 == 0 { 1 } else { -1 }
+    }
+
+    fn next_biguint(&mut self, max_limbs: u64) -> BigUint {
+        let len = self.next_u64() % max_limbs + 1;
+        BigUint::from_limbs(
+            (0..len)
+                .map(|_| self.next_u64() >> (self.next_u64() % 64))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+const RANDOM_ITERATIONS: usize = 10_000;
+
+#[test]
+fn matches_i128() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..RANDOM_ITERATIONS {
+        let (a, b) = (rng.next_i64() as i128, rng.next_i64() as i128);
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+
+        assert_eq!(
+            big_a.clone() + big_b.clone(),
+            BigInt::from(a + b),
+            "{a} + {b}"
+        );
+        assert_eq!(
+            big_a.clone() - big_b.clone(),
+            BigInt::from(a - b),
+            "{a} - {b}"
+        );
+        assert_eq!(
+            big_a.clone() * big_b.clone(),
+            BigInt::from(a * b),
+            "{a} * {b}"
+        );
+        assert_eq!(
+            big_a.clone() & big_b.clone(),
+            BigInt::from(a & b),
+            "{a} & {b}"
+        );
+        assert_eq!(
+            big_a.clone() | big_b.clone(),
+            BigInt::from(a | b),
+            "{a} | {b}"
+        );
+        assert_eq!(
+            big_a.clone() ^ big_b.clone(),
+            BigInt::from(a ^ b),
+            "{a} ^ {b}"
+        );
+        assert_eq!(!big_a.clone(), BigInt::from(!a), "!{a}");
+        assert_eq!(big_a.clone() >> 7, BigInt::from(a >> 7), "{a} >> 7");
+        assert_eq!(big_a.clone() << 9, BigInt::from(a << 9), "{a} << 9");
+        assert_eq!(
+            big_a.clone() >> BigInt::from(7),
+            BigInt::from(a >> 7),
+            "{a} >> 7"
+        );
+        assert_eq!(big_a.clone().signumt(), BigInt::from(a.signumt()));
+        assert_eq!(big_a.cmp(&big_b), a.cmp(&b), "{a} cmp {b}");
+        assert_eq!(big_a.to_string(), a.to_string());
+
+        if b != 0 {
+            assert_eq!(
+                big_a.clone() / big_b.clone(),
+                BigInt::from(a / b),
+                "{a} / {b}"
+            );
+            assert_eq!(
+                big_a.clone() % big_b.clone(),
+                BigInt::from(a % b),
+                "{a} % {b}"
+            );
+        }
+
+        assert_eq!(
+            big_a.clone().trunc_cbrt(),
+            BigInt::from(a.trunc_cbrt()),
+            "cbrt({a})"
+        );
+        if a > 0 {
+            assert_eq!(ILog::ilog2(big_a.clone()), a.ilog2(), "ilog2({a})");
+            assert_eq!(ILog::ilog10(big_a.clone()), a.ilog10(), "ilog10({a})");
+            assert_eq!(
+                ILog::checked_ilog(big_a.clone(), BigInt::from(7)),
+                a.checked_ilog(7),
+                "ilog({a}, 7)"
+            );
+        }
+        if a >= 0 {
+            assert_eq!(
+                big_a.trunc_sqrt(),
+                BigInt::from(a.trunc_sqrt()),
+                "sqrt({a})"
+            );
+        }
+    }
+}
+
+#[test]
+fn division_identity() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+
+    for _ in 0..RANDOM_ITERATIONS {
+        let lhs = rng.next_biguint(8);
+        let rhs = rng.next_biguint(5);
+        if rhs == BigUint::default() {
+            continue;
+        }
+
+        let (quotient, remainder) = lhs.div_rem(&rhs);
+        assert!(remainder < rhs, "{lhs} % {rhs} = {remainder}");
+        assert_eq!(quotient * rhs.clone() + remainder, lhs, "{lhs} / {rhs}");
+    }
+}
+
+#[test]
+fn roots_are_exact() {
+    let mut rng = XorShift(0xd1b5_4a32_d192_ed03);
+
+    for _ in 0..RANDOM_ITERATIONS / 10 {
+        let value = rng.next_biguint(6);
+
+        for n in 2..6 {
+            let root = value.clone().trunc_nth_root(n);
+            let next = root.clone() + BigUint::from(1_u8);
+
+            assert!(root.clone().pow(n) <= value, "root({value}, {n}) = {root}");
+            assert!(next.pow(n) > value, "root({value}, {n}) = {root}");
+        }
+    }
+}
+
+#[test]
+fn roots_with_large_n() {
+    let one = BigUint::from(1_u8);
+
+    assert_eq!(BigUint::from(5_u8).trunc_nth_root(1 << 24), one);
+    assert_eq!(BigUint::from(u128::MAX).trunc_nth_root(u32::MAX), one);
+    assert_eq!(BigUint::from(u128::MAX).trunc_nth_root(128), one);
+    assert_eq!(
+        (BigUint::from(u128::MAX) + one.clone()).trunc_nth_root(128),
+        BigUint::from(2_u8)
+    );
+    assert_eq!(BigUint::from(1_u8).trunc_nth_root(7), one);
+    assert_eq!(
+        BigUint::default().trunc_nth_root(1 << 24),
+        BigUint::default()
+    );
+    assert_eq!(
+        BigInt::from(-5_i8).trunc_nth_root((1 << 24) + 1),
+        BigInt::from(-1_i8)
+    );
+}
+
+/// Generic over `Int` so it runs for both primitives and big integers.
+fn digits<T: Int>(mut value: T) -> Vec<T> {
+    let mut digits = Vec::new();
+    while !value.is_zero() {
+        digits.push(value.clone() % num!(10));
+        value /= num!(10);
+    }
+
+    digits
+}
+
+#[test]
+fn generic_int() {
+    assert_eq!(digits(1203_u32), [3, 0, 2, 1]);
+
+    let value: BigUint = num!(100_000_000_000_000_000_000_000_000_000_000_000_000_000_000_007);
+    let value_digits = digits(value.clone());
+    assert_eq!(value_digits.len(), 48);
+    assert_eq!(value_digits[0], BigUint::from(7_u8));
+    assert_eq!(value_digits[47], BigUint::from(1_u8));
+    assert_eq!(ILog::ilog10(value), 47);
+
+    assert_eq!(
+        digits(BigInt::from(-42)),
+        [BigInt::from(-2), BigInt::from(-4)]
+    );
+}
+
+#[test]
+fn int_edge_cases() {
+    let zero = BigUint::default();
+    let two = BigUint::from(2_u8);
+
+    assert_eq!(
+        two.clone() << BigUint::from(100_u8),
+        BigUint::from(1_u128 << 101)
+    );
+    assert_eq!(two.clone() >> BigUint::from_limbs([0, 1]), zero);
+    assert_eq!(
+        BigInt::from(-5) >> BigInt::from(1_u128 << 64),
+        BigInt::from(-1)
+    );
+
+    assert_eq!(two.clone().saturating_sub(BigUint::from(3_u8)), zero);
+    assert_eq!(two.clone().saturating_neg(), zero);
+    assert_eq!(
+        CheckedSub::checked_sub(two.clone(), BigUint::from(3_u8)),
+        None
+    );
+    assert_eq!(CheckedNeg::checked_neg(two.clone()), None);
+    assert_eq!(CheckedNeg::checked_neg(zero.clone()), Some(zero.clone()));
+
+    assert_eq!(zero.clone().signumt(), zero);
+    assert_eq!(two.signumt(), BigUint::from(1_u8));
+    assert_eq!(BigInt::from(-9).bin_signum(), BigInt::from(-1));
+    assert_eq!(ILog::checked_ilog2(zero), None);
+    assert_eq!(ILog::checked_ilog10(BigInt::from(-10)), None);
+}
+
+#[test]
+#[should_panic]
+fn negative_shift_panics() {
+    let _ = BigInt::from(1) << BigInt::from(-1);
+}
@@ -0,0 +1,53 @@
+use newnum::*;
+
+#[test]
+fn next_power_of_two() {
+    assert_eq!(Bits::next_power_of_two(0_u8), 1);
+    assert_eq!(Bits::next_power_of_two(1_u8), 1);
+    assert_eq!(Bits::next_power_of_two(3_u8), 4);
+    assert_eq!(Bits::next_power_of_two(128_u8), 128);
+    assert_eq!(Bits::next_power_of_two(64_i8), 64);
+    assert_eq!(Bits::next_power_of_two(-5_i8), 1);
+    assert_eq!(Bits::next_power_of_two(i8::MIN), 1);
+}
+
+#[test]
+fn checked_next_power_of_two_overflow() {
+    assert_eq!(Bits::checked_next_power_of_two(129_u8), None);
+    assert_eq!(Bits::checked_next_power_of_two(u128::MAX), None);
+    assert_eq!(Bits::checked_next_power_of_two(65_i8), None);
+    assert_eq!(Bits::checked_next_power_of_two(i64::MAX), None);
+    assert_eq!(
+        Bits::checked_next_power_of_two(1_i64 << 62),
+        Some(1_i64 << 62)
+    );
+    assert_eq!(Bits::checked_next_power_of_two(-1_i32), Some(1));
+}
+
+#[test]
+#[should_panic]
+fn signed_next_power_of_two_overflow_panics() {
+    Bits::next_power_of_two(65_i8);
+}
+
+#[test]
+fn is_power_of_two() {
+    assert!(Bits::is_power_of_two(1_u8));
+    assert!(Bits::is_power_of_two(64_i8));
+    assert!(!Bits::is_power_of_two(0_u32));
+    assert!(!Bits::is_power_of_two(6_u32));
+    assert!(!Bits::is_power_of_two(i8::MIN));
+    assert!(!Bits::is_power_of_two(-2_i32));
+}
+
+#[test]
+fn signed_bit_counts() {
+    assert_eq!(Bits::count_ones(-1_i16), 16);
+    assert_eq!(Bits::leading_zeros(-1_i16), 0);
+    assert_eq!(Bits::leading_ones(i8::MIN), 1);
+    assert_eq!(Bits::trailing_zeros(i8::MIN), 7);
+    assert_eq!(Bits::trailing_zeros(0_u32), 32);
+    assert_eq!(Bits::rotate_left(i8::MIN, 1), 1);
+    assert_eq!(Bits::reverse_bits(1_i8), i8::MIN);
+    assert_eq!(<i128 as Bits>::BITS, 128);
+}
@@ -0,0 +1,120 @@
+use newnum::{num, AHyper, ATrig, Arg, Complex, Exp, Fixed, Hyper, Log, Norm, Ratio, Root, Trig};
+
+type C = Complex<f64>;
+
+fn assert_close(actual: C, expected: C, context: &str) {
+    assert!(
+        (actual - expected).norm() <= 1e-9 * (1.0 + expected.norm()),
+        "{context}: {actual} != {expected}"
+    );
+}
+
+/// A grid of values in every quadrant, including the axes.
+fn grid() -> impl Iterator<Item = C> {
+    let parts = [-2.5, -1.0, -0.3, 0.0, 0.7, 1.0, 3.2];
+
+    parts
+        .into_iter()
+        .flat_map(move |re| parts.into_iter().map(move |im| C::new(re, im)))
+}
+
+#[test]
+fn inverse_functions_round_trip() {
+    for z in grid() {
+        assert_close(z.sqrt() * z.sqrt(), z, &format!("sqrt({z})^2"));
+        assert_close(z.cbrt() * z.cbrt() * z.cbrt(), z, &format!("cbrt({z})^3"));
+        assert_close(
+            z.exp().ln(),
+            C::new(z.re, z.im.sin().atan2(z.im.cos())),
+            &format!("ln(exp({z}))"),
+        );
+        if z != C::new(-1.0, 0.0) {
+            assert_close(
+                z.ln_1p(),
+                (z + C::new(1.0, 0.0)).ln(),
+                &format!("ln_1p({z})"),
+            );
+        }
+        assert_close(
+            z.exp_m1(),
+            z.exp() - C::new(1.0, 0.0),
+            &format!("exp_m1({z})"),
+        );
+
+        assert_close(z.asin().sin(), z, &format!("sin(asin({z}))"));
+        assert_close(z.acos().cos(), z, &format!("cos(acos({z}))"));
+        assert_close(z.asinh().sinh(), z, &format!("sinh(asinh({z}))"));
+        assert_close(z.acosh().cosh(), z, &format!("cosh(acosh({z}))"));
+
+        if z != C::I && z != -C::I {
+            assert_close(z.atan().tan(), z, &format!("tan(atan({z}))"));
+        }
+        if z != C::new(1.0, 0.0) && z != C::new(-1.0, 0.0) {
+            assert_close(z.atanh().tanh(), z, &format!("tanh(atanh({z}))"));
+        }
+    }
+}
+
+#[test]
+fn functions_match_real_functions_on_the_real_axis() {
+    for re in [-0.9, -0.2, 0.0, 0.5, 0.99] {
+        let z = C::new(re, 0.0);
+
+        assert_close(z.sin(), C::new(re.sin(), 0.0), "sin");
+        assert_close(z.tan(), C::new(re.tan(), 0.0), "tan");
+        assert_close(z.asin(), C::new(re.asin(), 0.0), "asin");
+        assert_close(z.acos(), C::new(re.acos(), 0.0), "acos");
+        assert_close(z.atan(), C::new(re.atan(), 0.0), "atan");
+        assert_close(z.tanh(), C::new(re.tanh(), 0.0), "tanh");
+        assert_close(z.atanh(), C::new(re.atanh(), 0.0), "atanh");
+        assert_eq!(z.arg(), 0.0_f64.atan2(re), "arg({re})");
+    }
+}
+
+#[test]
+fn principal_branches() {
+    assert_eq!(C::new(-4.0, 0.0).sqrt(), C::new(0.0, 2.0));
+    assert_eq!(C::new(-4.0, -0.0).sqrt(), C::new(0.0, -2.0));
+    assert_eq!(C::new(-1.0, 0.0).arg(), std::f64::consts::PI);
+    assert_eq!(C::new(-1.0, -0.0).arg(), -std::f64::consts::PI);
+    assert_close(
+        C::new(-8.0, 0.0).cbrt(),
+        C::new(1.0, 3_f64.sqrt()),
+        "cbrt(-8)",
+    );
+    assert_close(
+        C::new(-1.0, 0.0).ln(),
+        C::new(0.0, std::f64::consts::PI),
+        "ln(-1)",
+    );
+    assert_close(
+        C::new(2.0, 0.0).asin(),
+        C::new(std::f64::consts::FRAC_PI_2, 1.3169578969248166),
+        "asin(2)",
+    );
+    assert_eq!(num!(2.5: C), C::new(2.5, 0.0));
+}
+
+#[test]
+fn display() {
+    assert_eq!(C::new(1.5, 2.0).to_string(), "1.5+2i");
+    assert_eq!(C::new(-1.0, -0.5).to_string(), "-1-0.5i");
+    assert_eq!(C::new(0.0, -0.0).to_string(), "0-0i");
+    assert_eq!(C::new(1.0, f64::NAN).to_string(), "1+NaNi");
+    assert_eq!(Complex::new(3_i8, i8::MIN).to_string(), "3-128i");
+
+    // `Ratio` and `Fixed` don't support the `+` flag.
+    assert_eq!(
+        Complex::new(Ratio::new(1, 2), Ratio::new(3, 4)).to_string(),
+        "1/2+3/4i"
+    );
+    assert_eq!(
+        Complex::new(Ratio::new(1, 2), Ratio::new(-3, 4)).to_string(),
+        "1/2-3/4i"
+    );
+
+    let value: Complex<Fixed<i32, 16>> = Complex::new(num!(1.5), num!(1.5));
+    assert_eq!(value.to_string(), "1.5+1.5i");
+    let value: Complex<Fixed<i32, 16>> = Complex::new(num!(1.5), num!(-1.5));
+    assert_eq!(value.to_string(), "1.5-1.5i");
+}
@@ -0,0 +1,111 @@
+use newnum::*;
+
+fn err(sign: Sign, kind: NumConvertErrorKind) -> NumConvertError {
+    NumConvertError { sign, kind }
+}
+
+#[test]
+fn float_to_int_bounds() {
+    // `2^63` is the first `f64` above `i64::MAX`.
+    assert_eq!(
+        i64::try_from_num(9223372036854775808.0_f64),
+        Err(err(Sign::Positive, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(
+        i64::try_from_num(9223372036854774784.0_f64),
+        Ok(9223372036854774784)
+    );
+    assert_eq!(i64::try_from_num(-9223372036854775808.0_f64), Ok(i64::MIN));
+    assert_eq!(
+        u64::try_from_num(18446744073709551616.0_f64),
+        Err(err(Sign::Positive, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(i8::try_from_num(-128.0_f32), Ok(-128));
+    assert_eq!(
+        i8::try_from_num(-129.0_f32),
+        Err(err(Sign::Negative, NumConvertErrorKind::Overflow))
+    );
+}
+
+#[test]
+fn negative_float_to_unsigned() {
+    assert_eq!(
+        u8::try_from_num(-0.5_f64),
+        Err(err(Sign::Negative, NumConvertErrorKind::Fraction))
+    );
+    assert_eq!(
+        u8::try_from_num(-1.0_f64),
+        Err(err(Sign::Negative, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(
+        u8::try_from_num(-1.5_f32),
+        Err(err(Sign::Negative, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(u8::try_from_num(-0.0_f64), Ok(0));
+}
+
+#[test]
+fn nan_sign() {
+    assert_eq!(
+        i32::try_from_num(f64::NAN),
+        Err(err(Sign::Positive, NumConvertErrorKind::NaN))
+    );
+    assert_eq!(
+        i32::try_from_num(-f64::NAN),
+        Err(err(Sign::Negative, NumConvertErrorKind::NaN))
+    );
+    assert_eq!(
+        u8::try_from_num(-f32::NAN),
+        Err(err(Sign::Negative, NumConvertErrorKind::NaN))
+    );
+    assert!(f32::try_from_num(f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+fn int_to_float_overflow() {
+    assert_eq!(
+        f32::try_from_num(u128::MAX),
+        Err(err(Sign::Positive, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(f32::try_from_num(i128::MIN), Ok(-(2.0_f32.powi(127))));
+    assert_eq!(f64::try_from_num(u128::MAX), Ok(2.0_f64.powi(128)));
+    assert_eq!(
+        f32::try_from_num(1e300_f64),
+        Err(err(Sign::Positive, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(
+        f32::try_from_num(-1e300_f64),
+        Err(err(Sign::Negative, NumConvertErrorKind::Overflow))
+    );
+    assert_eq!(f32::try_from_num(f64::NEG_INFINITY), Ok(f32::NEG_INFINITY));
+}
+
+#[test]
+fn saturating_from_num_bounds() {
+    assert_eq!(i32::saturating_from_num(1e300_f64), i32::MAX);
+    assert_eq!(i32::saturating_from_num(-1e300_f64), i32::MIN);
+    assert_eq!(i32::saturating_from_num(f64::NAN), 0);
+    assert_eq!(u8::saturating_from_num(-2.7_f32), 0);
+    assert_eq!(u8::saturating_from_num(2.7_f32), 2);
+    assert_eq!(u8::saturating_from_num(-1_i32), 0);
+    assert_eq!(u8::saturating_from_num(300_i32), u8::MAX);
+    assert_eq!(i128::saturating_from_num(u128::MAX), i128::MAX);
+    assert_eq!(u64::saturating_from_num(i128::MIN), 0);
+    assert_eq!(i8::saturating_from_num(i128::MIN), i8::MIN);
+
+    assert_eq!(f32::saturating_from_num(u128::MAX), f32::MAX);
+    assert_eq!(f32::saturating_from_num(-1e300_f64), f32::MIN);
+    assert_eq!(f32::saturating_from_num(f64::INFINITY), f32::INFINITY);
+}
+
+#[test]
+fn as_prim_conversions() {
+    assert_eq!(300_i32.checked_as::<u8>(), None);
+    assert_eq!(200_i32.checked_as::<u8>(), Some(200));
+    assert_eq!((-5_i64).saturating_as::<u32>(), 0);
+    assert_eq!(7_u8.lossless_as::<f32>(), 7.0);
+    assert_eq!(
+        (-1_i8).try_as::<u128>(),
+        Err(err(Sign::Negative, NumConvertErrorKind::Overflow))
+    );
+}
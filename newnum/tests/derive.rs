@@ -0,0 +1,150 @@
+use std::ops::*;
+
+use newnum::{derive::*, *};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(
+    CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem, CheckedNeg, CheckedShl, Conj, Norm,
+    Arg
+)]
+#[derive_bound(CheckedAdd; T: CheckedAdd)]
+#[derive_bound(CheckedSub; T: CheckedSub)]
+#[derive_bound(CheckedMul; T: CheckedMul)]
+#[derive_bound(CheckedDiv; T: CheckedDiv)]
+#[derive_bound(CheckedRem; T: CheckedRem)]
+#[derive_bound(CheckedNeg; T: CheckedNeg)]
+#[derive_bound(CheckedShl; T: CheckedShl)]
+#[derive_bound(Conj; T: Conj)]
+#[derive_bound(Norm; T: Norm<Output = T>)]
+#[derive_bound(Arg; T: Arg<Output = T>)]
+struct Vec2<T> {
+    x: T,
+    y: T,
+}
+
+macro_rules! op_impl {
+    ($trait:ident($fn:ident)) => {
+        impl<T: $trait<Output = T>> $trait for Vec2<T> {
+            type Output = Self;
+
+            fn $fn(self, rhs: Self) -> Self {
+                Self {
+                    x: $trait::$fn(self.x, rhs.x),
+                    y: $trait::$fn(self.y, rhs.y),
+                }
+            }
+        }
+    };
+}
+op_impl!(Add(add));
+op_impl!(Sub(sub));
+op_impl!(Mul(mul));
+op_impl!(Div(div));
+op_impl!(Rem(rem));
+
+#[derive(Debug, Clone, Copy, PartialEq, ToBytes, FromBytes)]
+struct Pixel {
+    value: u16,
+    alpha: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ToBytes, FromBytes)]
+#[derive_bound(ToBytes; T: ToBytes)]
+#[derive_bound(FromBytes; T: FromBytes)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+fn vec2<T>(x: T, y: T) -> Vec2<T> {
+    Vec2 { x, y }
+}
+
+#[test]
+fn checked_derives_map_each_field() {
+    assert_eq!(vec2(1_u8, 2).checked_add(vec2(3, 4)), Some(vec2(4, 6)));
+    assert_eq!(vec2(5_u8, 6).checked_sub(vec2(1, 2)), Some(vec2(4, 4)));
+    assert_eq!(vec2(5_u8, 6).checked_mul(vec2(2, 3)), Some(vec2(10, 18)));
+    assert_eq!(vec2(9_u8, 8).checked_div(vec2(2, 3)), Some(vec2(4, 2)));
+    assert_eq!(vec2(9_u8, 8).checked_rem(vec2(2, 3)), Some(vec2(1, 2)));
+    assert_eq!(vec2(1_i8, -2).checked_neg(), Some(vec2(-1, 2)));
+    assert_eq!(vec2(1_u8, 3).checked_shl(2), Some(vec2(4, 12)));
+}
+
+#[test]
+fn checked_derives_fail_if_any_field_fails() {
+    assert_eq!(vec2(1_u8, 200).checked_add(vec2(1, 100)), None);
+    assert_eq!(vec2(1_u8, 2).checked_sub(vec2(2, 1)), None);
+    assert_eq!(vec2(2_u8, 16).checked_mul(vec2(2, 16)), None);
+    assert_eq!(vec2(1_u8, 2).checked_div(vec2(1, 0)), None);
+    assert_eq!(vec2(1_u8, 2).checked_rem(vec2(0, 1)), None);
+    assert_eq!(vec2(i8::MIN, 1).checked_neg(), None);
+    assert_eq!(vec2(1_u8, 1).checked_shl(8), None);
+
+    assert_eq!(
+        vec2(1.0_f32, f32::MAX).checked_add(vec2(1.0, f32::MAX)),
+        None
+    );
+    assert_eq!(vec2(1.0_f32, 2.0).checked_div(vec2(2.0, 0.0)), None);
+}
+
+#[test]
+fn complex_derives_map_each_field() {
+    let complex = vec2(Complex::new(1.0_f64, 2.0), Complex::new(-3.0, -4.0));
+    assert_eq!(
+        complex.conj(),
+        vec2(Complex::new(1.0, -2.0), Complex::new(-3.0, 4.0))
+    );
+
+    assert_eq!(vec2(3.0_f64, -4.0).norm(), vec2(3.0, 4.0));
+    assert_eq!(vec2(3.0_f64, -4.0).norm_sqr(), vec2(9.0, 16.0));
+    assert_eq!(vec2(3.0_f64, -4.0).arg(), vec2(0.0, std::f64::consts::PI));
+}
+
+#[test]
+fn bytes_derive_round_trip() {
+    let pixel = Pixel {
+        value: 0x0102,
+        alpha: 3,
+    };
+
+    assert_eq!(pixel.to_le_bytes(), [2, 1, 3]);
+    assert_eq!(pixel.to_be_bytes(), [1, 2, 3]);
+    assert_eq!(Pixel::from_le_bytes(pixel.to_le_bytes()), pixel);
+    assert_eq!(Pixel::from_be_bytes(pixel.to_be_bytes()), pixel);
+    assert_eq!(Pixel::from_ne_bytes(pixel.to_ne_bytes()), pixel);
+}
+
+#[test]
+fn generic_bytes_derive_round_trip() {
+    let pair = Pair {
+        first: 0x0102_u16,
+        second: 0x0304,
+    };
+
+    assert_eq!(pair.to_le_bytes().as_ref(), [2, 1, 4, 3]);
+    assert_eq!(pair.to_be_bytes().as_ref(), [1, 2, 3, 4]);
+    assert_eq!(Pair::from_le_bytes(pair.to_le_bytes()), pair);
+    assert_eq!(Pair::from_be_bytes(pair.to_be_bytes()), pair);
+    assert_eq!(Pair::from_ne_bytes(pair.to_ne_bytes()), pair);
+
+    let bytes = <Pair<u16> as ToBytes>::Bytes::try_from([1, 2, 3, 4].as_slice()).unwrap();
+    assert_eq!(
+        Pair::<u16>::from_be_bytes(bytes),
+        Pair {
+            first: 0x0102,
+            second: 0x0304,
+        }
+    );
+    assert!(<Pair<u16> as ToBytes>::Bytes::try_from([1, 2, 3].as_slice()).is_err());
+
+    let nested = Pair {
+        first: pair,
+        second: Pair {
+            first: 5,
+            second: 6,
+        },
+    };
+    assert_eq!(nested.to_be_bytes().as_ref(), [1, 2, 3, 4, 0, 5, 0, 6]);
+    assert_eq!(Pair::from_be_bytes(nested.to_be_bytes()), nested);
+}
@@ -0,0 +1,106 @@
+use newnum::*;
+
+#[test]
+fn exhaustive_i8() {
+    for lhs in i8::MIN..=i8::MAX {
+        for rhs in i8::MIN..=i8::MAX {
+            if rhs == 0 || (lhs == i8::MIN && rhs == -1) {
+                continue;
+            }
+
+            let exact = lhs as f64 / rhs as f64;
+
+            assert_eq!(
+                DivFloor::div_floor(lhs, rhs) as f64,
+                exact.floor(),
+                "{lhs} / {rhs}"
+            );
+            assert_eq!(
+                DivCeil::div_ceil(lhs, rhs) as f64,
+                exact.ceil(),
+                "{lhs} / {rhs}"
+            );
+
+            let quotient = DivEuclid::div_euclid(lhs, rhs);
+            let remainder = RemEuclid::rem_euclid(lhs, rhs);
+            assert!(
+                (0..rhs.unsigned_abs() as i32).contains(&(remainder as i32)),
+                "{lhs} % {rhs}"
+            );
+            assert_eq!(
+                quotient as i32 * rhs as i32 + remainder as i32,
+                lhs as i32,
+                "{lhs} / {rhs}"
+            );
+
+            assert_eq!(DivRem::div_rem(lhs, rhs), (lhs / rhs, lhs % rhs));
+        }
+    }
+}
+
+#[test]
+fn exhaustive_u8() {
+    for lhs in u8::MIN..=u8::MAX {
+        for rhs in 1..=u8::MAX {
+            let exact = lhs as f64 / rhs as f64;
+
+            assert_eq!(DivFloor::div_floor(lhs, rhs) as f64, exact.floor());
+            assert_eq!(DivCeil::div_ceil(lhs, rhs) as f64, exact.ceil());
+            assert_eq!(DivEuclid::div_euclid(lhs, rhs), lhs / rhs);
+            assert_eq!(RemEuclid::rem_euclid(lhs, rhs), lhs % rhs);
+        }
+    }
+}
+
+#[test]
+fn signed_negative_divisors() {
+    assert_eq!(DivFloor::div_floor(7_i32, -2), -4);
+    assert_eq!(DivFloor::div_floor(-7_i32, -2), 3);
+    assert_eq!(DivFloor::div_floor(-8_i32, -2), 4);
+    assert_eq!(DivCeil::div_ceil(7_i32, -2), -3);
+    assert_eq!(DivCeil::div_ceil(-7_i32, -2), 4);
+    assert_eq!(DivEuclid::div_euclid(7_i32, -2), -3);
+    assert_eq!(DivEuclid::div_euclid(-7_i32, -2), 4);
+    assert_eq!(RemEuclid::rem_euclid(7_i32, -2), 1);
+    assert_eq!(RemEuclid::rem_euclid(-7_i32, -2), 1);
+    assert_eq!(DivRem::div_rem(-7_i32, -2), (3, -1));
+
+    assert_eq!(DivFloor::div_floor(i64::MIN, i64::MAX), -2);
+    assert_eq!(DivCeil::div_ceil(i64::MIN, i64::MAX), -1);
+    assert_eq!(DivFloor::div_floor(i64::MAX, i64::MIN), -1);
+    assert_eq!(RemEuclid::rem_euclid(i64::MIN, i64::MAX), i64::MAX - 1);
+}
+
+#[test]
+#[should_panic]
+fn div_floor_min_by_minus_one_panics() {
+    DivFloor::div_floor(std::hint::black_box(i32::MIN), -1);
+}
+
+#[test]
+#[should_panic]
+fn div_ceil_by_zero_panics() {
+    DivCeil::div_ceil(std::hint::black_box(1_i32), 0);
+}
+
+#[test]
+#[should_panic]
+fn rem_euclid_by_zero_panics() {
+    RemEuclid::rem_euclid(std::hint::black_box(1_u32), 0);
+}
+
+#[test]
+fn float_negative_divisors() {
+    assert_eq!(DivFloor::div_floor(7.0_f64, -2.0), -4.0);
+    assert_eq!(DivFloor::div_floor(-7.0_f64, -2.0), 3.0);
+    assert_eq!(DivCeil::div_ceil(7.0_f64, -2.0), -3.0);
+    assert_eq!(DivCeil::div_ceil(-7.0_f64, -2.0), 4.0);
+    assert_eq!(DivEuclid::div_euclid(7.0_f64, -2.0), -3.0);
+    assert_eq!(DivEuclid::div_euclid(-7.0_f64, -2.0), 4.0);
+    assert_eq!(RemEuclid::rem_euclid(-7.0_f64, -2.0), 1.0);
+    assert_eq!(RemEuclid::rem_euclid(-0.5_f32, 4.0), 3.5);
+    assert_eq!(DivRem::div_rem(-7.5_f64, 2.0), (-3.0, -1.5));
+
+    assert_eq!(DivFloor::div_floor(1.0_f64, 0.0), f64::INFINITY);
+    assert!(RemEuclid::rem_euclid(1.0_f64, 0.0).is_nan());
+}
@@ -0,0 +1,142 @@
+use newnum::{
+    num, AHyper, ATrig, Dual, DualN, Exp, Float, FloatClass, FloatConsts, Hyper, Hypot, Log, Pow,
+    Root, Trig,
+};
+
+type D = Dual<f64>;
+
+/// Checks the derivative of `f` against a central finite difference at each of `points`.
+fn assert_derivative(name: &str, points: &[f64], f: impl Fn(D) -> D) {
+    const STEP: f64 = 1e-6;
+
+    for &x in points {
+        let output = f(D::variable(x, 0));
+        let finite_difference =
+            (f(D::constant(x + STEP)).value() - f(D::constant(x - STEP)).value()) / (2.0 * STEP);
+
+        assert!(
+            (output.derivative() - finite_difference).abs()
+                <= 1e-5 * (1.0 + finite_difference.abs()),
+            "{name}'({x}): {} != {finite_difference}",
+            output.derivative()
+        );
+    }
+}
+
+#[test]
+fn derivatives_match_finite_differences() {
+    let points = [-2.3, -0.7, 0.4, 1.1, 3.6];
+    let positive_points = [0.2, 0.9, 1.7, 5.0];
+    let unit_points = [-0.8, -0.1, 0.3, 0.9];
+
+    assert_derivative("sin", &points, |x| x.sin());
+    assert_derivative("cos", &points, |x| x.cos());
+    assert_derivative("tan", &points, |x| x.tan());
+    assert_derivative("asin", &unit_points, |x| x.asin());
+    assert_derivative("acos", &unit_points, |x| x.acos());
+    assert_derivative("atan", &points, |x| x.atan());
+    assert_derivative("sinh", &points, |x| x.sinh());
+    assert_derivative("cosh", &points, |x| x.cosh());
+    assert_derivative("tanh", &points, |x| x.tanh());
+    assert_derivative("asinh", &points, |x| x.asinh());
+    assert_derivative("acosh", &[1.2, 2.0, 7.5], |x| x.acosh());
+    assert_derivative("atanh", &unit_points, |x| x.atanh());
+    assert_derivative("sqrt", &positive_points, |x| x.sqrt());
+    assert_derivative("cbrt", &points, |x| x.cbrt());
+    assert_derivative("nth_root", &positive_points, |x| x.nth_root(5));
+    assert_derivative("rsqrt", &positive_points, |x| x.rsqrt());
+    assert_derivative("exp", &points, |x| x.exp());
+    assert_derivative("exp2", &points, |x| x.exp2());
+    assert_derivative("exp_m1", &points, |x| x.exp_m1());
+    assert_derivative("ln", &positive_points, |x| x.ln());
+    assert_derivative("log2", &positive_points, |x| x.log2());
+    assert_derivative("log10", &positive_points, |x| x.log10());
+    assert_derivative("ln_1p", &positive_points, |x| x.ln_1p());
+    assert_derivative("log", &positive_points, |x| D::constant(3.0).log(x));
+    assert_derivative("powi", &points, |x| Pow::<i32>::pow(x, -3));
+    assert_derivative("powu", &points, |x| Pow::<u32>::pow(x, 5));
+    assert_derivative("powf", &positive_points, |x| Pow::<D>::pow(x, x));
+    assert_derivative("hypot", &points, |x| x.hypot(D::constant(2.0) * x.sin()));
+    assert_derivative("quotient", &points, |x| {
+        x.sin() / (x * x + D::constant(1.0))
+    });
+    assert_derivative("remainder", &[0.8, 2.2, 3.1], |x| D::constant(7.0) % x);
+}
+
+#[test]
+fn gradient_of_multiple_variables() {
+    let x = DualN::<f64, 3>::variable(1.5, 0);
+    let y = DualN::<f64, 3>::variable(-0.5, 1);
+    let z = DualN::<f64, 3>::variable(2.0, 2);
+
+    // f(x, y, z) = x * y + z / x
+    let output = x * y + z / x;
+
+    assert_eq!(output.value(), 1.5 * -0.5 + 2.0 / 1.5);
+    assert_eq!(
+        output.gradient(),
+        [-0.5 - 2.0 / (1.5 * 1.5), 1.5, 1.0 / 1.5]
+    );
+}
+
+#[test]
+fn pow_u32_beyond_i32() {
+    let output = Pow::<u32>::pow(D::variable(-1.0, 0), 1 << 31);
+    assert_eq!(output.value(), 1.0);
+    assert_eq!(output.derivative(), -2147483648.0);
+
+    let output = Pow::<u32>::pow(D::variable(2.0, 0), 0);
+    assert_eq!(output.value(), 1.0);
+    assert_eq!(output.derivative(), 0.0);
+}
+
+#[test]
+fn pow_i32_min() {
+    let output = Pow::<i32>::pow(D::variable(-1.0, 0), i32::MIN);
+    assert_eq!(output.value(), 1.0);
+    assert_eq!(output.derivative(), 2147483648.0);
+
+    let output = Pow::<i32>::pow(D::variable(2.0, 0), i32::MIN);
+    assert_eq!(output.value(), 0.0);
+    assert_eq!(output.derivative(), 0.0);
+}
+
+/// A function written for primitive floats, with no `DualN` specific code.
+fn logistic<T: Float>(x: T) -> T {
+    T::ONE / (T::ONE + (-x).exp())
+}
+
+#[test]
+fn generic_float_code() {
+    let output = logistic(D::variable(0.5, 0));
+    let value = logistic(0.5);
+
+    assert_eq!(output.value(), value);
+    assert!((output.derivative() - value * (1.0 - value)).abs() < 1e-15);
+
+    let output = logistic(num!(0.5: D));
+    assert_eq!(output.derivative(), 0.0);
+
+    assert!(D::NAN.is_nan());
+    assert_eq!(D::default().value(), 0.0);
+}
+
+#[test]
+fn second_derivative() {
+    // f(x) = x^3, f'(x) = 3x^2, f''(x) = 6x
+    let x = Dual::<Dual<f64>>::new(Dual::variable(2.0, 0), [Dual::constant(1.0)]);
+    let output = x * x * x;
+
+    assert_eq!(output.value().value(), 8.0);
+    assert_eq!(output.derivative().value(), 12.0);
+    assert_eq!(output.derivative().derivative(), 12.0);
+}
+
+#[test]
+fn display() {
+    assert_eq!(D::variable(1.5, 0).to_string(), "1.5+1ε");
+    assert_eq!(
+        DualN::<f64, 2>::new(3.0, [2.0, -1.0]).to_string(),
+        "3+2ε0-1ε1"
+    );
+}
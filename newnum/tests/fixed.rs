@@ -0,0 +1,170 @@
+use newnum::{num, Fixed, FromFloatLiteral, Root, Round, Trig, WholeEquivalent};
+
+type Fx = Fixed<u32, 16>;
+type SFx = Fixed<i64, 32>;
+type IFx = Fixed<i32, 16>;
+
+#[test]
+fn sqrt_is_rounded_down() {
+    for bits in (0..u32::MAX).step_by(65_521).chain([u32::MAX]) {
+        let sqrt = Fx::from_bits(bits).sqrt().to_bits() as u64;
+        let scaled = (bits as u64) << 16;
+
+        assert!(sqrt * sqrt <= scaled, "sqrt({bits})");
+        assert!((sqrt + 1) * (sqrt + 1) > scaled, "sqrt({bits})");
+    }
+}
+
+#[test]
+fn roots_golden_bits() {
+    let sqrt = |bits: i32| Root::sqrt(IFx::from_bits(bits)).to_bits();
+    let cbrt = |bits: i32| Root::cbrt(IFx::from_bits(bits)).to_bits();
+
+    assert_eq!(sqrt(2 << 16), 92681);
+    assert_eq!(sqrt(6554), 20724);
+    assert_eq!(sqrt(1), 256);
+    assert_eq!(sqrt(i32::MAX), 11863283);
+
+    assert_eq!(cbrt(2 << 16), 82570);
+    assert_eq!(cbrt(-(2 << 16)), -82570);
+    assert_eq!(cbrt(-(8 << 16)), -(2 << 16));
+    assert_eq!(cbrt(6554), 30419);
+    assert_eq!(cbrt(1), 1625);
+    assert_eq!(cbrt(i32::MIN), -(32 << 16));
+    assert_eq!(Root::nth_root(IFx::from_bits(1), 5).to_bits(), 7131);
+
+    assert_eq!(Root::sqrt(SFx::from_bits(2 << 32)).to_bits(), 6074000999);
+    assert_eq!(Root::cbrt(SFx::from_bits(2 << 32)).to_bits(), 5411319704);
+    assert_eq!(Root::cbrt(SFx::from_bits(1)).to_bits(), 2642245);
+    assert_eq!(
+        Root::nth_root(SFx::from_bits(i64::MAX), 7).to_bits(),
+        92489556976
+    );
+}
+
+// Expected bits are the exact values rounded to nearest.
+#[test]
+fn trig_golden_bits() {
+    let trig = |bits: i32| {
+        let angle = IFx::from_bits(bits);
+        [angle.sin(), angle.cos(), angle.tan()].map(IFx::to_bits)
+    };
+
+    assert_eq!(trig(1 << 16), [55147, 35409, 102066]);
+    assert_eq!(trig(-(1 << 15)), [-31420, 57513, -35802]);
+    assert_eq!(trig(3 << 16), [9248, -64880, -9342]);
+    assert_eq!(trig(1), [1, 1 << 16, 1]);
+
+    let trig = |bits: i64| {
+        let angle = SFx::from_bits(bits);
+        [angle.sin(), angle.cos(), angle.tan()].map(SFx::to_bits)
+    };
+
+    assert_eq!(trig(1 << 32), [3614090360, 2320580734, 6689015244]);
+    assert_eq!(trig(-(3 << 31)), [-4284208345, 303813968, -60565137500]);
+    assert_eq!(trig(7 << 32), [2821735955, 3237985527, 3742840586]);
+}
+
+#[test]
+fn trig_matches_f64() {
+    let resolution = 2_f64.powi(-32);
+
+    for bits in (-(64_i64 << 32)..(64 << 32)).step_by(1_234_567_891) {
+        let angle = SFx::from_bits(bits);
+        let angle_f64 = bits as f64 * resolution;
+
+        let sin = angle.sin().to_bits() as f64 * resolution;
+        let cos = angle.cos().to_bits() as f64 * resolution;
+        assert!((sin - angle_f64.sin()).abs() < 1e-8, "sin({angle_f64})");
+        assert!((cos - angle_f64.cos()).abs() < 1e-8, "cos({angle_f64})");
+    }
+}
+
+#[test]
+fn float_literals_round_to_nearest() {
+    assert_eq!(num!(1.25: Fx).to_bits(), 0x0001_4000);
+    assert_eq!(num!(0.1: Fx).to_bits(), 6554);
+    assert_eq!(num!(-0.1: SFx).to_bits(), -429_496_730);
+    assert_eq!(num!(65535.99998: Fx).to_bits(), u32::MAX);
+    assert_eq!(num!(0.000001: Fx).to_bits(), 0);
+    assert_eq!(num!(0.5: Fixed<i8, 7>).to_bits(), 64);
+}
+
+#[test]
+fn float_literal_bounds_are_in_range() {
+    // `i64::MAX as f64` rounds up to `2^63`, which is out of range.
+    assert_eq!(SFx::MAX_FLOAT_LITERAL, 2147483647.9999998);
+    assert_eq!(SFx::MIN_FLOAT_LITERAL, -2147483648.0);
+    assert_eq!(Fx::MAX_FLOAT_LITERAL, 65535.99998474121);
+    assert_eq!(IFx::MIN_FLOAT_LITERAL, -32768.0);
+
+    assert_eq!(num!(2147483647.9999998: SFx).to_bits(), i64::MAX - 1023);
+}
+
+#[test]
+fn negative_rounding() {
+    let values: [IFx; 4] = [num!(-2.5), num!(-1.25), num!(-3.5), num!(-0.75)];
+    let results = |f: fn(IFx) -> IFx| values.map(f);
+
+    assert_eq!(
+        results(Round::round),
+        [num!(-3), num!(-1), num!(-4), num!(-1)]
+    );
+    assert_eq!(
+        results(Round::round_ties_even),
+        [num!(-2), num!(-1), num!(-4), num!(-1)]
+    );
+    assert_eq!(
+        results(Round::floor),
+        [num!(-3), num!(-2), num!(-4), num!(-1)]
+    );
+    assert_eq!(
+        results(Round::ceil),
+        [num!(-2), num!(-1), num!(-3), num!(0)]
+    );
+    assert_eq!(
+        results(Round::trunc),
+        [num!(-2), num!(-1), num!(-3), num!(0)]
+    );
+    assert_eq!(
+        results(Round::fract),
+        [num!(-0.5), num!(-0.25), num!(-0.5), num!(-0.75)]
+    );
+    assert_eq!(WholeEquivalent::iround(num!(-2.5: IFx)), -3);
+    assert_eq!(WholeEquivalent::ifloor(num!(-0.75: IFx)), -1);
+}
+
+#[test]
+fn mul_div_rounding() {
+    let ulp = IFx::from_bits(1);
+    let half: IFx = num!(0.5);
+
+    // Multiplication rounds down, also for negative results.
+    assert_eq!(ulp * half, IFx::from_bits(0));
+    assert_eq!(-ulp * half, IFx::from_bits(-1));
+    assert_eq!(IFx::from_bits(3) * half, IFx::from_bits(1));
+    assert_eq!(IFx::from_bits(-3) * half, IFx::from_bits(-2));
+
+    // Division rounds towards zero.
+    let three: IFx = num!(3);
+    assert_eq!(IFx::from_bits(1) / three, IFx::from_bits(0));
+    assert_eq!(IFx::from_bits(-1) / three, IFx::from_bits(0));
+    assert_eq!(num!(1: IFx) / three, IFx::from_bits(21845));
+    assert_eq!(num!(-1: IFx) / three, IFx::from_bits(-21845));
+    assert_eq!(num!(-7.5: IFx) / num!(2.5), num!(-3));
+}
+
+#[test]
+fn display() {
+    assert_eq!(num!(-2.5: IFx).to_string(), "-2.5");
+    assert_eq!(num!(0: IFx).to_string(), "0");
+    assert_eq!(num!(-0.75: IFx).to_string(), "-0.75");
+    assert_eq!(IFx::from_bits(1).to_string(), "0.0000152587890625");
+    assert_eq!(IFx::from_bits(-1).to_string(), "-0.0000152587890625");
+    assert_eq!(IFx::from_bits(i32::MIN).to_string(), "-32768");
+    assert_eq!(
+        Fx::from_bits(u32::MAX).to_string(),
+        "65535.9999847412109375"
+    );
+    assert_eq!(format!("{:?}", num!(1.25: IFx)), "1.25");
+}
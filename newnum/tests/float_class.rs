@@ -0,0 +1,191 @@
+use std::num::FpCategory;
+
+use newnum::{derive::*, *};
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatClass, FloatConsts)]
+#[derive_bound(FloatClass; T: FloatClass<FloatBoolMapped = bool, CategoryMapped = FpCategory>)]
+#[derive_bound(FloatConsts; T: FloatConsts)]
+struct Meters<T>(T);
+
+#[derive(Debug, Clone, Copy, PartialEq, FloatConsts)]
+struct Pair {
+    first: f32,
+    second: f64,
+}
+
+/// `(is_nan, is_finite, is_infinite, is_normal, is_subnormal, is_sign_positive, is_sign_negative, classify)`
+type Class = (bool, bool, bool, bool, bool, bool, bool, FpCategory);
+
+fn class<T: FloatClass<FloatBoolMapped = bool, CategoryMapped = FpCategory>>(value: T) -> Class {
+    (
+        value.is_nan(),
+        value.is_finite(),
+        value.is_infinite(),
+        value.is_normal(),
+        value.is_subnormal(),
+        value.is_sign_positive(),
+        value.is_sign_negative(),
+        value.classify(),
+    )
+}
+
+const NAN: Class = (
+    true,
+    false,
+    false,
+    false,
+    false,
+    true,
+    false,
+    FpCategory::Nan,
+);
+const NEG_NAN: Class = (
+    true,
+    false,
+    false,
+    false,
+    false,
+    false,
+    true,
+    FpCategory::Nan,
+);
+const INFINITY: Class = (
+    false,
+    false,
+    true,
+    false,
+    false,
+    true,
+    false,
+    FpCategory::Infinite,
+);
+const NEG_INFINITY: Class = (
+    false,
+    false,
+    true,
+    false,
+    false,
+    false,
+    true,
+    FpCategory::Infinite,
+);
+const SUBNORMAL: Class = (
+    false,
+    true,
+    false,
+    false,
+    true,
+    true,
+    false,
+    FpCategory::Subnormal,
+);
+const NEG_SUBNORMAL: Class = (
+    false,
+    true,
+    false,
+    false,
+    true,
+    false,
+    true,
+    FpCategory::Subnormal,
+);
+const ZERO: Class = (
+    false,
+    true,
+    false,
+    false,
+    false,
+    true,
+    false,
+    FpCategory::Zero,
+);
+const NEG_ZERO: Class = (
+    false,
+    true,
+    false,
+    false,
+    false,
+    false,
+    true,
+    FpCategory::Zero,
+);
+const NORMAL: Class = (
+    false,
+    true,
+    false,
+    true,
+    false,
+    true,
+    false,
+    FpCategory::Normal,
+);
+const NEG_NORMAL: Class = (
+    false,
+    true,
+    false,
+    true,
+    false,
+    false,
+    true,
+    FpCategory::Normal,
+);
+
+#[test]
+fn float_classification() {
+    assert_eq!(class(f32::NAN.copysign(1.0)), NAN);
+    assert_eq!(class(f64::NAN.copysign(-1.0)), NEG_NAN);
+    assert_eq!(class(f32::INFINITY), INFINITY);
+    assert_eq!(class(f64::NEG_INFINITY), NEG_INFINITY);
+    assert_eq!(class(f32::MIN_POSITIVE / 2.0), SUBNORMAL);
+    assert_eq!(class(-f64::from_bits(1)), NEG_SUBNORMAL);
+    assert_eq!(class(0.0_f32), ZERO);
+    assert_eq!(class(-0.0_f64), NEG_ZERO);
+    assert_eq!(class(f64::MIN_POSITIVE), NORMAL);
+    assert_eq!(class(f32::MIN), NEG_NORMAL);
+}
+
+#[test]
+fn float_consts() {
+    assert!(<f32 as FloatConsts>::NAN.is_nan());
+    assert_eq!(<f64 as FloatConsts>::INFINITY, f64::INFINITY);
+    assert_eq!(<f32 as FloatConsts>::NEG_INFINITY, f32::NEG_INFINITY);
+    assert_eq!(<f64 as FloatConsts>::EPSILON, f64::EPSILON);
+    assert_eq!(<f32 as FloatConsts>::MIN_POSITIVE, f32::MIN_POSITIVE);
+    assert_eq!(<f64 as FloatConsts>::MAX, f64::MAX);
+    assert_eq!(<f32 as FloatConsts>::MIN, f32::MIN);
+}
+
+#[test]
+fn float_class_derive_forwards_to_the_field() {
+    assert_eq!(class(Meters(f32::NAN.copysign(1.0))), NAN);
+    assert_eq!(class(Meters(f64::NEG_INFINITY)), NEG_INFINITY);
+    assert_eq!(class(Meters(-f64::from_bits(1))), NEG_SUBNORMAL);
+    assert_eq!(class(Meters(-0.0_f32)), NEG_ZERO);
+    assert_eq!(class(Meters(1.5_f64)), NORMAL);
+}
+
+#[test]
+fn float_consts_derive_initializes_each_field() {
+    assert!(<Meters<f64> as FloatConsts>::NAN.0.is_nan());
+    assert_eq!(
+        <Meters<f32> as FloatConsts>::INFINITY,
+        Meters(f32::INFINITY)
+    );
+    assert_eq!(<Meters<f64> as FloatConsts>::EPSILON, Meters(f64::EPSILON));
+    assert_eq!(<Meters<f32> as FloatConsts>::MIN, Meters(f32::MIN));
+
+    assert_eq!(
+        <Pair as FloatConsts>::MAX,
+        Pair {
+            first: f32::MAX,
+            second: f64::MAX
+        }
+    );
+    assert_eq!(
+        <Pair as FloatConsts>::MIN_POSITIVE,
+        Pair {
+            first: f32::MIN_POSITIVE,
+            second: f64::MIN_POSITIVE
+        }
+    );
+}
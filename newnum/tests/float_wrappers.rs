@@ -0,0 +1,125 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    hash::{BuildHasher, RandomState},
+};
+
+use newnum::*;
+
+#[test]
+fn nan_rejection() {
+    assert_eq!(NotNan::new(f32::NAN), Err(InvalidFloatError::NaN));
+    assert_eq!(NotNan::new(-f64::NAN), Err(InvalidFloatError::NaN));
+    assert_eq!(
+        NotNan::new(f64::INFINITY).map(NotNan::get),
+        Ok(f64::INFINITY)
+    );
+
+    assert_eq!(Finite::new(f32::NAN), Err(InvalidFloatError::NaN));
+    assert_eq!(Finite::new(f32::INFINITY), Err(InvalidFloatError::Infinite));
+    assert_eq!(
+        Finite::new(f64::NEG_INFINITY),
+        Err(InvalidFloatError::Infinite)
+    );
+    assert_eq!(Finite::new(f64::MAX).map(Finite::get), Ok(f64::MAX));
+}
+
+#[test]
+#[should_panic]
+fn not_nan_operation_producing_nan_panics() {
+    let infinity = NotNan::new(f64::INFINITY).unwrap();
+    let _ = infinity - infinity;
+}
+
+#[test]
+#[should_panic]
+fn not_nan_sqrt_of_negative_panics() {
+    Root::sqrt(NotNan::new(-1.0_f32).unwrap());
+}
+
+#[test]
+#[should_panic]
+fn finite_overflow_panics() {
+    let max = Finite::new(f64::MAX).unwrap();
+    let _ = max * Finite::new(2.0).unwrap();
+}
+
+#[test]
+fn zeros_are_equal_and_hash_equally() {
+    let state = RandomState::new();
+
+    let zero = NotNan::new(0.0_f64).unwrap();
+    let neg_zero = NotNan::new(-0.0_f64).unwrap();
+    assert_eq!(zero, neg_zero);
+    assert_eq!(state.hash_one(zero), state.hash_one(neg_zero));
+
+    let zero = Finite::new(0.0_f32).unwrap();
+    let neg_zero = Finite::new(-0.0_f32).unwrap();
+    assert_eq!(zero, neg_zero);
+    assert_eq!(state.hash_one(zero), state.hash_one(neg_zero));
+
+    assert_eq!(OrderedFloat(0.0_f64), OrderedFloat(-0.0));
+    assert_eq!(
+        state.hash_one(OrderedFloat(0.0_f64)),
+        state.hash_one(OrderedFloat(-0.0_f64))
+    );
+
+    let set = [0.0, -0.0, 1.0]
+        .map(|value| NotNan::new(value).unwrap())
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn ordered_float_total_order() {
+    let nan = OrderedFloat::new(f64::NAN);
+    let neg_nan = OrderedFloat::new(-f64::NAN);
+    let infinity = OrderedFloat::new(f64::INFINITY);
+
+    assert_eq!(nan, neg_nan);
+    assert!(nan > infinity);
+    assert!(neg_nan > infinity);
+    assert!(OrderedFloat(f64::NEG_INFINITY) < OrderedFloat(f64::MIN));
+    assert_eq!(Ord::max(nan, infinity), nan);
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(nan), state.hash_one(neg_nan));
+
+    let mut values = [3.0, f64::NAN, -1.0, f64::NEG_INFINITY, -f64::NAN, 0.0].map(OrderedFloat);
+    values.sort();
+
+    assert_eq!(
+        values[..4],
+        [f64::NEG_INFINITY, -1.0, 0.0, 3.0].map(OrderedFloat)
+    );
+    assert!(values[4].get().is_nan());
+    assert!(values[5].get().is_nan());
+
+    // Operations are never rejected.
+    assert!((OrderedFloat(0.0_f32) / OrderedFloat(0.0)).get().is_nan());
+}
+
+#[test]
+fn btree_map_keyed_by_not_nan() {
+    let mut map = BTreeMap::new();
+    for (key, value) in [(2.5, "b"), (-1.0, "a"), (10.0, "c"), (-0.0, "zero")] {
+        map.insert(NotNan::new(key).unwrap(), value);
+    }
+
+    // `0.0` replaces the entry of the equal `-0.0` key.
+    map.insert(NotNan::new(0.0).unwrap(), "zero2");
+
+    assert_eq!(
+        map.iter()
+            .map(|(key, value)| (key.get(), *value))
+            .collect::<Vec<_>>(),
+        [(-1.0, "a"), (0.0, "zero2"), (2.5, "b"), (10.0, "c")]
+    );
+    assert_eq!(map.get(&NotNan::new(-0.0).unwrap()), Some(&"zero2"));
+    assert_eq!(
+        map.range(NotNan::new(0.0).unwrap()..)
+            .next()
+            .map(|(key, _)| key.get()),
+        Some(-0.0)
+    );
+}
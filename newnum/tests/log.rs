@@ -0,0 +1,61 @@
+use newnum::*;
+
+#[test]
+fn ilog() {
+    assert_eq!(ILog::ilog2(1_u8), 0);
+    assert_eq!(ILog::ilog2(255_u8), 7);
+    assert_eq!(ILog::ilog10(999_u16), 2);
+    assert_eq!(ILog::ilog10(1000_u16), 3);
+    assert_eq!(ILog::ilog(80_i32, 3), 3);
+    assert_eq!(ILog::ilog(81_i32, 3), 4);
+    assert_eq!(ILog::ilog2(u128::MAX), 127);
+    assert_eq!(ILog::ilog2(i128::MAX), 126);
+}
+
+#[test]
+fn checked_ilog() {
+    assert_eq!(ILog::checked_ilog2(0_u32), None);
+    assert_eq!(ILog::checked_ilog10(0_u32), None);
+    assert_eq!(ILog::checked_ilog(0_u32, 2), None);
+    assert_eq!(ILog::checked_ilog2(-1_i32), None);
+    assert_eq!(ILog::checked_ilog10(i64::MIN), None);
+    assert_eq!(ILog::checked_ilog(10_u32, 1), None);
+    assert_eq!(ILog::checked_ilog(10_u32, 0), None);
+    assert_eq!(ILog::checked_ilog(10_i32, -2), None);
+    assert_eq!(ILog::checked_ilog(1_u32, 2), Some(0));
+}
+
+#[test]
+#[should_panic]
+fn ilog2_of_zero_panics() {
+    ILog::ilog2(std::hint::black_box(0_u32));
+}
+
+#[test]
+#[should_panic]
+fn ilog10_of_zero_panics() {
+    ILog::ilog10(std::hint::black_box(0_u64));
+}
+
+#[test]
+#[should_panic]
+fn ilog_of_negative_panics() {
+    ILog::ilog(std::hint::black_box(-8_i32), 2);
+}
+
+#[test]
+#[should_panic]
+fn ilog_base_one_panics() {
+    ILog::ilog(std::hint::black_box(8_u8), 1);
+}
+
+#[test]
+fn float_log() {
+    assert_eq!(Log::ln(1.0_f64), 0.0);
+    assert_eq!(Log::log2(1024.0_f32), 10.0);
+    assert_eq!(Log::log10(1000.0_f64), 3.0);
+    assert_eq!(Log::log(0.0_f64, 2.0), f64::NEG_INFINITY);
+    assert!(Log::ln(-1.0_f64).is_nan());
+    assert_eq!(Log::ln_1p(-1.0_f64), f64::NEG_INFINITY);
+    assert_eq!(Log::ln_1p(1e-20_f64), 1e-20);
+}
@@ -0,0 +1,65 @@
+use newnum::*;
+
+macro_rules! assert_consts {
+    ($type:ident: $($const:ident = $method:ident),* $(,)?) => {
+        $(
+            assert_eq!(
+                <$type as MathConsts>::$const,
+                std::$type::consts::$const,
+                concat!(stringify!($type), "::", stringify!($const))
+            );
+            assert_eq!(
+                <$type as FromFloatLiteral>::$method(),
+                <$type as MathConsts>::$const,
+                concat!(stringify!($type), "::", stringify!($method))
+            );
+        )*
+    };
+}
+
+macro_rules! assert_all_consts {
+    ($type:ident) => {
+        assert_consts!(
+            $type:
+            PI = pi,
+            TAU = tau,
+            FRAC_PI_2 = frac_pi_2,
+            FRAC_PI_3 = frac_pi_3,
+            FRAC_PI_4 = frac_pi_4,
+            FRAC_PI_6 = frac_pi_6,
+            FRAC_PI_8 = frac_pi_8,
+            FRAC_1_PI = frac_1_pi,
+            FRAC_2_PI = frac_2_pi,
+            FRAC_2_SQRT_PI = frac_2_sqrt_pi,
+            SQRT_2 = sqrt_2,
+            FRAC_1_SQRT_2 = frac_1_sqrt_2,
+            E = e,
+            LN_2 = ln_2,
+            LN_10 = ln_10,
+            LOG2_E = log2_e,
+            LOG10_E = log10_e,
+            LOG2_10 = log2_10,
+            LOG10_2 = log10_2,
+        );
+    };
+}
+
+#[test]
+fn math_consts_match_std() {
+    assert_all_consts!(f32);
+    assert_all_consts!(f64);
+}
+
+#[test]
+fn golden_ratio() {
+    assert_eq!(<f64 as MathConsts>::GOLDEN_RATIO, 1.618033988749895);
+    assert_eq!(<f32 as MathConsts>::GOLDEN_RATIO, 1.618034);
+    assert_eq!(
+        <f64 as FromFloatLiteral>::golden_ratio(),
+        <f64 as MathConsts>::GOLDEN_RATIO
+    );
+    assert_eq!(
+        <f32 as FromFloatLiteral>::golden_ratio(),
+        <f32 as MathConsts>::GOLDEN_RATIO
+    );
+}
@@ -0,0 +1,70 @@
+use std::num::NonZero;
+
+use newnum::*;
+
+macro_rules! non_zero {
+    ($value:expr) => {
+        NonZero::new($value).unwrap()
+    };
+}
+
+#[test]
+fn literals() {
+    let value: NonZero<i32> = num!(-5);
+    assert_eq!(value.get(), -5);
+    let value: NonZero<u8> = num!(255);
+    assert_eq!(value.get(), 255);
+    let value: NonZero<i8> = num!(-128);
+    assert_eq!(value.get(), -128);
+    let value: NonZero<u128> = num_approx!(1);
+    assert_eq!(value.get(), 1);
+
+    assert_eq!(<NonZero<u16> as FromIntLiteral>::MIN_LITERAL, 1);
+    assert_eq!(<NonZero<i16> as FromIntLiteral>::MIN_LITERAL, -32768);
+}
+
+#[test]
+fn sign_and_abs() {
+    assert_eq!(non_zero!(-3_i32).sign(), Sign::Negative);
+    assert_eq!(non_zero!(3_u32).sign(), Sign::Positive);
+    assert!(!Signed::is_zero(&non_zero!(1_i8)));
+    assert!(Signed::is_negative(&non_zero!(-1_i64)));
+
+    assert_eq!(Positive::abs(non_zero!(-7_i16)), non_zero!(7));
+    assert_eq!(Positive::abs(non_zero!(7_u16)), non_zero!(7));
+    assert_eq!(Negative::neg_abs(non_zero!(7_i16)), non_zero!(-7));
+    assert_eq!(Negative::neg_abs(non_zero!(-7_i16)), non_zero!(-7));
+    assert_eq!(Negative::neg_abs(non_zero!(i8::MIN)), non_zero!(i8::MIN));
+}
+
+#[test]
+fn min_max_and_bounds() {
+    assert_eq!(MinMax::min(non_zero!(-3_i32), non_zero!(2)), non_zero!(-3));
+    assert_eq!(MinMax::max(non_zero!(4_u8), non_zero!(9)), non_zero!(9));
+    assert_eq!(
+        MinMax::clamp(non_zero!(100_u8), non_zero!(1), non_zero!(10)),
+        non_zero!(10)
+    );
+
+    assert_eq!(NonZero::<u8>::type_min(), non_zero!(1));
+    assert_eq!(NonZero::<i8>::type_min(), non_zero!(i8::MIN));
+    assert_eq!(NonZero::<u64>::type_max(), non_zero!(u64::MAX));
+}
+
+#[test]
+fn roots() {
+    assert_eq!(non_zero!(17_u32).trunc_sqrt(), non_zero!(4));
+    assert_eq!(non_zero!(1_u8).trunc_sqrt(), non_zero!(1));
+    assert_eq!(non_zero!(-28_i32).trunc_cbrt(), non_zero!(-3));
+    assert_eq!(non_zero!(u64::MAX).trunc_nth_root(64), non_zero!(1));
+    assert_eq!(non_zero!(16_i64).checked_exact_sqrt(), Some(non_zero!(4)));
+    assert_eq!(non_zero!(15_i64).checked_exact_sqrt(), None);
+}
+
+#[test]
+fn abs_diff_outputs_the_zeroable_type() {
+    assert_eq!(non_zero!(5_u8).abs_diff(non_zero!(5)), 0_u8);
+    assert_eq!(non_zero!(3_u8).abs_diff(non_zero!(250)), 247_u8);
+    assert_eq!(non_zero!(-4_i32).abs_diff(non_zero!(6)), 10_i32);
+    assert_eq!(non_zero!(6_i32).abs_diff(non_zero!(-4)), 10_i32);
+}
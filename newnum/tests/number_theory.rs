@@ -0,0 +1,137 @@
+use newnum::*;
+
+#[test]
+fn is_prime_large() {
+    // The largest primes that fit in `u64` and `u128`.
+    assert!(18446744073709551557_u64.is_prime());
+    assert!(340282366920938463463374607431768211297_u128.is_prime());
+    // `2^61 - 1` and `2^127 - 1`.
+    assert!(2305843009213693951_i64.is_prime());
+    assert!(170141183460469231731687303715884105727_i128.is_prime());
+
+    assert!(!u64::MAX.is_prime());
+    assert!(!u128::MAX.is_prime());
+    // `2^64 + 1 = 274177 * 67280421310721`.
+    assert!(!18446744073709551617_u128.is_prime());
+    // `(2^61 - 1)^2`.
+    assert!(!5316911983139663487003542222693990401_u128.is_prime());
+}
+
+#[test]
+fn is_prime_carmichael() {
+    // Carmichael numbers pass the Fermat test for every coprime base.
+    assert!(!561_u32.is_prime());
+    assert!(!41041_u32.is_prime());
+    assert!(!825265_u32.is_prime());
+    assert!(!321197185_i64.is_prime());
+}
+
+#[test]
+fn is_prime_strong_pseudoprimes() {
+    // The smallest strong pseudoprimes to the bases 2, 2..=3, 2..=5, 2..=7 and 2..=37,
+    // the last of which is only caught by the 13th base.
+    assert!(!2047_u16.is_prime());
+    assert!(!1373653_u32.is_prime());
+    assert!(!25326001_u32.is_prime());
+    assert!(!3215031751_u64.is_prime());
+    assert!(!318665857834031151167461_u128.is_prime());
+}
+
+#[test]
+fn is_prime_small() {
+    let primes = (0_i32..100)
+        .filter(|value| value.is_prime())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        primes,
+        [
+            2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83,
+            89, 97
+        ]
+    );
+
+    assert!(!(-7_i32).is_prime());
+    assert!(!i32::MIN.is_prime());
+}
+
+#[test]
+fn mod_inverse() {
+    assert_eq!(3_u32.mod_inverse(11), Some(4));
+    assert_eq!((-3_i32).mod_inverse(11), Some(7));
+    assert_eq!(1_u8.mod_inverse(1), Some(0));
+    assert_eq!(u64::MAX.mod_inverse(u64::MAX - 1), Some(1));
+
+    assert_eq!(0_u32.mod_inverse(7), None);
+    assert_eq!(6_u32.mod_inverse(9), None);
+    assert_eq!(14_i64.mod_inverse(21), None);
+    assert_eq!(10_u8.mod_inverse(5), None);
+}
+
+#[test]
+#[should_panic]
+fn mod_inverse_zero_modulus() {
+    3_u32.mod_inverse(0);
+}
+
+#[test]
+fn mod_pow() {
+    assert_eq!(4_u32.mod_pow(13, 497), 445);
+    assert_eq!((-2_i32).mod_pow(3, 5), 2);
+    assert_eq!(
+        u64::MAX.mod_pow(u64::MAX, u64::MAX - 2),
+        2_u64.mod_pow(u64::MAX, u64::MAX - 2)
+    );
+    assert_eq!(5_u8.mod_pow(0, 1), 0);
+}
+
+#[test]
+fn extended_gcd_signs() {
+    for (a, b) in [
+        (240_i32, 46),
+        (-240, 46),
+        (240, -46),
+        (-240, -46),
+        (0, -5),
+        (-5, 0),
+    ] {
+        let (gcd, x, y) = a.extended_gcd(b);
+
+        assert!(gcd >= 0);
+        assert_eq!(gcd, a.gcd(b));
+        assert_eq!(a * x + b * y, gcd);
+    }
+
+    assert_eq!(0_i32.extended_gcd(0), (0, 1, 0));
+}
+
+#[test]
+fn gcd_lcm() {
+    assert_eq!(0_u32.gcd(0), 0);
+    assert_eq!((-12_i32).gcd(-18), 6);
+    assert_eq!(i32::MIN.gcd(-1), 1);
+    assert_eq!((-4_i32).lcm(6), 12);
+    assert_eq!(0_u32.lcm(6), 0);
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn big_int() {
+    let mersenne = BigUint::from(2_u8).pow(521) - BigUint::one();
+    assert!(mersenne.clone().is_prime());
+    assert!(!(mersenne.clone() * num!(3)).is_prime());
+    assert_eq!(
+        num!(2: BigUint).mod_pow(mersenne.clone() - BigUint::one(), mersenne.clone()),
+        BigUint::one()
+    );
+
+    let a = BigInt::from(-240);
+    let b = BigInt::from(46);
+    assert_eq!(a.clone().gcd(b.clone()), num!(2));
+    assert_eq!(a.clone().lcm(b.clone()), num!(5520));
+
+    let (gcd, x, y) = a.clone().extended_gcd(b.clone());
+    assert_eq!(a * x + b * y, gcd);
+
+    assert_eq!(num!(3: BigInt).mod_inverse(num!(11)), Some(num!(4)));
+    assert_eq!(num!(6: BigInt).mod_inverse(num!(9)), None);
+}
@@ -0,0 +1,93 @@
+use std::num::{Saturating, Wrapping};
+
+use newnum::{derive::*, *};
+
+#[derive(Debug, Clone, Copy, PartialEq, One, ConstOne)]
+#[derive_bound(One; T: One)]
+#[derive_bound(ConstOne; T: ConstOne)]
+struct Vec2<T> {
+    x: T,
+    y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, One, ConstOne)]
+struct Scale(f32, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, One, ConstOne)]
+#[derive_bound(One; T: One)]
+#[derive_bound(ConstOne; T: ConstOne)]
+enum Factor<T> {
+    Zero,
+    #[one]
+    Unit,
+    Value(T),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, One, ConstOne)]
+enum Weight {
+    None,
+    #[one]
+    Full {
+        value: u8,
+        scale: f64,
+    },
+}
+
+#[test]
+fn prim_one() {
+    assert_eq!(u8::one(), 1);
+    assert_eq!(i128::one(), 1);
+    assert_eq!(f64::one(), 1.0);
+    assert_eq!(<usize as ConstOne>::ONE, 1);
+    assert_eq!(<f32 as ConstOne>::ONE, 1.0);
+
+    assert!(One::is_one(&1_i8));
+    assert!(!One::is_one(&-1_i8));
+    assert!(!One::is_one(&0_u32));
+    assert!(One::is_one(&1.0_f32));
+    assert!(!One::is_one(&f64::NAN));
+}
+
+#[test]
+fn std_wrapper_one() {
+    assert_eq!(Wrapping::<u16>::one(), Wrapping(1));
+    assert_eq!(<Saturating<i64> as ConstOne>::ONE, Saturating(1));
+    assert!(One::is_one(&Wrapping(1_i8)));
+    assert!(!One::is_one(&Saturating(2_u8)));
+}
+
+#[test]
+fn one_derive_struct() {
+    assert_eq!(Vec2::<i32>::one(), Vec2 { x: 1, y: 1 });
+    assert_eq!(<Vec2<f64> as ConstOne>::ONE, Vec2 { x: 1.0, y: 1.0 });
+    assert_eq!(Scale::one(), Scale(1.0, 1));
+    assert_eq!(<Scale as ConstOne>::ONE, Scale(1.0, 1));
+
+    assert!(Vec2::<u8>::one().is_one());
+    assert!(!Vec2 { x: 1_u8, y: 2 }.is_one());
+}
+
+#[test]
+fn one_derive_enum() {
+    assert_eq!(Factor::<u8>::one(), Factor::Unit);
+    assert_eq!(<Factor<u8> as ConstOne>::ONE, Factor::Unit);
+    assert!(Factor::<u8>::Unit.is_one());
+    assert!(!Factor::Value(1_u8).is_one());
+    assert!(!Factor::<u8>::Zero.is_one());
+
+    assert_eq!(
+        Weight::one(),
+        Weight::Full {
+            value: 1,
+            scale: 1.0
+        }
+    );
+    assert_eq!(
+        <Weight as ConstOne>::ONE,
+        Weight::Full {
+            value: 1,
+            scale: 1.0
+        }
+    );
+    assert!(!Weight::None.is_one());
+}
@@ -0,0 +1,35 @@
+use newnum::*;
+
+#[test]
+fn int_pow() {
+    assert_eq!(Pow::pow(3_u8, 5), 243);
+    assert_eq!(Pow::pow(-2_i8, 7), i8::MIN);
+    assert_eq!(Pow::pow(0_u32, 0), 1);
+    assert_eq!(Pow::pow(u128::MAX, 1), u128::MAX);
+}
+
+#[test]
+fn float_pow() {
+    assert_eq!(Pow::<u32>::pow(1.5_f64, 2), 2.25);
+    assert_eq!(Pow::<i32>::pow(2.0_f64, -2), 0.25);
+    assert_eq!(Pow::<f64>::pow(4.0_f64, 0.5), 2.0);
+    assert!(Pow::<f64>::pow(-4.0_f64, 0.5).is_nan());
+}
+
+#[test]
+fn float_pow_u32_above_i32_max() {
+    let exp = i32::MAX as u32 + 1;
+
+    assert_eq!(Pow::<u32>::pow(-1.0_f64, exp), 1.0);
+    assert_eq!(Pow::<u32>::pow(-1.0_f32, exp + 1), -1.0);
+    assert_eq!(Pow::<u32>::pow(2.0_f64, exp), f64::INFINITY);
+    assert_eq!(Pow::<u32>::pow(0.5_f64, u32::MAX), 0.0);
+}
+
+#[test]
+fn float_exp() {
+    assert_eq!(Exp::exp(0.0_f64), 1.0);
+    assert_eq!(Exp::exp2(10.0_f32), 1024.0);
+    assert_eq!(Exp::exp(f64::NEG_INFINITY), 0.0);
+    assert_eq!(Exp::exp_m1(1e-20_f64), 1e-20);
+}
@@ -0,0 +1,227 @@
+use newnum::*;
+
+/// Wraps `value` into `start..=end` by brute force, as the reference for the wrapping operations.
+fn wrap_reference(value: i128, start: i128, end: i128) -> i128 {
+    start + (value - start).rem_euclid(end - start + 1)
+}
+
+macro_rules! assert_small_range {
+    ($type:ident, $min:literal, $max:literal) => {{
+        type R = Ranged<$type, $min, $max>;
+
+        for a in $min..=$max {
+            let ranged_a = R::new(a).unwrap();
+
+            assert_eq!(
+                ranged_a.wrapping_neg().get() as i128,
+                wrap_reference(-(a as i128), $min, $max),
+                "-{a}"
+            );
+            assert_eq!(
+                ranged_a.wrapping_pow(3).get() as i128,
+                wrap_reference((a as i128).pow(3), $min, $max),
+                "{a}^3"
+            );
+
+            for b in $min..=$max {
+                let ranged_b = R::new(b).unwrap();
+                let (a, b) = (a as i128, b as i128);
+
+                assert_eq!(
+                    ranged_a.wrapping_add(ranged_b).get() as i128,
+                    wrap_reference(a + b, $min, $max),
+                    "{a} + {b}"
+                );
+                assert_eq!(
+                    ranged_a.wrapping_sub(ranged_b).get() as i128,
+                    wrap_reference(a - b, $min, $max),
+                    "{a} - {b}"
+                );
+                assert_eq!(
+                    ranged_a.wrapping_mul(ranged_b).get() as i128,
+                    wrap_reference(a * b, $min, $max),
+                    "{a} * {b}"
+                );
+
+                assert_eq!(
+                    ranged_a.saturating_add(ranged_b).get() as i128,
+                    Ord::clamp(a + b, $min, $max),
+                    "{a} + {b}"
+                );
+                assert_eq!(
+                    ranged_a.checked_add(ranged_b).map(|sum| sum.get() as i128),
+                    Some(a + b).filter(|sum| ($min..=$max).contains(sum)),
+                    "{a} + {b}"
+                );
+            }
+        }
+    }};
+}
+
+#[test]
+fn small_ranges() {
+    assert_small_range!(u8, 100, 150);
+    assert_small_range!(u8, 0, 255);
+    assert_small_range!(i8, -128, 127);
+    assert_small_range!(i8, -20, -3);
+    assert_small_range!(i16, -7, 30);
+    assert_small_range!(i32, 5, 5);
+}
+
+#[test]
+fn wide_ranges() {
+    type Full = Ranged<i128, { i128::MIN }, { i128::MAX }>;
+    let max = Full::new(i128::MAX).unwrap();
+    assert_eq!(max.wrapping_add(Full::new(1).unwrap()).get(), i128::MIN);
+    assert_eq!(
+        max.wrapping_mul(max).get(),
+        i128::MAX.wrapping_mul(i128::MAX)
+    );
+
+    // The range is intersected with the range of `u128`, so it is `0..=i128::MAX`.
+    type Half = Ranged<u128, { i128::MIN }, { i128::MAX }>;
+    let max = Half::new(i128::MAX as u128).unwrap();
+    assert_eq!(max.wrapping_add(Half::new(1).unwrap()).get(), 0);
+    assert_eq!(max.wrapping_mul(max).get(), 1);
+    assert!(Half::new(i128::MAX as u128 + 1).is_err());
+
+    type Odd = Ranged<i128, -3, { i128::MAX }>;
+    let max = Odd::new(i128::MAX).unwrap();
+    assert_eq!(max.wrapping_add(max).get(), i128::MAX - 4);
+    assert_eq!(Odd::new(-3).unwrap().wrapping_neg().get(), 3);
+}
+
+#[test]
+fn signed() {
+    type R = Ranged<i8, -5, 5>;
+
+    assert_eq!(R::new(-3).unwrap().sign(), Sign::Negative);
+    assert_eq!(R::new(0).unwrap().sign(), Sign::Zero);
+    assert_eq!(R::new(4).unwrap().bit_sign(), BitSign::Positive);
+    assert!(Signed::is_positive(&R::new(1).unwrap()));
+    assert!(Signed::is_negative(&R::new(-1).unwrap()));
+    assert!(Signed::is_zero(&R::new(0).unwrap()));
+    assert!(R::new(0).unwrap().is_bin_positive());
+    assert!(R::new(-5).unwrap().is_bin_negative());
+}
+
+fn not_negative<T: NotNegative>() {}
+fn not_positive<T: NotPositive>() {}
+fn not_zero<T: NotZero>() {}
+
+#[test]
+fn sign_markers() {
+    not_negative::<Ranged<i8, 0, 10>>();
+    not_negative::<Ranged<i32, 1, 10>>();
+    not_zero::<Ranged<i32, 1, { i128::MAX }>>();
+    not_positive::<Ranged<i8, -10, 0>>();
+    not_positive::<Ranged<i64, { i128::MIN }, -1>>();
+}
+
+#[test]
+fn type_min_max() {
+    assert_eq!(Ranged::<u8, -5, 300>::type_min().get(), 0);
+    assert_eq!(Ranged::<u8, -5, 300>::type_max().get(), 255);
+    assert_eq!(Ranged::<i8, -20, -3>::type_min().get(), -20);
+    assert_eq!(Ranged::<i8, -20, -3>::type_max().get(), -3);
+    assert_eq!(
+        Ranged::<i128, { i128::MIN }, 7>::type_min().get(),
+        i128::MIN
+    );
+}
+
+#[test]
+fn abs_and_neg_abs_in_range() {
+    type R = Ranged<i8, -10, 5>;
+
+    assert_eq!(R::new(-4).unwrap().abs().get(), 4);
+    assert_eq!(R::new(3).unwrap().neg_abs().get(), -3);
+    assert_eq!(R::new(-10).unwrap().neg_abs().get(), -10);
+}
+
+#[test]
+#[should_panic]
+fn abs_out_of_range_panics() {
+    Ranged::<i8, -10, 5>::new(-10).unwrap().abs();
+}
+
+#[test]
+#[should_panic]
+fn neg_abs_out_of_range_panics() {
+    Ranged::<i8, -5, 10>::new(10).unwrap().neg_abs();
+}
+
+#[test]
+fn saturating_ops_clamp_to_the_range() {
+    type R = Ranged<u8, 0, 100>;
+    let value = |value| R::new(value).unwrap();
+
+    assert_eq!(value(20).saturating_mul(value(10)).get(), 100);
+    assert_eq!(value(9).saturating_mul(value(11)).get(), 99);
+    assert_eq!(value(3).saturating_pow(5).get(), 100);
+    assert_eq!(value(3).saturating_pow(4).get(), 81);
+    assert_eq!(value(3).saturating_shl(6).get(), 100);
+    assert_eq!(value(3).saturating_shl(200).get(), 100);
+    assert_eq!(value(3).saturating_shl(4).get(), 48);
+
+    type S = Ranged<i8, -50, 50>;
+    let value = |value| S::new(value).unwrap();
+
+    assert_eq!(value(-20).saturating_mul(value(10)).get(), -50);
+    assert_eq!(value(-2).saturating_pow(7).get(), -50);
+    assert_eq!(value(-2).saturating_shl(5).get(), -50);
+    assert_eq!(value(7).saturating_shl(9).get(), 50);
+}
+
+#[test]
+fn wrapping_shl_wraps_the_product() {
+    type R = Ranged<u8, 0, 100>;
+    let value = |value| R::new(value).unwrap();
+
+    assert_eq!(value(30).wrapping_shl(2).get(), 19);
+    // `2^200 = 1 (mod 101)`.
+    assert_eq!(value(3).wrapping_shl(200).get(), 3);
+
+    type S = Ranged<i8, -20, -3>;
+    for a in -20..=-3 {
+        for shift in 0..10 {
+            assert_eq!(
+                S::new(a).unwrap().wrapping_shl(shift).get() as i128,
+                wrap_reference((a as i128) << shift, -20, -3),
+                "{a} << {shift}"
+            );
+        }
+    }
+}
+
+#[test]
+fn trunc_root() {
+    type R = Ranged<u8, 3, 100>;
+    let value = |value| R::new(value).unwrap();
+
+    assert_eq!(value(100).trunc_sqrt().get(), 10);
+    assert_eq!(value(99).trunc_sqrt().get(), 9);
+    assert_eq!(value(64).trunc_cbrt().get(), 4);
+    assert_eq!(value(81).trunc_nth_root(4).get(), 3);
+    assert_eq!(value(64).checked_exact_sqrt(), Some(value(8)));
+    assert_eq!(value(50).checked_exact_sqrt(), None);
+    // `2` is out of range.
+    assert_eq!(value(4).checked_exact_sqrt(), None);
+}
+
+#[test]
+#[should_panic]
+fn trunc_root_out_of_range_panics() {
+    Ranged::<u8, 3, 100>::new(5).unwrap().trunc_cbrt();
+}
+
+#[test]
+fn fract_is_zero() {
+    assert_eq!(Ranged::<i8, -5, 5>::new(3).unwrap().fract().get(), 0);
+}
+
+#[test]
+#[should_panic]
+fn fract_without_zero_panics() {
+    Ranged::<u8, 1, 10>::new(5).unwrap().fract();
+}
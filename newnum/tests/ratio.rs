@@ -0,0 +1,124 @@
+use newnum::{num, Positive, Ratio, Round, WholeEquivalent};
+
+type R = Ratio<i16>;
+
+/// Every ratio with a numerator in `-range..=range` and a denominator in `1..=range`, with duplicates.
+fn small_ratios(range: i16) -> impl Iterator<Item = R> {
+    (-range..=range).flat_map(move |numer| (1..=range).map(move |denom| R::new(numer, denom)))
+}
+
+/// Compares `a / b` and `c / d` by cross-multiplying in `i64`, as the reference for `Ratio`'s comparison.
+fn cross_cmp(lhs: R, rhs: R) -> std::cmp::Ordering {
+    (*lhs.numer() as i64 * *rhs.denom() as i64).cmp(&(*rhs.numer() as i64 * *lhs.denom() as i64))
+}
+
+#[test]
+fn arithmetic_matches_cross_multiplication() {
+    for a in small_ratios(12) {
+        assert!(*a.denom() > 0, "{a}");
+
+        for b in small_ratios(12) {
+            assert_eq!(a.cmp(&b), cross_cmp(a, b), "{a} vs {b}");
+            assert_eq!(a + b - b, a, "{a} + {b} - {b}");
+
+            let product = a * b;
+            assert_eq!(
+                *product.numer() as i64 * *a.denom() as i64 * *b.denom() as i64,
+                *a.numer() as i64 * *b.numer() as i64 * *product.denom() as i64,
+                "{a} * {b}"
+            );
+
+            if b != num!(0) {
+                assert_eq!(a / b * b, a, "{a} / {b} * {b}");
+
+                let remainder = a % b;
+                assert_eq!(((a - remainder) / b).fract(), num!(0), "{a} % {b}");
+                assert!(remainder.abs() < b.abs(), "{a} % {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn rounding_matches_f64() {
+    for a in small_ratios(24) {
+        let a_f64 = *a.numer() as f64 / *a.denom() as f64;
+
+        assert_eq!(a.ifloor() as f64, a_f64.floor(), "floor({a})");
+        assert_eq!(a.iceil() as f64, a_f64.ceil(), "ceil({a})");
+        assert_eq!(a.itrunc() as f64, a_f64.trunc(), "trunc({a})");
+        assert_eq!(a.iround() as f64, a_f64.round(), "round({a})");
+        assert_eq!(
+            *a.round_ties_even().numer() as f64,
+            a_f64.round_ties_even(),
+            "round_ties_even({a})"
+        );
+        assert_eq!(a.trunc() + a.fract(), a, "fract({a})");
+    }
+}
+
+#[test]
+#[allow(clippy::excessive_precision)]
+fn float_literals_are_exact() {
+    assert_eq!(num!(0.1: Ratio<i64>), Ratio::new(1, 10));
+    assert_eq!(num!(-0.125: Ratio<i64>), Ratio::new(-1, 8));
+    assert_eq!(num!(2.54e1: Ratio<i64>), Ratio::new(127, 5));
+    assert_eq!(
+        num!(1e-18: Ratio<i64>),
+        Ratio::new(1, 1_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        num!(0.30000000000000000001: Ratio<i128>),
+        Ratio::new(30_000_000_000_000_000_001, 100_000_000_000_000_000_000)
+    );
+
+    // Literals that don't fit are approximated.
+    assert_eq!(num!(3.1428: Ratio<i8>), Ratio::new(22, 7));
+    assert_eq!(num!(1e-30: Ratio<i64>), num!(0));
+}
+
+#[test]
+fn new_with_min() {
+    let parts = |ratio: Ratio<i32>| ratio.into_parts();
+
+    assert_eq!(parts(Ratio::new(i32::MIN, i32::MIN)), (1, 1));
+    assert_eq!(parts(Ratio::new(0, i32::MIN)), (0, 1));
+    assert_eq!(parts(Ratio::new(i32::MIN, 5)), (i32::MIN, 5));
+    assert_eq!(parts(Ratio::new(i32::MIN, 6)), (-1_073_741_824, 3));
+    assert_eq!(parts(Ratio::new(i32::MIN, -2)), (1_073_741_824, 1));
+    assert_eq!(parts(Ratio::new(6, i32::MIN)), (-3, 1_073_741_824));
+    assert_eq!(parts(Ratio::new(i8::MIN as i32, 1)), (-128, 1));
+    assert_eq!(Ratio::new(i8::MIN, i8::MIN).into_parts(), (1, 1));
+}
+
+#[test]
+#[should_panic]
+fn new_unrepresentable_panics() {
+    Ratio::new(i32::MIN, -1);
+}
+
+#[cfg(feature = "bigint")]
+#[test]
+fn big_int_ratio() {
+    use newnum::{BigInt, Pow};
+
+    // The harmonic number `H(60)` overflows `i64` in both the numerator and denominator.
+    let harmonic = (1..=60)
+        .map(|n| Ratio::new(BigInt::from(1), BigInt::from(n)))
+        .fold(num!(0), |sum: Ratio<BigInt>, term| sum + term);
+    assert_eq!(
+        harmonic.to_string(),
+        "15117092380124150817026911/3230237388259077233637600"
+    );
+    assert_eq!(harmonic.clone().ifloor(), BigInt::from(4));
+    assert_eq!((-harmonic.clone()).ifloor(), BigInt::from(-5));
+    assert_eq!((-harmonic.clone()).iceil(), BigInt::from(-4));
+    assert!(harmonic > num!(4.67) && harmonic < num!(4.68));
+
+    let tenth: Ratio<BigInt> = num!(0.1);
+    assert_eq!(tenth, Ratio::new(BigInt::from(1), BigInt::from(10)));
+    assert_eq!(
+        *Pow::<u32>::pow(tenth, 40).denom(),
+        Pow::<u32>::pow(BigInt::from(10), 40)
+    );
+}
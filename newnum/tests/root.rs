@@ -0,0 +1,304 @@
+use newnum::{Hypot, Root, TruncRoot};
+
+/// Deterministic xorshift generator so the randomized tests are reproducible without a `rand` dependency.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    /// Returns a random value with a random bit length so small and large values are both covered.
+    fn next_value(&mut self) -> u128 {
+        let bits = self.next_u64() % 129;
+        self.next_u128().checked_shr(128 - bits as u32).unwrap_or(0)
+    }
+}
+
+const RANDOM_ITERATIONS: usize = 100_000;
+
+macro_rules! assert_uint_roots {
+    ($type:ident, $value:expr) => {{
+        let value: $type = $value;
+
+        let sqrt = value.trunc_sqrt();
+        assert!(sqrt.pow(2) <= value, "trunc_sqrt({value}) = {sqrt}");
+        assert!(
+            (sqrt + 1)
+                .checked_pow(2)
+                .is_none_or(|square| square > value),
+            "trunc_sqrt({value}) = {sqrt}"
+        );
+
+        let cbrt = value.trunc_cbrt();
+        assert!(cbrt.pow(3) <= value, "trunc_cbrt({value}) = {cbrt}");
+        assert!(
+            (cbrt + 1).checked_pow(3).is_none_or(|cube| cube > value),
+            "trunc_cbrt({value}) = {cbrt}"
+        );
+
+        assert_eq!(
+            value.checked_exact_sqrt(),
+            Some(sqrt).filter(|sqrt| sqrt * sqrt == value)
+        );
+    }};
+}
+
+macro_rules! assert_sint_roots {
+    ($type:ident => $unsigned:ident, $value:expr) => {{
+        let value: $type = $value;
+
+        if value >= 0 {
+            assert_eq!(
+                value.trunc_sqrt() as $unsigned,
+                (value as $unsigned).trunc_sqrt()
+            );
+            assert_eq!(
+                value.checked_exact_sqrt().map(|sqrt| sqrt as $unsigned),
+                (value as $unsigned).checked_exact_sqrt()
+            );
+        } else {
+            assert_eq!(value.checked_exact_sqrt(), None);
+        }
+
+        let cbrt = value.trunc_cbrt();
+        assert_eq!(cbrt.unsigned_abs(), value.unsigned_abs().trunc_cbrt());
+        assert!(cbrt == 0 || (cbrt < 0) == (value < 0));
+    }};
+}
+
+macro_rules! assert_edge_cases {
+    ($type:ident, $assert:ident $(=> $unsigned:ident)?) => {
+        for shift in 0..$type::BITS {
+            let power = (1 as $type) << shift;
+            for value in [power.wrapping_sub(1), power, power.wrapping_add(1)] {
+                $assert!($type $(=> $unsigned)?, value);
+            }
+        }
+
+        $assert!($type $(=> $unsigned)?, $type::MIN);
+        $assert!($type $(=> $unsigned)?, $type::MAX);
+    };
+}
+
+#[test]
+fn exhaustive_u8() {
+    for value in u8::MIN..=u8::MAX {
+        assert_uint_roots!(u8, value);
+    }
+}
+
+#[test]
+fn exhaustive_u16() {
+    for value in u16::MIN..=u16::MAX {
+        assert_uint_roots!(u16, value);
+    }
+}
+
+#[test]
+fn exhaustive_i8() {
+    for value in i8::MIN..=i8::MAX {
+        assert_sint_roots!(i8 => u8, value);
+    }
+}
+
+#[test]
+fn exhaustive_i16() {
+    for value in i16::MIN..=i16::MAX {
+        assert_sint_roots!(i16 => u16, value);
+    }
+}
+
+#[test]
+fn random_uint() {
+    let mut rng = XorShift(0x9E37_79B9_7F4A_7C15);
+
+    assert_edge_cases!(u32, assert_uint_roots);
+    assert_edge_cases!(u64, assert_uint_roots);
+    assert_edge_cases!(u128, assert_uint_roots);
+    assert_edge_cases!(usize, assert_uint_roots);
+
+    for _ in 0..RANDOM_ITERATIONS {
+        let value = rng.next_value();
+
+        assert_uint_roots!(u32, value as u32);
+        assert_uint_roots!(u64, value as u64);
+        assert_uint_roots!(u128, value);
+        assert_uint_roots!(usize, value as usize);
+
+        // Perfect squares and their neighbours are where truncation errors show up.
+        let root = rng.next_u64();
+        let square = root as u128 * root as u128;
+        assert_eq!(square.trunc_sqrt(), root as u128);
+        assert_eq!((square + 1).trunc_sqrt(), root as u128);
+        if square > 0 {
+            assert_eq!((square - 1).trunc_sqrt(), root as u128 - 1);
+        }
+    }
+}
+
+#[test]
+fn random_sint() {
+    let mut rng = XorShift(0xD1B5_4A32_D192_ED03);
+
+    assert_edge_cases!(i32, assert_sint_roots => u32);
+    assert_edge_cases!(i64, assert_sint_roots => u64);
+    assert_edge_cases!(i128, assert_sint_roots => u128);
+    assert_edge_cases!(isize, assert_sint_roots => usize);
+
+    for _ in 0..RANDOM_ITERATIONS {
+        let value = rng.next_value();
+
+        assert_sint_roots!(i32 => u32, value as i32);
+        assert_sint_roots!(i64 => u64, value as i64);
+        assert_sint_roots!(i128 => u128, value as i128);
+        assert_sint_roots!(isize => usize, value as isize);
+    }
+}
+
+#[test]
+#[should_panic]
+fn negative_sqrt_panics() {
+    (-4_i32).trunc_sqrt();
+}
+
+#[test]
+fn negative_cbrt() {
+    assert_eq!((-8_i32).trunc_cbrt(), -2);
+    assert_eq!((-9_i32).trunc_cbrt(), -2);
+    assert_eq!(i128::MIN.trunc_cbrt(), -5_541_191_377_756);
+}
+
+/// Finds the truncated `n`th root by trying every candidate.
+fn brute_force_nth_root(value: i32, n: u32) -> i32 {
+    let root = (0..=value.abs())
+        .take_while(|root: &i32| (*root as i64).pow(n) <= value.abs() as i64)
+        .last()
+        .unwrap();
+
+    root * value.signum()
+}
+
+#[test]
+fn exhaustive_nth_root_8_bit() {
+    for n in 1..=9 {
+        for value in u8::MIN..=u8::MAX {
+            assert_eq!(
+                value.trunc_nth_root(n) as i32,
+                brute_force_nth_root(value as i32, n),
+                "trunc_nth_root({value}, {n})"
+            );
+        }
+
+        for value in i8::MIN..=i8::MAX {
+            if value < 0 && n % 2 == 0 {
+                continue;
+            }
+
+            assert_eq!(
+                value.trunc_nth_root(n) as i32,
+                brute_force_nth_root(value as i32, n),
+                "trunc_nth_root({value}, {n})"
+            );
+        }
+    }
+}
+
+#[test]
+fn nth_root_of_max() {
+    for n in 1..=130 {
+        let root = u128::MAX.trunc_nth_root(n);
+        assert!(root.checked_pow(n).is_some());
+        assert!(
+            root.checked_add(1)
+                .and_then(|root| root.checked_pow(n))
+                .is_none(),
+            "n = {n}"
+        );
+
+        let root = i128::MIN.trunc_nth_root(n | 1);
+        assert!(root < 0);
+        assert!(root.checked_pow(n | 1).is_some());
+        assert!(
+            root.checked_sub(1)
+                .and_then(|root| root.checked_pow(n | 1))
+                .is_none(),
+            "n = {}",
+            n | 1
+        );
+    }
+
+    assert_eq!(u64::MAX.trunc_nth_root(2), u32::MAX as u64);
+    assert_eq!(u64::MAX.trunc_nth_root(64), 1);
+    assert_eq!(i64::MIN.trunc_nth_root(63), -2);
+}
+
+#[test]
+#[should_panic]
+fn zeroth_root_panics() {
+    8_u32.trunc_nth_root(0);
+}
+
+#[test]
+#[should_panic]
+fn zeroth_root_of_zero_panics() {
+    0_i32.trunc_nth_root(0);
+}
+
+#[test]
+#[should_panic]
+fn even_root_of_negative_panics() {
+    (-16_i32).trunc_nth_root(4);
+}
+
+#[test]
+#[should_panic]
+fn float_zeroth_trunc_root_panics() {
+    8.0_f64.trunc_nth_root(0);
+}
+
+#[test]
+#[should_panic]
+fn float_even_trunc_root_of_negative_panics() {
+    (-16.0_f64).trunc_nth_root(2);
+}
+
+#[test]
+fn float_nth_root() {
+    assert_eq!(Root::nth_root(8.0_f64, 1), 8.0);
+    assert_eq!(Root::nth_root(16.0_f64, 2), 4.0);
+    assert_eq!(Root::nth_root(-27.0_f64, 3), -3.0);
+    assert!((Root::nth_root(32.0_f64, 5) - 2.0).abs() < 1e-15);
+    assert!((Root::nth_root(-32.0_f64, 5) + 2.0).abs() < 1e-15);
+    assert_eq!(Root::nth_root(0.0_f32, 4), 0.0);
+
+    assert!(Root::nth_root(8.0_f64, 0).is_nan());
+    assert!(Root::nth_root(-16.0_f64, 2).is_nan());
+    assert!(Root::nth_root(-16.0_f64, 4).is_nan());
+
+    assert_eq!((-33.0_f64).trunc_nth_root(5), -2.0);
+    assert_eq!(80.9_f32.trunc_nth_root(4), 2.0);
+}
+
+#[test]
+fn float_rsqrt_hypot_and_exact_sqrt() {
+    assert_eq!(Root::rsqrt(4.0_f64), 0.5);
+    assert_eq!(Root::rsqrt(0.0_f64), f64::INFINITY);
+    assert!(Root::rsqrt(-1.0_f32).is_nan());
+
+    assert_eq!(Hypot::hypot(3.0_f64, -4.0), 5.0);
+    assert_eq!(Hypot::hypot(f64::MAX, f64::MAX), f64::INFINITY);
+    assert_eq!(Hypot::hypot(1e300_f64, 1e300), 1e300 * 2_f64.sqrt());
+
+    assert_eq!(16.0_f64.checked_exact_sqrt(), Some(4.0));
+    assert_eq!(0.25_f64.checked_exact_sqrt(), None);
+    assert_eq!(2.0_f32.checked_exact_sqrt(), None);
+    assert_eq!((-4.0_f64).checked_exact_sqrt(), None);
+}
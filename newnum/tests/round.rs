@@ -0,0 +1,129 @@
+use std::num::Wrapping;
+
+use newnum::*;
+
+#[test]
+fn int_fract_is_zero() {
+    assert_eq!(Round::fract(7_i32), 0);
+    assert_eq!(Round::fract(-7_i32), 0);
+    assert_eq!(Round::fract(u8::MAX), 0);
+    assert_eq!(Round::fract(Wrapping(5_u16)), Wrapping(0));
+    assert_eq!(Round::fract(-1.5_f32), -0.5);
+}
+
+#[test]
+fn int_round_to_multiple() {
+    assert_eq!(14_i32.round_to_multiple(10), 10);
+    assert_eq!(15_i32.round_to_multiple(10), 20);
+    assert_eq!((-14_i32).round_to_multiple(10), -10);
+    assert_eq!((-15_i32).round_to_multiple(10), -20);
+    assert_eq!((-5_i32).round_to_multiple(10), -10);
+    assert_eq!(15_i32.round_to_multiple(-10), 20);
+    assert_eq!((-15_i32).round_to_multiple(-10), -20);
+    assert_eq!(7_u8.round_to_multiple(1), 7);
+    assert_eq!(249_u8.round_to_multiple(100), 200);
+    assert_eq!(i8::MIN.round_to_multiple(-1), i8::MIN);
+    assert_eq!(i8::MIN.round_to_multiple(i8::MIN), i8::MIN);
+    assert_eq!(63_i8.round_to_multiple(i8::MIN), 0);
+    assert_eq!((-64_i8).round_to_multiple(i8::MIN), i8::MIN);
+}
+
+#[test]
+#[should_panic]
+fn int_round_to_multiple_zero_step() {
+    5_u32.round_to_multiple(0);
+}
+
+// Overflowing results follow the std overflow behavior, which only panics with overflow checks.
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn int_round_to_multiple_overflow() {
+    126_i8.round_to_multiple(4);
+}
+
+#[test]
+fn float_round_to_multiple() {
+    assert_eq!(2.5_f64.round_to_multiple(1.0), 3.0);
+    assert_eq!((-2.5_f64).round_to_multiple(1.0), -3.0);
+    assert_eq!((-7.5_f32).round_to_multiple(5.0), -10.0);
+    assert_eq!(7.4_f32.round_to_multiple(-5.0), 5.0);
+    assert_eq!(0.3_f64.round_to_multiple(0.25), 0.25);
+    assert!(1.0_f32.round_to_multiple(0.0).is_nan());
+}
+
+#[test]
+fn int_round_to_decimals() {
+    assert_eq!(1234_i32.round_to_decimals(2), 1234);
+    assert_eq!(1234_i32.round_to_decimals(-2), 1200);
+    assert_eq!(1250_i32.round_to_decimals(-2), 1300);
+    assert_eq!((-1250_i32).round_to_decimals(-2), -1300);
+    assert_eq!((-1249_i32).round_to_decimals(-2), -1200);
+    assert_eq!(250_u8.round_to_decimals(-3), 0);
+    assert_eq!(i8::MIN.round_to_decimals(-3), 0);
+    assert_eq!(u64::MAX.round_to_decimals(-20), 0);
+}
+
+#[test]
+#[should_panic]
+fn int_round_to_decimals_overflow() {
+    60000_u16.round_to_decimals(-5);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn int_round_to_decimals_overflow_in_range() {
+    250_u8.round_to_decimals(-2);
+}
+
+#[test]
+fn float_round_to_decimals() {
+    assert_eq!(1.2345_f64.round_to_decimals(2), 1.23);
+    assert_eq!((-1.25_f64).round_to_decimals(1), -1.3);
+    assert_eq!(1.25_f64.round_to_decimals(1), 1.3);
+    assert_eq!(1250.0_f32.round_to_decimals(-2), 1300.0);
+    assert_eq!((-1250.0_f32).round_to_decimals(-2), -1300.0);
+    assert_eq!(f64::MAX.round_to_decimals(2), f64::MAX);
+    assert_eq!(1e300_f64.round_to_decimals(-400), 0.0);
+    assert_eq!(f32::INFINITY.round_to_decimals(2), f32::INFINITY);
+    assert!(f32::NAN.round_to_decimals(2).is_nan());
+}
+
+#[test]
+fn round_with() {
+    let modes = [
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfToEven,
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::Trunc,
+        RoundingMode::ATrunc,
+    ];
+
+    let results = |value: f32| modes.map(|mode| value.round_with(mode));
+
+    assert_eq!(results(2.5), [3.0, 2.0, 2.0, 3.0, 2.0, 3.0]);
+    assert_eq!(results(-2.5), [-3.0, -2.0, -3.0, -2.0, -2.0, -3.0]);
+    assert_eq!(results(-3.5), [-4.0, -4.0, -4.0, -3.0, -3.0, -4.0]);
+    assert_eq!(results(-0.2), [-0.0, -0.0, -1.0, -0.0, -0.0, -1.0]);
+    assert_eq!(modes.map(|mode| (-7_i32).round_with(mode)), [-7; 6]);
+    assert_eq!(RoundingMode::default(), RoundingMode::HalfAwayFromZero);
+}
+
+#[test]
+fn round_stochastic() {
+    assert_eq!(1.25_f64.round_stochastic(0.0), 1.0);
+    assert_eq!(1.25_f64.round_stochastic(0.74), 1.0);
+    assert_eq!(1.25_f64.round_stochastic(0.75), 2.0);
+    assert_eq!((-1.25_f64).round_stochastic(0.2), -2.0);
+    assert_eq!((-1.25_f64).round_stochastic(0.25), -1.0);
+    assert_eq!(3.0_f32.round_stochastic(0.99), 3.0);
+    assert_eq!((-3_i32).round_stochastic(0), -3);
+
+    // Unbiased on average for evenly spaced noise.
+    let sum = (0..100)
+        .map(|index| (-1.25_f64).round_stochastic(index as f64 / 100.0))
+        .sum::<f64>();
+    assert_eq!(sum / 100.0, -1.25);
+}
@@ -0,0 +1,80 @@
+use std::num::Saturating;
+
+use newnum::*;
+
+#[test]
+fn saturating_ops_on_signed_min() {
+    assert_eq!(SaturatingOps::saturating_neg(i8::MIN), i8::MAX);
+    assert_eq!(SaturatingOps::saturating_sub(i8::MIN, 1), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_add(i8::MIN, -1), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_mul(i8::MIN, -1), i8::MAX);
+    assert_eq!(SaturatingOps::saturating_mul(i8::MIN, 2), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_pow(-2_i8, 7), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_pow(-2_i8, 9), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_pow(-2_i8, 8), i8::MAX);
+}
+
+#[test]
+fn saturating_shl_signed() {
+    assert_eq!(SaturatingOps::saturating_shl(i8::MIN, 0), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(i8::MIN, 1), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(i8::MIN, 100), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(-1_i8, 7), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(-1_i8, 8), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(-3_i8, 5), -96);
+    assert_eq!(SaturatingOps::saturating_shl(-3_i8, 6), i8::MIN);
+    assert_eq!(SaturatingOps::saturating_shl(1_i8, 6), 64);
+    assert_eq!(SaturatingOps::saturating_shl(1_i8, 7), i8::MAX);
+    assert_eq!(SaturatingOps::saturating_shl(0_i8, 100), 0);
+}
+
+#[test]
+fn saturating_shl_unsigned() {
+    assert_eq!(SaturatingOps::saturating_shl(1_u8, 7), 128);
+    assert_eq!(SaturatingOps::saturating_shl(1_u8, 8), u8::MAX);
+    assert_eq!(SaturatingOps::saturating_shl(3_u8, 7), u8::MAX);
+    assert_eq!(SaturatingOps::saturating_shl(0_u128, 1000), 0);
+    assert_eq!(
+        SaturatingOps::saturating_shl(u128::MAX >> 1, 1),
+        u128::MAX - 1
+    );
+}
+
+#[test]
+fn saturating_neg_unsigned() {
+    assert_eq!(SaturatingOps::saturating_neg(0_u8), 0);
+    assert_eq!(SaturatingOps::saturating_neg(u8::MAX), 0);
+}
+
+#[test]
+fn std_saturating_is_num() {
+    fn sum<T: Num + Copy>(values: &[T]) -> T {
+        values.iter().fold(num!(0), |sum, value| sum + *value)
+    }
+
+    assert_eq!(
+        sum(&[Saturating(200_u8), Saturating(100)]),
+        Saturating(u8::MAX)
+    );
+    assert_eq!(
+        sum(&[Saturating(i8::MIN), Saturating(-1)]),
+        Saturating(i8::MIN)
+    );
+    assert_eq!(Pow::pow(Saturating(3_u8), 6), Saturating(u8::MAX));
+    assert_eq!(Positive::abs(Saturating(i8::MIN)), Saturating(i8::MAX));
+    assert_eq!(Saturating(i8::MIN).neg_abs(), Saturating(i8::MIN));
+}
+
+#[test]
+fn saturating_abs_diff_saturates_the_difference() {
+    assert_eq!(
+        Saturating(-100_i8).abs_diff(Saturating(100)),
+        Saturating(i8::MAX)
+    );
+    assert_eq!(
+        Saturating(i8::MAX).abs_diff(Saturating(i8::MIN)),
+        Saturating(i8::MAX)
+    );
+    assert_eq!(Saturating(-5_i8).abs_diff(Saturating(7)), Saturating(12));
+    assert_eq!(Saturating(3_u8).abs_diff(Saturating(250)), Saturating(247));
+}
@@ -0,0 +1,92 @@
+use newnum::{sign_wrappers::*, *};
+
+fn sign_err<T>(result: Result<T, InvalidSignError>) -> Option<Option<Sign>> {
+    result.err().map(|err| err.sign)
+}
+
+#[test]
+fn constructors_check_the_sign() {
+    assert_eq!(Pos::new(3_i32).map(Pos::get), Ok(3));
+    assert_eq!(sign_err(Pos::new(0_i32)), Some(Some(Sign::Zero)));
+    assert_eq!(sign_err(Pos::new(-0.0_f64)), Some(Some(Sign::Zero)));
+    assert_eq!(sign_err(Pos::new(f32::NAN)), Some(None));
+
+    assert_eq!(NonNeg::new(0_u8).map(NonNeg::get), Ok(0));
+    assert_eq!(sign_err(NonNeg::new(-1_i8)), Some(Some(Sign::Negative)));
+
+    assert_eq!(Neg::new(-2.5_f32).map(Neg::get), Ok(-2.5));
+    assert_eq!(sign_err(Neg::new(1_i64)), Some(Some(Sign::Positive)));
+    assert_eq!(sign_err(Neg::new(-f64::NAN)), Some(None));
+
+    assert_eq!(NonZero::new(-7_i16).map(NonZero::get), Ok(-7));
+    assert_eq!(sign_err(NonZero::new(0.0_f32)), Some(Some(Sign::Zero)));
+}
+
+#[test]
+fn float_literals() {
+    let neg: Neg<f64> = num!(-1.5);
+    assert_eq!(neg.get(), -1.5);
+    let neg: Neg<f32> = num!(-3);
+    assert_eq!(neg.get(), -3.0);
+    let pos: Pos<f64> = num!(2.5);
+    assert_eq!(pos.get(), 2.5);
+    let non_zero: NonZero<f32> = num!(-0.25);
+    assert_eq!(non_zero.get(), -0.25);
+
+    assert_eq!(num!(-1.0: f64), -1.0);
+    assert_eq!(num!(-7: f32), -7.0);
+    assert_eq!(num!(1e300: f64), 1e300);
+    assert_eq!(num!(-3.0e38: f32), -3.0e38);
+}
+
+#[test]
+fn operators_keep_or_drop_the_wrapper() {
+    let pos = Pos::new(4_i32).unwrap();
+    let neg = Neg::new(-3_i32).unwrap();
+    let non_neg = NonNeg::new(7_i32).unwrap();
+    let non_zero = NonZero::new(-2_i32).unwrap();
+
+    assert_eq!((pos + pos).get(), 8);
+    assert_eq!((pos * pos).get(), 16);
+    assert_eq!(pos - Pos::new(6).unwrap(), -2);
+    assert_eq!(pos / Pos::new(8).unwrap(), 0);
+    assert_eq!((-pos).get(), -4);
+
+    assert_eq!((non_neg / NonNeg::new(2).unwrap()).get(), 3);
+    assert_eq!((non_neg % NonNeg::new(4).unwrap()).get(), 3);
+    assert_eq!(non_neg - NonNeg::new(9).unwrap(), -2);
+
+    assert_eq!((neg + neg).get(), -6);
+    assert_eq!((neg * neg).get(), 9);
+    assert_eq!((-neg).get(), 3);
+    assert_eq!(neg - Neg::new(-5).unwrap(), 2);
+
+    assert_eq!((non_zero * non_zero).get(), 4);
+    assert_eq!((-non_zero).get(), 2);
+    assert_eq!(non_zero + NonZero::new(2).unwrap(), 0);
+
+    let mut sum = pos;
+    sum += pos;
+    sum *= Pos::new(2).unwrap();
+    assert_eq!(sum.get(), 16);
+}
+
+#[test]
+#[should_panic]
+fn pos_overflow_to_non_positive_panics() {
+    let _ = Pos::new(i8::MAX).unwrap() + Pos::new(1).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn pos_float_underflow_panics() {
+    let tiny = Pos::new(f64::MIN_POSITIVE).unwrap();
+    let _ = tiny * tiny;
+}
+
+#[test]
+#[should_panic]
+fn non_neg_nan_division_panics() {
+    let zero = NonNeg::new(0.0_f32).unwrap();
+    let _ = zero / zero;
+}
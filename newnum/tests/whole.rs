@@ -0,0 +1,53 @@
+use newnum::*;
+
+#[test]
+fn round_to() {
+    assert_eq!(2.5_f32.round_to::<u8>(), Some(3));
+    assert_eq!((-2.5_f64).round_to::<i8>(), Some(-3));
+    assert_eq!(255.4_f32.round_to::<u8>(), Some(255));
+    assert_eq!(255.5_f32.round_to::<u8>(), None);
+    assert_eq!((-0.4_f64).round_to::<u8>(), Some(0));
+    assert_eq!((-0.6_f64).round_to::<u8>(), None);
+    assert_eq!(f32::NAN.round_to::<i32>(), None);
+    assert_eq!(f64::INFINITY.round_to::<u64>(), None);
+    assert_eq!(300_u32.round_to::<u8>(), None);
+    assert_eq!((-3_i32).round_to::<i8>(), Some(-3));
+}
+
+#[test]
+fn floor_ceil_trunc_to() {
+    assert_eq!((-1.5_f32).floor_to::<i8>(), Some(-2));
+    assert_eq!((-1.5_f32).ceil_to::<i8>(), Some(-1));
+    assert_eq!((-1.5_f32).trunc_to::<i8>(), Some(-1));
+    assert_eq!((-0.5_f32).floor_to::<u8>(), None);
+    assert_eq!((-0.5_f32).ceil_to::<u8>(), Some(0));
+    assert_eq!((-0.5_f32).trunc_to::<u8>(), Some(0));
+    assert_eq!(127.5_f64.floor_to::<i8>(), Some(127));
+    assert_eq!(127.5_f64.ceil_to::<i8>(), None);
+}
+
+#[test]
+fn saturating_round_to_non_finite() {
+    assert_eq!(f32::NAN.saturating_round_to::<i32>(), 0);
+    assert_eq!((-f64::NAN).saturating_round_to::<u8>(), 0);
+    assert_eq!(f32::INFINITY.saturating_round_to::<i32>(), i32::MAX);
+    assert_eq!(f64::NEG_INFINITY.saturating_round_to::<i64>(), i64::MIN);
+    assert_eq!(f64::NEG_INFINITY.saturating_round_to::<u16>(), 0);
+    assert_eq!(f32::INFINITY.saturating_round_to::<u128>(), u128::MAX);
+
+    assert_eq!(f32::NAN.saturating_floor_to::<i8>(), 0);
+    assert_eq!(f32::INFINITY.saturating_ceil_to::<i8>(), i8::MAX);
+    assert_eq!(f32::NEG_INFINITY.saturating_trunc_to::<i8>(), i8::MIN);
+}
+
+#[test]
+fn saturating_round_to_bounds() {
+    assert_eq!(254.5_f32.saturating_round_to::<u8>(), 255);
+    assert_eq!(1e10_f64.saturating_round_to::<i32>(), i32::MAX);
+    assert_eq!((-0.6_f32).saturating_round_to::<u8>(), 0);
+    assert_eq!((-1.5_f32).saturating_floor_to::<i8>(), -2);
+    assert_eq!(2.1_f64.saturating_ceil_to::<u8>(), 3);
+    assert_eq!((-2.9_f64).saturating_trunc_to::<i8>(), -2);
+    assert_eq!(i32::MIN.saturating_round_to::<i16>(), i16::MIN);
+    assert_eq!(u64::MAX.saturating_round_to::<i64>(), i64::MAX);
+}
@@ -0,0 +1,96 @@
+use newnum::*;
+
+/// Reads a signed `(low, high)` pair as `high * 2^8 + low`, where `low` is read as unsigned.
+fn signed_value(low: i8, high: i8) -> i32 {
+    high as i32 * 256 + low as u8 as i32
+}
+
+#[test]
+fn signed_widening_and_carrying_mul_are_exact() {
+    for lhs in i8::MIN..=i8::MAX {
+        for rhs in i8::MIN..=i8::MAX {
+            let (low, high) = WideningMul::widening_mul(lhs, rhs);
+            assert_eq!(signed_value(low, high), lhs as i32 * rhs as i32);
+
+            for carry in [i8::MIN, -1, 0, 1, i8::MAX] {
+                let (low, high) = WideningMul::carrying_mul(lhs, rhs, carry);
+                assert_eq!(
+                    signed_value(low, high),
+                    lhs as i32 * rhs as i32 + carry as i32,
+                    "{lhs} * {rhs} + {carry}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn signed_widening_mul_examples() {
+    assert_eq!(WideningMul::widening_mul(-1_i8, 1), (-1, -1));
+    assert_eq!(WideningMul::widening_mul(i8::MIN, i8::MIN), (0, 64));
+    assert_eq!(WideningMul::widening_mul(i8::MIN, -1), (i8::MIN, 0));
+    assert_eq!(WideningMul::carrying_mul(i64::MIN, 1, -1), (i64::MAX, -1));
+}
+
+#[test]
+fn signed_carrying_add_and_borrowing_sub_report_overflow() {
+    for lhs in i8::MIN..=i8::MAX {
+        for rhs in i8::MIN..=i8::MAX {
+            for carry in [false, true] {
+                let exact = lhs as i16 + rhs as i16 + carry as i16;
+                assert_eq!(
+                    WideningMul::carrying_add(lhs, rhs, carry),
+                    (exact as i8, i8::try_from(exact).is_err()),
+                    "{lhs} + {rhs} + {carry}"
+                );
+
+                let exact = lhs as i16 - rhs as i16 - carry as i16;
+                assert_eq!(
+                    WideningMul::borrowing_sub(lhs, rhs, carry),
+                    (exact as i8, i8::try_from(exact).is_err()),
+                    "{lhs} - {rhs} - {carry}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn signed_carry_examples() {
+    assert_eq!(WideningMul::carrying_add(i8::MAX, 0, true), (i8::MIN, true));
+    // `MIN + -1` overflows and `+ 1` overflows back, so the sum fits.
+    assert_eq!(
+        WideningMul::carrying_add(i8::MIN, -1, true),
+        (i8::MIN, false)
+    );
+    assert_eq!(
+        WideningMul::borrowing_sub(i8::MIN, 0, true),
+        (i8::MAX, true)
+    );
+    assert_eq!(
+        WideningMul::borrowing_sub(i8::MAX, -1, true),
+        (i8::MAX, false)
+    );
+}
+
+#[test]
+fn unsigned_limbs() {
+    assert_eq!(
+        WideningMul::widening_mul(u64::MAX, u64::MAX),
+        (1, u64::MAX - 1)
+    );
+    assert_eq!(
+        WideningMul::carrying_mul(u64::MAX, u64::MAX, u64::MAX),
+        (0, u64::MAX)
+    );
+    assert_eq!(
+        WideningMul::carrying_add(u8::MAX, u8::MAX, true),
+        (u8::MAX, true)
+    );
+    assert_eq!(WideningMul::borrowing_sub(0_u8, u8::MAX, true), (0, true));
+    assert_eq!(WideningMul::borrowing_sub(0_u8, 0, true), (u8::MAX, true));
+
+    assert_eq!(WideningMul::widen(200_u8), 200_u16);
+    assert_eq!(u8::narrow(0x1234), 0x34);
+    assert_eq!(i8::narrow(-129), 127);
+}
@@ -0,0 +1,56 @@
+use std::num::Wrapping;
+
+use newnum::*;
+
+#[test]
+fn wrapping_ops_on_signed_min() {
+    assert_eq!(WrappingOps::wrapping_neg(i8::MIN), i8::MIN);
+    assert_eq!(WrappingOps::wrapping_sub(i8::MIN, 1), i8::MAX);
+    assert_eq!(WrappingOps::wrapping_add(i8::MIN, -1), i8::MAX);
+    assert_eq!(WrappingOps::wrapping_mul(i8::MIN, -1), i8::MIN);
+    assert_eq!(WrappingOps::wrapping_mul(i64::MIN, 2), 0);
+    assert_eq!(WrappingOps::wrapping_pow(-2_i8, 7), i8::MIN);
+    assert_eq!(WrappingOps::wrapping_pow(-2_i8, 8), 0);
+}
+
+#[test]
+fn wrapping_shl_masks_the_shift() {
+    assert_eq!(WrappingOps::wrapping_shl(i8::MIN, 1), 0);
+    assert_eq!(WrappingOps::wrapping_shl(i8::MIN, 8), i8::MIN);
+    assert_eq!(WrappingOps::wrapping_shl(1_u32, 33), 2);
+    assert_eq!(WrappingOps::wrapping_shl(-1_i16, 15), i16::MIN);
+}
+
+#[test]
+fn wrapping_neg_unsigned() {
+    assert_eq!(WrappingOps::wrapping_neg(0_u8), 0);
+    assert_eq!(WrappingOps::wrapping_neg(1_u8), u8::MAX);
+    assert_eq!(WrappingOps::wrapping_neg(u128::MAX), 1);
+}
+
+#[test]
+fn std_wrapping_is_num() {
+    fn sum<T: Num + Copy>(values: &[T]) -> T {
+        values.iter().fold(num!(0), |sum, value| sum + *value)
+    }
+
+    assert_eq!(sum(&[Wrapping(200_u8), Wrapping(100)]), Wrapping(44));
+    assert_eq!(sum(&[Wrapping(i8::MIN), Wrapping(-1)]), Wrapping(i8::MAX));
+    assert_eq!(Pow::pow(Wrapping(3_u8), 6), Wrapping(217));
+    assert_eq!(Positive::abs(Wrapping(i8::MIN)), Wrapping(i8::MIN));
+}
+
+#[test]
+fn wrapping_abs_diff_wraps_the_difference() {
+    assert_eq!(
+        Wrapping(-100_i8).abs_diff(Wrapping(100)),
+        Wrapping(200_u8 as i8)
+    );
+    assert_eq!(
+        Wrapping(100_i8).abs_diff(Wrapping(-100)),
+        Wrapping(200_u8 as i8)
+    );
+    assert_eq!(Wrapping(i8::MIN).abs_diff(Wrapping(i8::MAX)), Wrapping(-1));
+    assert_eq!(Wrapping(-5_i8).abs_diff(Wrapping(7)), Wrapping(12));
+    assert_eq!(Wrapping(3_u8).abs_diff(Wrapping(250)), Wrapping(247));
+}
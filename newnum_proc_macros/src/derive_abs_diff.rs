@@ -18,6 +18,8 @@ pub fn abs_diff_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Toke
 
     quote! {
         impl #impl_generics ::newnum::AbsDiff for #type_ident #ty_generics #where_clause {
+            type Output = Self;
+
             fn abs_diff(self, rhs: Self) -> Self::Output {
                 #output
             }
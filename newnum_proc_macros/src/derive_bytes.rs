@@ -0,0 +1,232 @@
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, GenericParam};
+
+use crate::util::derive_split_generics;
+
+pub fn to_bytes_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match bytes_fields(&input, "ToBytes") {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "ToBytes");
+
+    if has_type_or_const_params(&input) {
+        let bytes_type = fields
+            .iter()
+            .rev()
+            .fold(quote! { [u8; 0] }, |bytes_type, field| {
+                let field_type = &field.ty;
+
+                quote_spanned! {
+                    field_type.span() =>
+                    ::newnum::ConcatBytes<<#field_type as ::newnum::ToBytes>::Bytes, #bytes_type>
+                }
+            });
+
+        let to_bytes_fns = ["to_le_bytes", "to_be_bytes", "to_ne_bytes"].map(|fn_ident| {
+            let fn_ident = format_ident!("{fn_ident}");
+
+            let members = fields.members().collect::<Vec<_>>();
+            let bytes = members.iter().zip(fields.iter()).rev().fold(
+                quote! { [] },
+                |bytes, (member, field)| {
+                    let field_type = &field.ty;
+
+                    quote_spanned! {
+                        field_type.span() =>
+                        ::newnum::ConcatBytes(<#field_type as ::newnum::ToBytes>::#fn_ident(self.#member), #bytes)
+                    }
+                },
+            );
+
+            quote! {
+                fn #fn_ident(self) -> Self::Bytes {
+                    #bytes
+                }
+            }
+        });
+
+        return quote! {
+            impl #impl_generics ::newnum::ToBytes for #type_ident #ty_generics #where_clause {
+                type Bytes = #bytes_type;
+
+                #(#to_bytes_fns)*
+            }
+        }
+        .into();
+    }
+
+    let bytes_len = fields.iter().map(|field| {
+        let field_type = &field.ty;
+
+        quote_spanned! {
+            field_type.span() =>
+            ::core::mem::size_of::<<#field_type as ::newnum::ToBytes>::Bytes>()
+        }
+    });
+
+    let to_bytes_fns = ["to_le_bytes", "to_be_bytes", "to_ne_bytes"].map(|fn_ident| {
+        let fn_ident = format_ident!("{fn_ident}");
+
+        // Each field's bytes are copied right after the previous field's bytes.
+        let field_copies = fields.members().zip(fields.iter()).map(|(member, field)| {
+            let field_type = &field.ty;
+
+            quote_spanned! {
+                field_type.span() =>
+                let field_bytes = <#field_type as ::newnum::ToBytes>::#fn_ident(self.#member);
+                let field_bytes = ::core::convert::AsRef::<[u8]>::as_ref(&field_bytes);
+                bytes[offset..offset + field_bytes.len()].copy_from_slice(field_bytes);
+                offset += field_bytes.len();
+            }
+        });
+
+        quote! {
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn #fn_ident(self) -> Self::Bytes {
+                let mut bytes = [0; ::core::mem::size_of::<Self::Bytes>()];
+                let mut offset = 0;
+
+                #(#field_copies)*
+
+                bytes
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::ToBytes for #type_ident #ty_generics #where_clause {
+            type Bytes = [u8; 0 #(+ #bytes_len)*];
+
+            #(#to_bytes_fns)*
+        }
+    }
+    .into()
+}
+
+pub fn from_bytes_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match bytes_fields(&input, "FromBytes") {
+        Ok(fields) => fields,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "FromBytes");
+
+    if has_type_or_const_params(&input) {
+        let from_bytes_fns = ["from_le_bytes", "from_be_bytes", "from_ne_bytes"].map(|fn_ident| {
+            let fn_ident = format_ident!("{fn_ident}");
+
+            // Each field takes the first bytes of the `ConcatBytes` chain, and leaves the rest for the next fields.
+            let field_bytes_idents = (0..fields.len())
+                .map(|index| format_ident!("field_bytes_{index}"))
+                .collect::<Vec<_>>();
+            let field_values =
+                fields
+                    .iter()
+                    .zip(&field_bytes_idents)
+                    .map(|(field, field_bytes)| {
+                        let field_type = &field.ty;
+
+                        quote_spanned! {
+                            field_type.span() =>
+                            <#field_type as ::newnum::FromBytes>::#fn_ident(#field_bytes)
+                        }
+                    });
+            let members = fields.members();
+
+            quote! {
+                #[allow(unused_variables)]
+                fn #fn_ident(bytes: Self::Bytes) -> Self {
+                    #(
+                        let ::newnum::ConcatBytes(#field_bytes_idents, bytes) = bytes;
+                    )*
+
+                    Self {#(
+                        #members: #field_values,
+                    )*}
+                }
+            }
+        });
+
+        return quote! {
+            impl #impl_generics ::newnum::FromBytes for #type_ident #ty_generics #where_clause {
+                #(#from_bytes_fns)*
+            }
+        }
+        .into();
+    }
+
+    let from_bytes_fns = ["from_le_bytes", "from_be_bytes", "from_ne_bytes"].map(|fn_ident| {
+        let fn_ident = format_ident!("{fn_ident}");
+
+        // Each field is read from the bytes right after the previous field's bytes.
+        let field_values = fields.iter().map(|field| {
+            let field_type = &field.ty;
+
+            quote_spanned! {
+                field_type.span() =>
+                {
+                    let field_bytes_len = ::core::mem::size_of::<<#field_type as ::newnum::ToBytes>::Bytes>();
+                    let field_bytes = match ::core::convert::TryFrom::try_from(&bytes[offset..offset + field_bytes_len]) {
+                        ::core::result::Result::Ok(field_bytes) => field_bytes,
+                        ::core::result::Result::Err(_) => unreachable!(),
+                    };
+                    offset += field_bytes_len;
+
+                    <#field_type as ::newnum::FromBytes>::#fn_ident(field_bytes)
+                }
+            }
+        });
+        let members = fields.members();
+
+        quote! {
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            fn #fn_ident(bytes: Self::Bytes) -> Self {
+                let mut offset = 0;
+
+                Self {#(
+                    #members: #field_values,
+                )*}
+            }
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::FromBytes for #type_ident #ty_generics #where_clause {
+            #(#from_bytes_fns)*
+        }
+    }
+    .into()
+}
+
+fn bytes_fields<'a>(input: &'a DeriveInput, trait_ident: &str) -> Result<&'a Fields, Error> {
+    match &input.data {
+        Data::Struct(data) => Ok(&data.fields),
+        Data::Enum(_) => Err(Error::new(
+            Span::call_site(),
+            format!("`{trait_ident}` cannot be derived for enums"),
+        )),
+        Data::Union(_) => Err(Error::new(
+            Span::call_site(),
+            format!("`{trait_ident}` cannot be derived for unions"),
+        )),
+    }
+}
+
+/// `Bytes` is an array with the total length of the fields' bytes,
+/// unless it depends on generic parameters where it is a `ConcatBytes` chain instead.
+fn has_type_or_const_params(input: &DeriveInput) -> bool {
+    input
+        .generics
+        .params
+        .iter()
+        .any(|param| !matches!(param, GenericParam::Lifetime(_)))
+}
@@ -0,0 +1,109 @@
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+
+use crate::util::{derive_map_fields, derive_rhs_map_fields, derive_split_generics};
+
+pub fn checked_add_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    checked_binary_derive_macro(input, "CheckedAdd", "checked_add")
+}
+
+pub fn checked_sub_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    checked_binary_derive_macro(input, "CheckedSub", "checked_sub")
+}
+
+pub fn checked_mul_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    checked_binary_derive_macro(input, "CheckedMul", "checked_mul")
+}
+
+pub fn checked_div_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    checked_binary_derive_macro(input, "CheckedDiv", "checked_div")
+}
+
+pub fn checked_rem_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    checked_binary_derive_macro(input, "CheckedRem", "checked_rem")
+}
+
+pub fn checked_neg_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "CheckedNeg");
+
+    let output = derive_map_fields(&input, "CheckedNeg", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::CheckedNeg>::checked_neg(#field)?
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::CheckedNeg for #type_ident #ty_generics #where_clause {
+            fn checked_neg(self) -> Option<Self> {
+                Some(#output)
+            }
+        }
+    }
+    .into()
+}
+
+pub fn checked_shl_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "CheckedShl");
+
+    let output = derive_map_fields(&input, "CheckedShl", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::CheckedShl>::checked_shl(#field, rhs)?
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::CheckedShl for #type_ident #ty_generics #where_clause {
+            fn checked_shl(self, rhs: u32) -> Option<Self> {
+                Some(#output)
+            }
+        }
+    }
+    .into()
+}
+
+fn checked_binary_derive_macro(
+    input: proc_macro::TokenStream,
+    trait_ident: &str,
+    fn_ident: &str,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, trait_ident);
+
+    let trait_path = {
+        let trait_ident = format_ident!("{trait_ident}");
+
+        quote! { ::newnum::#trait_ident }
+    };
+    let fn_ident = format_ident!("{fn_ident}");
+
+    let output = derive_rhs_map_fields(
+        &input,
+        trait_ident,
+        "rhs",
+        |field, rhs_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as #trait_path>::#fn_ident(#field, #rhs_field)?
+            }
+        },
+    );
+
+    quote! {
+        impl #impl_generics #trait_path for #type_ident #ty_generics #where_clause {
+            fn #fn_ident(self, rhs: Self) -> Option<Self> {
+                Some(#output)
+            }
+        }
+    }
+    .into()
+}
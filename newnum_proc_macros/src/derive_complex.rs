@@ -0,0 +1,86 @@
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+
+use crate::util::{derive_map_fields, derive_split_generics};
+
+pub fn conj_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Conj");
+
+    let conj_output = derive_map_fields(&input, "Conj", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Conj>::conj(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Conj for #type_ident #ty_generics #where_clause {
+            fn conj(self) -> Self {
+                #conj_output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn norm_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Norm");
+
+    let norm_output = derive_map_fields(&input, "Norm", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Norm>::norm(#field)
+        }
+    });
+    let norm_sqr_output = derive_map_fields(&input, "Norm", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Norm>::norm_sqr(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Norm for #type_ident #ty_generics #where_clause {
+            type Output = Self;
+
+            fn norm(self) -> Self::Output {
+                #norm_output
+            }
+            fn norm_sqr(self) -> Self::Output {
+                #norm_sqr_output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn arg_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Arg");
+
+    let arg_output = derive_map_fields(&input, "Arg", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Arg>::arg(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Arg for #type_ident #ty_generics #where_clause {
+            type Output = Self;
+
+            fn arg(self) -> Self::Output {
+                #arg_output
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,127 @@
+use proc_macro2::{Literal, Span};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error};
+
+use crate::util::{derive_rhs_map_fields, derive_split_generics};
+
+pub fn div_euclid_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    div_binary_derive_macro(input, "DivEuclid", "div_euclid")
+}
+
+pub fn rem_euclid_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    div_binary_derive_macro(input, "RemEuclid", "rem_euclid")
+}
+
+pub fn div_floor_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    div_binary_derive_macro(input, "DivFloor", "div_floor")
+}
+
+pub fn div_ceil_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    div_binary_derive_macro(input, "DivCeil", "div_ceil")
+}
+
+pub fn div_rem_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "DivRem");
+
+    // Each field's `div_rem` is stored in temporaries,
+    // so fields don't need to be `Copy` to build both the quotient and the remainder.
+    let output = match &input.data {
+        Data::Struct(data) => {
+            let quotients = (0..data.fields.len())
+                .map(|i| format_ident!("quotient_{i}"))
+                .collect::<Vec<_>>();
+            let remainders = (0..data.fields.len())
+                .map(|i| format_ident!("remainder_{i}"))
+                .collect::<Vec<_>>();
+
+            let field_div_rems = data.fields.iter().enumerate().map(|(i, field)| {
+                let member = match &field.ident {
+                    Some(ident) => quote! { #ident },
+                    None => {
+                        let i = Literal::usize_unsuffixed(i);
+                        quote! { #i }
+                    }
+                };
+                let field_type = &field.ty;
+                let quotient = &quotients[i];
+                let remainder = &remainders[i];
+
+                quote_spanned! {
+                    field_type.span() =>
+                    let (#quotient, #remainder) = <#field_type as ::newnum::DivRem>::div_rem(self.#member, rhs.#member);
+                }
+            });
+
+            let members = data.fields.members().collect::<Vec<_>>();
+
+            quote! {
+                #(#field_div_rems)*
+
+                (
+                    Self {#(
+                        #members: #quotients,
+                    )*},
+                    Self {#(
+                        #members: #remainders,
+                    )*},
+                )
+            }
+        }
+        Data::Enum(_) => {
+            Error::new(Span::call_site(), "`DivRem` cannot be derived for enums").to_compile_error()
+        }
+        Data::Union(_) => Error::new(Span::call_site(), "`DivRem` cannot be derived for unions")
+            .to_compile_error(),
+    };
+
+    quote! {
+        impl #impl_generics ::newnum::DivRem for #type_ident #ty_generics #where_clause {
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                #output
+            }
+        }
+    }
+    .into()
+}
+
+fn div_binary_derive_macro(
+    input: proc_macro::TokenStream,
+    trait_ident: &str,
+    fn_ident: &str,
+) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, trait_ident);
+
+    let trait_path = {
+        let trait_ident = format_ident!("{trait_ident}");
+
+        quote! { ::newnum::#trait_ident }
+    };
+    let fn_ident = format_ident!("{fn_ident}");
+
+    let output = derive_rhs_map_fields(
+        &input,
+        trait_ident,
+        "rhs",
+        |field, rhs_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as #trait_path>::#fn_ident(#field, #rhs_field)
+            }
+        },
+    );
+
+    quote! {
+        impl #impl_generics #trait_path for #type_ident #ty_generics #where_clause {
+            fn #fn_ident(self, rhs: Self) -> Self {
+                #output
+            }
+        }
+    }
+    .into()
+}
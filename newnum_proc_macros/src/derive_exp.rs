@@ -0,0 +1,45 @@
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+
+use crate::util::{derive_map_fields, derive_split_generics};
+
+pub fn exp_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Exp");
+
+    let exp_output = derive_map_fields(&input, "Exp", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Exp>::exp(#field)
+        }
+    });
+    let exp2_output = derive_map_fields(&input, "Exp", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Exp>::exp2(#field)
+        }
+    });
+    let exp_m1_output = derive_map_fields(&input, "Exp", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Exp>::exp_m1(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Exp for #type_ident #ty_generics #where_clause {
+            fn exp(self) -> Self {
+                #exp_output
+            }
+            fn exp2(self) -> Self {
+                #exp2_output
+            }
+            fn exp_m1(self) -> Self {
+                #exp_m1_output
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,96 @@
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+
+use crate::util::{derive_eval_fields, derive_map_single_field_ref, derive_split_generics};
+
+pub fn float_class_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "FloatClass");
+
+    let bool_fns = [
+        "is_nan",
+        "is_finite",
+        "is_infinite",
+        "is_normal",
+        "is_subnormal",
+        "is_sign_positive",
+        "is_sign_negative",
+    ]
+    .map(|fn_ident| {
+        let fn_ident = format_ident!("{fn_ident}");
+
+        let output = derive_map_single_field_ref(&input, "FloatClass", |field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::FloatClass>::#fn_ident(#field)
+            }
+        });
+
+        quote! {
+            fn #fn_ident(&self) -> Self::FloatBoolMapped {
+                #output
+            }
+        }
+    });
+
+    let classify_output = derive_map_single_field_ref(&input, "FloatClass", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::FloatClass>::classify(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::FloatClass for #type_ident #ty_generics #where_clause {
+            type FloatBoolMapped = bool;
+            type CategoryMapped = ::std::num::FpCategory;
+
+            #(#bool_fns)*
+
+            fn classify(&self) -> Self::CategoryMapped {
+                #classify_output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn float_consts_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "FloatConsts");
+
+    let consts = [
+        "NAN",
+        "INFINITY",
+        "NEG_INFINITY",
+        "EPSILON",
+        "MIN_POSITIVE",
+        "MAX",
+        "MIN",
+    ]
+    .map(|const_ident| {
+        let const_ident = format_ident!("{const_ident}");
+
+        let output = derive_eval_fields(&input, "FloatConsts", "float_consts", |field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::FloatConsts>::#const_ident
+            }
+        });
+
+        quote! {
+            const #const_ident: Self = #output;
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::FloatConsts for #type_ident #ty_generics #where_clause {
+            #(#consts)*
+        }
+    }
+    .into()
+}
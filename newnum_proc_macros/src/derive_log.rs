@@ -0,0 +1,170 @@
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields};
+
+use crate::util::{
+    derive_map_fields, derive_map_single_field, derive_rhs_map_fields, derive_split_generics,
+};
+
+pub fn log_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Log");
+
+    let ln_output = derive_map_fields(&input, "Log", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Log>::ln(#field)
+        }
+    });
+    let log2_output = derive_map_fields(&input, "Log", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Log>::log2(#field)
+        }
+    });
+    let log10_output = derive_map_fields(&input, "Log", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Log>::log10(#field)
+        }
+    });
+    let log_output =
+        derive_rhs_map_fields(&input, "Log", "base", |field, base_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::Log>::log(#field, #base_field)
+            }
+        });
+    let ln_1p_output = derive_map_fields(&input, "Log", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Log>::ln_1p(#field)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Log for #type_ident #ty_generics #where_clause {
+            fn ln(self) -> Self {
+                #ln_output
+            }
+            fn log2(self) -> Self {
+                #log2_output
+            }
+            fn log10(self) -> Self {
+                #log10_output
+            }
+            fn log(self, base: Self) -> Self {
+                #log_output
+            }
+            fn ln_1p(self) -> Self {
+                #ln_1p_output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn ilog_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "ILog");
+
+    let ilog2_output = derive_map_single_field(&input, "ILog", |field, field_type| {
+        quote! {
+            <#field_type as ::newnum::ILog>::ilog2(#field)
+        }
+    });
+    let ilog10_output = derive_map_single_field(&input, "ILog", |field, field_type| {
+        quote! {
+            <#field_type as ::newnum::ILog>::ilog10(#field)
+        }
+    });
+    let checked_ilog2_output = derive_map_single_field(&input, "ILog", |field, field_type| {
+        quote! {
+            <#field_type as ::newnum::ILog>::checked_ilog2(#field)
+        }
+    });
+    let checked_ilog10_output = derive_map_single_field(&input, "ILog", |field, field_type| {
+        quote! {
+            <#field_type as ::newnum::ILog>::checked_ilog10(#field)
+        }
+    });
+    // `checked_ilog` maps both `self` and `base`, so the variants of an enum have to match.
+    let checked_ilog_output = match &input.data {
+        Data::Struct(data) if data.fields.len() == 1 => {
+            let field_type = &data.fields.iter().next().unwrap().ty;
+            let member = data.fields.members().next().unwrap();
+
+            quote! {
+                <#field_type as ::newnum::ILog>::checked_ilog(self.#member, base.#member)
+            }
+        }
+        Data::Enum(data)
+            if data
+                .variants
+                .iter()
+                .all(|variant| variant.fields.len() == 1) =>
+        {
+            let variant_arms = data.variants.iter().map(|variant| {
+                let variant_ident = &variant.ident;
+                let field_type = &variant.fields.iter().next().unwrap().ty;
+
+                let (field_pattern, base_pattern) = match &variant.fields {
+                    Fields::Named(fields) => {
+                        let field_ident = fields.named.iter().next().unwrap().ident.as_ref();
+
+                        (
+                            quote! { { #field_ident: field } },
+                            quote! { { #field_ident: base_field } },
+                        )
+                    }
+                    Fields::Unnamed(_) => (quote! { (field) }, quote! { (base_field) }),
+                    Fields::Unit => unreachable!(),
+                };
+
+                quote! {
+                    (Self::#variant_ident #field_pattern, Self::#variant_ident #base_pattern) => {
+                        <#field_type as ::newnum::ILog>::checked_ilog(field, base_field)
+                    }
+                }
+            });
+
+            quote! {
+                #[allow(unreachable_patterns)]
+                match (self, base) {
+                    #(#variant_arms)*
+                    _ => None,
+                }
+            }
+        }
+        // the other outputs already contain the compile error.
+        _ => quote! { None },
+    };
+
+    quote! {
+        impl #impl_generics ::newnum::ILog for #type_ident #ty_generics #where_clause {
+            fn ilog2(self) -> u32 {
+                #ilog2_output
+            }
+            fn ilog10(self) -> u32 {
+                #ilog10_output
+            }
+            fn ilog(self, base: Self) -> u32 {
+                <Self as ::newnum::ILog>::checked_ilog(self, base)
+                    .expect("argument of integer logarithm must be positive and base must be at least 2")
+            }
+            fn checked_ilog2(self) -> Option<u32> {
+                #checked_ilog2_output
+            }
+            fn checked_ilog10(self) -> Option<u32> {
+                #checked_ilog10_output
+            }
+            fn checked_ilog(self, base: Self) -> Option<u32> {
+                #checked_ilog_output
+            }
+        }
+    }
+    .into()
+}
@@ -0,0 +1,48 @@
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
+
+use crate::util::{derive_eval_fields, derive_split_generics};
+
+pub fn one_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "One");
+
+    let output = derive_eval_fields(&input, "One", "one", |field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::One>::one()
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::One for #type_ident #ty_generics #where_clause {
+            fn one() -> Self {
+                #output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn const_one_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "ConstOne");
+
+    let output = derive_eval_fields(&input, "ConstOne", "one", |field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::ConstOne>::ONE
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::ConstOne for #type_ident #ty_generics #where_clause {
+            const ONE: Self = #output;
+        }
+    }
+    .into()
+}
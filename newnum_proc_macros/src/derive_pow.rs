@@ -0,0 +1,51 @@
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, parse_quote, spanned::Spanned, Data, DeriveInput, WhereClause};
+
+use crate::util::{derive_map_fields, derive_split_generics};
+
+pub fn pow_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (_, ty_generics, where_clause) = derive_split_generics(&input, "Pow");
+
+    let mut generics = input.generics.clone();
+    generics.params.push(parse_quote! { PowExp: Copy });
+    let (impl_generics, _, _) = generics.split_for_impl();
+
+    let field_types = match &input.data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| &field.ty))
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+
+    let mut where_clause = where_clause.unwrap_or_else(|| WhereClause {
+        where_token: Default::default(),
+        predicates: Default::default(),
+    });
+    where_clause
+        .predicates
+        .extend(field_types.into_iter().map::<syn::WherePredicate, _>(
+            |field_type| parse_quote! { #field_type: ::newnum::Pow<PowExp> },
+        ));
+
+    let pow_output = derive_map_fields(&input, "Pow", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Pow<PowExp>>::pow(#field, exp)
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::Pow<PowExp> for #type_ident #ty_generics #where_clause {
+            fn pow(self, exp: PowExp) -> Self {
+                #pow_output
+            }
+        }
+    }
+    .into()
+}
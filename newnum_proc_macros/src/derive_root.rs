@@ -1,7 +1,7 @@
 use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
 
-use crate::util::{derive_map_fields, derive_split_generics};
+use crate::util::{derive_map_fields, derive_rhs_map_fields, derive_split_generics};
 
 pub fn trunc_root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -21,6 +21,18 @@ pub fn trunc_root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::To
             <#field_type as ::newnum::TruncRoot>::trunc_cbrt(#field)
         }
     });
+    let trunc_nth_root_output = derive_map_fields(&input, "TruncRoot", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::TruncRoot>::trunc_nth_root(#field, n)
+        }
+    });
+    let checked_exact_sqrt_output = derive_map_fields(&input, "TruncRoot", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::TruncRoot>::checked_exact_sqrt(#field)?
+        }
+    });
 
     quote! {
         impl #impl_generics ::newnum::TruncRoot for #type_ident #ty_generics #where_clause {
@@ -30,6 +42,12 @@ pub fn trunc_root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::To
             fn trunc_cbrt(self) -> Self {
                 #trunc_cbrt_output
             }
+            fn trunc_nth_root(self, n: u32) -> Self {
+                #trunc_nth_root_output
+            }
+            fn checked_exact_sqrt(self) -> Option<Self> {
+                Some(#checked_exact_sqrt_output)
+            }
         }
     }
     .into()
@@ -53,6 +71,18 @@ pub fn root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
             <#field_type as ::newnum::Root>::cbrt(#field)
         }
     });
+    let nth_root_output = derive_map_fields(&input, "Root", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Root>::nth_root(#field, n)
+        }
+    });
+    let rsqrt_output = derive_map_fields(&input, "Root", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Root>::rsqrt(#field)
+        }
+    });
 
     quote! {
         impl #impl_generics ::newnum::Root for #type_ident #ty_generics #where_clause {
@@ -62,6 +92,40 @@ pub fn root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
             fn cbrt(self) -> Self {
                 #cbrt_output
             }
+            fn nth_root(self, n: u32) -> Self {
+                #nth_root_output
+            }
+            fn rsqrt(self) -> Self {
+                #rsqrt_output
+            }
+        }
+    }
+    .into()
+}
+
+pub fn hypot_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Hypot");
+
+    let output = derive_rhs_map_fields(
+        &input,
+        "Hypot",
+        "other",
+        |field, other_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::Hypot>::hypot(#field, #other_field)
+            }
+        },
+    );
+
+    quote! {
+        impl #impl_generics ::newnum::Hypot for #type_ident #ty_generics #where_clause {
+            fn hypot(self, other: Self) -> Self {
+                #output
+            }
         }
     }
     .into()
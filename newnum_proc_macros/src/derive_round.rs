@@ -1,7 +1,7 @@
 use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
 
-use crate::util::{derive_map_fields, derive_split_generics};
+use crate::util::{derive_map_fields, derive_rhs_map_fields, derive_split_generics};
 
 pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -15,6 +15,12 @@ pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
             <#field_type as ::newnum::Round>::round(#field)
         }
     });
+    let round_ties_even_output = derive_map_fields(&input, "Round", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Round>::round_ties_even(#field)
+        }
+    });
     let floor_output = derive_map_fields(&input, "Round", |field, field_type| {
         quote_spanned! {
             field_type.span() =>
@@ -45,12 +51,28 @@ pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
             <#field_type as ::newnum::Round>::fract(#field)
         }
     });
+    let round_to_multiple_output =
+        derive_rhs_map_fields(&input, "Round", "step", |field, step_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::Round>::round_to_multiple(#field, #step_field)
+            }
+        });
+    let round_to_decimals_output = derive_map_fields(&input, "Round", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Round>::round_to_decimals(#field, decimals)
+        }
+    });
 
     quote! {
         impl #impl_generics ::newnum::Round for #type_ident #ty_generics #where_clause {
             fn round(self) -> Self {
                 #round_output
             }
+            fn round_ties_even(self) -> Self {
+                #round_ties_even_output
+            }
             fn floor(self) -> Self {
                 #floor_output
             }
@@ -66,6 +88,13 @@ pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
             fn fract(self) -> Self {
                 #fract_output
             }
+
+            fn round_to_multiple(self, step: Self) -> Self {
+                #round_to_multiple_output
+            }
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                #round_to_decimals_output
+            }
         }
     }
     .into()
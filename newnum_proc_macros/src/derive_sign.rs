@@ -151,6 +151,26 @@ pub fn zero_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     .into()
 }
 
+pub fn const_zero_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let type_ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "ConstZero");
+
+    let zero_output = derive_eval_fields(&input, "ConstZero", "zero", |field_type| {
+        quote! {
+            <#field_type as ::newnum::ConstZero>::ZERO
+        }
+    });
+
+    quote! {
+        impl #impl_generics ::newnum::ConstZero for #type_ident #ty_generics #where_clause {
+            const ZERO: Self = #zero_output;
+        }
+    }
+    .into()
+}
+
 pub fn not_positive_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -1,7 +1,7 @@
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use quote::{quote, quote_spanned};
+use syn::{parse_macro_input, spanned::Spanned, DeriveInput};
 
-use crate::util::derive_split_generics;
+use crate::util::{derive_map_fields, derive_rhs_map_fields, derive_split_generics};
 
 pub fn whole_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -9,11 +9,28 @@ pub fn whole_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
     let type_ident = &input.ident;
     let (impl_generics, ty_generics, where_clause) = derive_split_generics(&input, "Whole");
 
+    let round_to_multiple_output =
+        derive_rhs_map_fields(&input, "Whole", "step", |field, step_field, field_type| {
+            quote_spanned! {
+                field_type.span() =>
+                <#field_type as ::newnum::Round>::round_to_multiple(#field, #step_field)
+            }
+        });
+    let round_to_decimals_output = derive_map_fields(&input, "Whole", |field, field_type| {
+        quote_spanned! {
+            field_type.span() =>
+            <#field_type as ::newnum::Round>::round_to_decimals(#field, decimals)
+        }
+    });
+
     quote! {
         impl #impl_generics ::newnum::Round for #type_ident #ty_generics #where_clause {
             fn round(self) -> Self {
                 self
             }
+            fn round_ties_even(self) -> Self {
+                self
+            }
             fn floor(self) -> Self {
                 self
             }
@@ -29,6 +46,13 @@ pub fn whole_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
             fn fract(self) -> Self {
                 <Self as ::newnum::Zero>::zero()
             }
+
+            fn round_to_multiple(self, step: Self) -> Self {
+                #round_to_multiple_output
+            }
+            fn round_to_decimals(self, decimals: i32) -> Self {
+                #round_to_decimals_output
+            }
         }
 
         impl #impl_generics ::newnum::WholeEquivalent for #type_ident #ty_generics #where_clause {
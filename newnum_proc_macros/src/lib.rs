@@ -1,8 +1,17 @@
 mod util;
 
 mod derive_abs_diff;
+mod derive_bytes;
+mod derive_checked;
+mod derive_complex;
+mod derive_div;
 mod derive_empty;
+mod derive_exp;
+mod derive_float_class;
+mod derive_log;
 mod derive_min_max;
+mod derive_one;
+mod derive_pow;
 mod derive_root;
 mod derive_round;
 mod derive_sign;
@@ -38,10 +47,10 @@ mod num;
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(Signed)]
-/// #[derive_bound(Signed; T: Signed)]
+/// #[derive_bound(Signed; T: Signed<SignMapped = Sign, BitSignMapped = BitSign, BoolMapped = bool>)]
 /// struct Fun<T>(T);
 /// ```
 #[proc_macro_derive(Signed, attributes(derive_bound))]
@@ -61,6 +70,10 @@ pub fn negative_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Toke
 pub fn zero_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_sign::zero_derive_macro(input)
 }
+#[proc_macro_derive(ConstZero, attributes(derive_bound, zero))]
+pub fn const_zero_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_sign::const_zero_derive_macro(input)
+}
 
 #[proc_macro_derive(NotPositive, attributes(derive_bound))]
 pub fn not_positive_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -130,38 +143,610 @@ pub fn fully_signed_derive_macro(input: proc_macro::TokenStream) -> proc_macro::
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
+///
+/// #[derive(AbsDiff)]
+/// #[derive_bound(AbsDiff; T: AbsDiff<Output = T>)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(AbsDiff, attributes(derive_bound))]
+pub fn abs_diff_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_abs_diff::abs_diff_derive_macro(input)
+}
+
+//
+//
+//
+// BYTES DERIVE MACROS
+//
+//
+//
+
+/// `ToBytes` derive macro.
+/// Concatenates the bytes of each field in field order,
+/// so `Bytes` is a byte array with the total length of the fields' bytes.
+/// For types with generic type / const parameters array lengths cannot depend on the parameters,
+/// so `Bytes` is a chain of `ConcatBytes` with the same layout instead.
+/// Throws a compile-time error for enums.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(ToBytes)]
+/// #[repr(C)]
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(ToBytes)]
+/// #[derive_bound(ToBytes; T: ToBytes)]
+/// struct Vec3<T> {
+///     x: T,
+///     y: T,
+///     z: T,
+/// }
+/// ```
+#[proc_macro_derive(ToBytes, attributes(derive_bound))]
+pub fn to_bytes_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_bytes::to_bytes_derive_macro(input)
+}
+
+/// `FromBytes` derive macro.
+/// Reads each field from the bytes in field order, following the layout of the `ToBytes` derive.
+/// Throws a compile-time error for enums.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(ToBytes, FromBytes)]
+/// #[repr(C)]
+/// struct Vec2 {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(ToBytes, FromBytes)]
+/// #[derive_bound(ToBytes; T: ToBytes)]
+/// #[derive_bound(FromBytes; T: FromBytes)]
+/// struct Vec3<T> {
+///     x: T,
+///     y: T,
+///     z: T,
+/// }
+/// ```
+#[proc_macro_derive(FromBytes, attributes(derive_bound))]
+pub fn from_bytes_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_bytes::from_bytes_derive_macro(input)
+}
+
+//
+//
+//
+// CHECKED DERIVE MACROS
+//
+//
+//
+
+/// `CheckedAdd` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedAdd` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Add;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedAdd)]
+/// #[derive_bound(CheckedAdd; T: CheckedAdd)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Add<Output = T>> Add for Fun<T> {
+///     type Output = Self;
+///
+///     fn add(self, rhs: Self) -> Self {
+///         Self(self.0 + rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(CheckedAdd, attributes(derive_bound))]
+pub fn checked_add_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_add_derive_macro(input)
+}
+
+/// `CheckedSub` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedSub` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Sub;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedSub)]
+/// #[derive_bound(CheckedSub; T: CheckedSub)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Sub<Output = T>> Sub for Fun<T> {
+///     type Output = Self;
+///
+///     fn sub(self, rhs: Self) -> Self {
+///         Self(self.0 - rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(CheckedSub, attributes(derive_bound))]
+pub fn checked_sub_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_sub_derive_macro(input)
+}
+
+/// `CheckedMul` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedMul` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Mul;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedMul)]
+/// #[derive_bound(CheckedMul; T: CheckedMul)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Mul<Output = T>> Mul for Fun<T> {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self {
+///         Self(self.0 * rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(CheckedMul, attributes(derive_bound))]
+pub fn checked_mul_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_mul_derive_macro(input)
+}
+
+/// `CheckedDiv` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedDiv` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Div;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedDiv)]
+/// #[derive_bound(CheckedDiv; T: CheckedDiv)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Div<Output = T>> Div for Fun<T> {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(self.0 / rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(CheckedDiv, attributes(derive_bound))]
+pub fn checked_div_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_div_derive_macro(input)
+}
+
+/// `CheckedRem` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedRem` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Rem;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedRem)]
+/// #[derive_bound(CheckedRem; T: CheckedRem)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Rem<Output = T>> Rem for Fun<T> {
+///     type Output = Self;
+///
+///     fn rem(self, rhs: Self) -> Self {
+///         Self(self.0 % rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(CheckedRem, attributes(derive_bound))]
+pub fn checked_rem_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_rem_derive_macro(input)
+}
+
+/// `CheckedNeg` derive macro.
+/// maps each field using the method,
+/// and returns `None` if any of the fields returns `None`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedNeg` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedNeg)]
+/// #[derive_bound(CheckedNeg; T: CheckedNeg)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(CheckedNeg, attributes(derive_bound))]
+pub fn checked_neg_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_neg_derive_macro(input)
+}
+
+/// `CheckedShl` derive macro.
+/// maps each field using the method and `rhs`,
+/// and returns `None` if any of the fields returns `None`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `CheckedShl` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(CheckedShl)]
+/// #[derive_bound(CheckedShl; T: CheckedShl)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(CheckedShl, attributes(derive_bound))]
+pub fn checked_shl_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_checked::checked_shl_derive_macro(input)
+}
+
+//
+//
+//
+// DIV DERIVE MACROS
+//
+//
+//
+
+/// `DivEuclid` derive macro.
+/// maps each field using the method.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `DivEuclid` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Div;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(DivEuclid)]
+/// #[derive_bound(DivEuclid; T: DivEuclid)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Div<Output = T>> Div for Fun<T> {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(self.0 / rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DivEuclid, attributes(derive_bound))]
+pub fn div_euclid_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_div::div_euclid_derive_macro(input)
+}
+
+/// `RemEuclid` derive macro.
+/// maps each field using the method.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `RemEuclid` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Rem;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(RemEuclid)]
+/// #[derive_bound(RemEuclid; T: RemEuclid)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Rem<Output = T>> Rem for Fun<T> {
+///     type Output = Self;
+///
+///     fn rem(self, rhs: Self) -> Self {
+///         Self(self.0 % rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(RemEuclid, attributes(derive_bound))]
+pub fn rem_euclid_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_div::rem_euclid_derive_macro(input)
+}
+
+/// `DivFloor` derive macro.
+/// maps each field using the method.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `DivFloor` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Div;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(DivFloor)]
+/// #[derive_bound(DivFloor; T: DivFloor)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Div<Output = T>> Div for Fun<T> {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(self.0 / rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DivFloor, attributes(derive_bound))]
+pub fn div_floor_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_div::div_floor_derive_macro(input)
+}
+
+/// `DivCeil` derive macro.
+/// maps each field using the method.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `DivCeil` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::Div;
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(DivCeil)]
+/// #[derive_bound(DivCeil; T: DivCeil)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Div<Output = T>> Div for Fun<T> {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(self.0 / rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DivCeil, attributes(derive_bound))]
+pub fn div_ceil_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_div::div_ceil_derive_macro(input)
+}
+
+/// `DivRem` derive macro.
+/// computes `div_rem` for each field,
+/// and returns the quotients and the remainders as two values of the type.
+/// Throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `DivRem` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use std::ops::{Div, Rem};
+///
+/// use newnum::{*, derive::*};
+///
+/// #[derive(DivRem)]
+/// #[derive_bound(DivRem; T: DivRem)]
+/// struct Fun<T>(T);
+///
+/// impl<T: Div<Output = T>> Div for Fun<T> {
+///     type Output = Self;
+///
+///     fn div(self, rhs: Self) -> Self {
+///         Self(self.0 / rhs.0)
+///     }
+/// }
+///
+/// impl<T: Rem<Output = T>> Rem for Fun<T> {
+///     type Output = Self;
+///
+///     fn rem(self, rhs: Self) -> Self {
+///         Self(self.0 % rhs.0)
+///     }
+/// }
+/// ```
+#[proc_macro_derive(DivRem, attributes(derive_bound))]
+pub fn div_rem_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_div::div_rem_derive_macro(input)
+}
+
+//
+//
+//
+// MIN-MAX DERIVE MACROS
+//
+//
+//
+
+/// `MinMax` derive macro.
+/// for each method,
+/// the derive implementation maps each field using the method.
+/// Throws a compile-time error for enums unless you use the `flat_minmax` attribute.
+///
+/// The `flat_minmax` attribute makes the derived implementation use `PartialOrd` for the entire type instead of `MinMax` for each field,
+/// so each field won't be minmaxed seperately.
+/// `flat_minmax` works for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `MinMax` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(MinMax)]
+/// #[derive_bound(MinMax; T: MinMax)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(MinMax, attributes(derive_bound, flat_minmax))]
+pub fn min_max_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_min_max::min_max_derive_macro(input)
+}
+
+//
+//
+//
+// ONE DERIVE MACROS
+//
+//
+//
+
+/// `One` derive macro.
+/// Initializes the `one` of each field,
+/// and for enum requires a single variant to be labeled as `one`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `One` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
 ///
-/// #[derive(AbsDiff)]
-/// #[derive_bound(AbsDiff; T: AbsDiff<Output = T>)]
+/// #[derive(One)]
+/// #[derive_bound(One; T: One)]
 /// struct Fun<T>(T);
 /// ```
-#[proc_macro_derive(AbsDiff, attributes(derive_bound))]
-pub fn abs_diff_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    derive_abs_diff::abs_diff_derive_macro(input)
+#[proc_macro_derive(One, attributes(derive_bound, one))]
+pub fn one_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_one::one_derive_macro(input)
 }
 
-//
-//
-//
-// MIN-MAX DERIVE MACROS
-//
-//
-//
-
-/// `MinMax` derive macro.
-/// for each method,
-/// the derive implementation maps each field using the method.
-/// Throws a compile-time error for enums unless you use the `flat_minmax` attribute.
-///
-/// The `flat_minmax` attribute makes the derived implementation use `PartialOrd` for the entire type instead of `MinMax` for each field,
-/// so each field won't be minmaxed seperately.
-/// `flat_minmax` works for enums.
+/// `ConstOne` derive macro.
+/// Initializes each field with its `ONE` constant,
+/// and for enum requires a single variant to be labeled as `one`.
 ///
 /// ### Generics
 ///
 /// For types with generic parameters,
-/// `MinMax` will be implemented with no additional trait-bounds.
+/// `ConstOne` will be implemented with no additional trait-bounds.
 ///
 /// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
 /// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
@@ -169,15 +754,16 @@ pub fn abs_diff_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Toke
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
-/// #[derive(MinMax)]
-/// #[derive_bound(MinMax; T: MinMax)]
+/// #[derive(One, ConstOne)]
+/// #[derive_bound(One; T: One)]
+/// #[derive_bound(ConstOne; T: ConstOne)]
 /// struct Fun<T>(T);
 /// ```
-#[proc_macro_derive(MinMax, attributes(derive_bound, flat_minmax))]
-pub fn min_max_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    derive_min_max::min_max_derive_macro(input)
+#[proc_macro_derive(ConstOne, attributes(derive_bound, one))]
+pub fn const_one_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_one::const_one_derive_macro(input)
 }
 
 //
@@ -203,7 +789,7 @@ pub fn min_max_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Token
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(TypeMin)]
 /// #[derive_bound(TypeMin; T: TypeMin)]
@@ -229,7 +815,7 @@ pub fn type_min_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Toke
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(TypeMax)]
 /// #[derive_bound(TypeMax; T: TypeMax)]
@@ -263,11 +849,10 @@ pub fn type_max_derive_macro(input: proc_macro::TokenStream) -> proc_macro::Toke
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
-/// #[derive(Round)]
-/// #[derive_bound(Round; T: Round)]
-/// struct Fun<T>(T);
+/// #[derive(Round, Zero, Signed)]
+/// struct Meters(f32);
 /// ```
 #[proc_macro_derive(Round, attributes(derive_bound))]
 pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -281,6 +866,7 @@ pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 ///
 /// This derive macro also derives `Round`,
 /// with an implementation that doesn't do anything to round the values because they are already whole.
+/// `round_to_multiple` and `round_to_decimals` do change whole values, so they map each field using the method.
 ///
 /// ### Generics
 ///
@@ -290,18 +876,15 @@ pub fn round_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 /// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
 /// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
 ///
-/// * If `derive_bound` is used, the `Round` trait will not be derived automatically,
-///   and will need to be seperately derived using the `Round` derive macro.
+/// * The `Whole` bounds are used for the derived `Round` implementation too.
 ///
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
-/// #[derive(Whole, Round)]
-/// #[derive_bound(Whole; T: Whole)]
-/// #[derive_bound(Round; T: Round)]
-/// struct Fun<T>(T);
+/// #[derive(Whole, Zero, Signed)]
+/// struct Count(u32);
 /// ```
 #[proc_macro_derive(Whole, attributes(derive_bound))]
 pub fn whole_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -333,7 +916,7 @@ pub fn whole_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(TruncRoot)]
 /// #[derive_bound(TruncRoot; T: TruncRoot)]
@@ -361,9 +944,10 @@ pub fn trunc_root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::To
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
-/// #[derive(Root)]
+/// #[derive(TruncRoot, Root)]
+/// #[derive_bound(TruncRoot; T: TruncRoot)]
 /// #[derive_bound(Root; T: Root)]
 /// struct Fun<T>(T);
 /// ```
@@ -372,6 +956,220 @@ pub fn root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
     derive_root::root_derive_macro(input)
 }
 
+/// `Hypot` derive macro.
+/// maps each field of `self` with the matching field of `other` using the method.
+/// Throws a compile-time error for enums.
+///
+/// This derived implementation matches the logical `Mul` derived implementation that multiplies each field seperately.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Hypot` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Hypot)]
+/// #[derive_bound(Hypot; T: Hypot)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Hypot, attributes(derive_bound))]
+pub fn hypot_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_root::hypot_derive_macro(input)
+}
+
+//
+//
+//
+// POW, EXP, LOG DERIVE MACROS
+//
+//
+//
+
+/// `Pow` derive macro.
+/// the derive implementation maps each field using the method and the same `exp` value.
+///
+/// This derived implementation matches the logical `Mul` derived implementation that multiplies each field seperately.
+///
+/// The derived implementation is generic over the exponent type, which is named `PowExp`,
+/// and is bounded so that each field type implements `Pow<PowExp>`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Pow` will be implemented with no additional trait-bounds other than the field bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Pow)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Pow, attributes(derive_bound))]
+pub fn pow_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_pow::pow_derive_macro(input)
+}
+
+/// `Exp` derive macro.
+/// for each method,
+/// the derive implementation maps each field using the method.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Exp` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Exp)]
+/// #[derive_bound(Exp; T: Exp)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Exp, attributes(derive_bound))]
+pub fn exp_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_exp::exp_derive_macro(input)
+}
+
+/// `Log` derive macro.
+/// for each method,
+/// the derive implementation maps each field using the method.
+///
+/// `log` maps each field of `self` with the matching field of `base`,
+/// so this macro throws a compile-time error for enums.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Log` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Log)]
+/// #[derive_bound(Log; T: Log)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Log, attributes(derive_bound))]
+pub fn log_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_log::log_derive_macro(input)
+}
+
+/// `ILog` derive macro.
+/// `ILog`'s methods return `u32` and not `Self`,
+/// so this macro expects the type to only have one field,
+/// and redirects the trait methods to the field as `ILog`.
+///
+/// For enums, `ilog` and `checked_ilog` expect `self` and `base` to be the same variant,
+/// and return `None` / panic otherwise.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `ILog` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(ILog)]
+/// #[derive_bound(ILog; T: ILog)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(ILog, attributes(derive_bound))]
+pub fn ilog_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_log::ilog_derive_macro(input)
+}
+
+//
+//
+//
+// FLOAT-CLASS, FLOAT-CONSTS DERIVE MACROS
+//
+//
+//
+
+/// `FloatClass` derive macro.
+/// `FloatClass` is a trait with methods that classify floats.
+/// This macro expects the type to only have one field,
+/// and redirects the trait methods to the field as `FloatClass`.
+///
+/// `FloatClass`'s methods return `Self::FloatBoolMapped` and `Self::CategoryMapped`.
+/// This macro expects the field's mapped types to be `bool` and `FpCategory`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `FloatClass` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(FloatClass)]
+/// #[derive_bound(FloatClass; T: FloatClass<FloatBoolMapped = bool, CategoryMapped = std::num::FpCategory>)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(FloatClass, attributes(derive_bound))]
+pub fn float_class_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_float_class::float_class_derive_macro(input)
+}
+
+/// `FloatConsts` derive macro.
+/// Initializes each constant of each field,
+/// and for enum requires a single variant to be labeled as `float_consts`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `FloatConsts` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(FloatConsts)]
+/// #[derive_bound(FloatConsts; T: FloatConsts)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(FloatConsts, attributes(derive_bound, float_consts))]
+pub fn float_consts_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_float_class::float_consts_derive_macro(input)
+}
+
 //
 //
 //
@@ -398,10 +1196,10 @@ pub fn root_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(Trig)]
-/// #[derive_bound(Trig; T: Trig)]
+/// #[derive_bound(Trig; T: Trig<Output = T>)]
 /// struct Fun<T>(T);
 /// ```
 #[proc_macro_derive(Trig, attributes(derive_bound))]
@@ -427,10 +1225,10 @@ pub fn trig_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(ATrig)]
-/// #[derive_bound(ATrig; T: ATrig)]
+/// #[derive_bound(ATrig; T: ATrig<Output = T>)]
 /// struct Fun<T>(T);
 /// ```
 #[proc_macro_derive(ATrig, attributes(derive_bound))]
@@ -456,10 +1254,10 @@ pub fn atrig_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(Hyper)]
-/// #[derive_bound(Hyper; T: Hyper)]
+/// #[derive_bound(Hyper; T: Hyper<Output = T>)]
 /// struct Fun<T>(T);
 /// ```
 #[proc_macro_derive(Hyper, attributes(derive_bound))]
@@ -485,10 +1283,10 @@ pub fn hyper_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenSt
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// #[derive(AHyper)]
-/// #[derive_bound(AHyper; T: AHyper)]
+/// #[derive_bound(AHyper; T: AHyper<Output = T>)]
 /// struct Fun<T>(T);
 /// ```
 #[proc_macro_derive(AHyper, attributes(derive_bound))]
@@ -496,6 +1294,97 @@ pub fn ahyper_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenS
     derive_trig::ahyper_derive_macro(input)
 }
 
+//
+//
+//
+// COMPLEX DERIVE MACROS
+//
+//
+//
+
+/// `Conj` derive macro.
+/// the derive implementation maps each field using the method.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Conj` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Conj)]
+/// #[derive_bound(Conj; T: Conj)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Conj, attributes(derive_bound))]
+pub fn conj_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_complex::conj_derive_macro(input)
+}
+
+/// `Norm` derive macro.
+/// for each method,
+/// the derive implementation maps each field using the method.
+///
+/// `Norm`'s methods return `Self::Output`.
+/// This macro expects that for each field: `T::Output = T`,
+/// so the derived `Output` is `Self` (the norm of each element and not of the whole container).
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Norm` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Norm)]
+/// #[derive_bound(Norm; T: Norm<Output = T>)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Norm, attributes(derive_bound))]
+pub fn norm_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_complex::norm_derive_macro(input)
+}
+
+/// `Arg` derive macro.
+/// the derive implementation maps each field using the method.
+///
+/// `Arg`'s method returns `Self::Output`.
+/// This macro expects that for each field: `T::Output = T`.
+///
+/// ### Generics
+///
+/// For types with generic parameters,
+/// `Arg` will be implemented with no additional trait-bounds.
+///
+/// To add bounds to the derive, use the `derive_bound` attribute which follows this syntax:
+/// `#[derive_bound(<trait-ident>; <where-predicate>, ...)]`.
+///
+/// ### Example
+///
+/// ```
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Arg)]
+/// #[derive_bound(Arg; T: Arg<Output = T>)]
+/// struct Fun<T>(T);
+/// ```
+#[proc_macro_derive(Arg, attributes(derive_bound))]
+pub fn arg_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    derive_complex::arg_derive_macro(input)
+}
+
 //
 //
 //
@@ -519,11 +1408,55 @@ pub fn ahyper_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenS
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use std::ops::*;
 ///
-/// #[derive(Num)]
-/// #[derive_bound(Num; T: Num)]
-/// struct Fun<T>(T);
+/// use newnum::{*, derive::*};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// #[derive(Num, AbsDiff, MinMax, TruncRoot, Pow, Round, Zero, Signed)]
+/// struct Meters(f32);
+///
+/// impl FromIntLiteral for Meters {
+///     const MIN_LITERAL: i128 = f32::MIN_LITERAL;
+///     const MAX_LITERAL: i128 = f32::MAX_LITERAL;
+///
+///     unsafe fn from_int_literal(value: i128) -> Self {
+///         Self(f32::from_int_literal(value))
+///     }
+///     unsafe fn approx_from_int_literal(value: i128) -> Self {
+///         Self(f32::approx_from_int_literal(value))
+///     }
+/// }
+///
+/// // `Add`, `Sub`, `Mul`, `Div`, `Rem` and their assign traits map the field.
+/// # macro_rules! op_impl {
+/// #     ($trait:ident($fn:ident), $assign_trait:ident($assign_fn:ident)) => {
+/// #         impl $trait for Meters {
+/// #             type Output = Self;
+/// #
+/// #             fn $fn(self, rhs: Self) -> Self {
+/// #                 Self($trait::$fn(self.0, rhs.0))
+/// #             }
+/// #         }
+/// #
+/// #         impl $assign_trait for Meters {
+/// #             fn $assign_fn(&mut self, rhs: Self) {
+/// #                 $assign_trait::$assign_fn(&mut self.0, rhs.0)
+/// #             }
+/// #         }
+/// #     };
+/// # }
+/// # op_impl!(Add(add), AddAssign(add_assign));
+/// # op_impl!(Sub(sub), SubAssign(sub_assign));
+/// # op_impl!(Mul(mul), MulAssign(mul_assign));
+/// # op_impl!(Div(div), DivAssign(div_assign));
+/// # op_impl!(Rem(rem), RemAssign(rem_assign));
+///
+/// fn double<T: Num>(value: T) -> T {
+///     value * num!(2)
+/// }
+///
+/// assert_eq!(double(Meters(1.5)), Meters(3.0));
 /// ```
 #[proc_macro_derive(Num, attributes(derive_bound))]
 pub fn num_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -541,6 +1474,8 @@ pub fn num_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// Converts a numeric literal into a `Num` type, generating a compile-time error if the literal is out of range for the type.
 ///
 /// * the type doesn't have to implement `Num`, but it must implement `FromIntLiteral` and `FromFloatLiteral` for float literal support.
+/// * integer literals beyond the range of `i128` require `FromBigIntLiteral`, which is implemented by unbounded types.
+/// * float literals are converted with `FromFloatLiteral::from_decimal_literal` which also receives the exact decimal digits of the literal.
 ///
 /// ### Syntax
 ///
@@ -549,7 +1484,7 @@ pub fn num_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// fn inc(value: &mut impl Num) {
 ///    *value += num!(1)
@@ -563,7 +1498,7 @@ pub fn num_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStre
 ///
 /// Example:
 ///
-///```
+///```compile_fail
 /// use newnum::*;
 ///
 /// fn add_alot(value: &mut impl Num) {
@@ -594,7 +1529,7 @@ pub fn num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// fn example<T: Float>() -> T {
 ///     num_approx!(16_777_217)
@@ -608,7 +1543,7 @@ pub fn num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// Example:
 ///
-///```
+///```compile_fail
 /// use newnum::*;
 ///
 /// fn add_alot(value: &mut impl Num) {
@@ -640,7 +1575,7 @@ pub fn num_approx(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// fn inc(value: &mut impl Num) {
 ///    *value += num!(1)
@@ -654,7 +1589,7 @@ pub fn num_approx(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// Example:
 ///
-///```
+///```compile_fail
 /// use newnum::*;
 ///
 /// fn add_alot(value: &mut impl Num) {
@@ -687,7 +1622,7 @@ pub fn internal_num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// ### Example
 ///
 /// ```
-/// use newnum::*;
+/// use newnum::{*, derive::*};
 ///
 /// fn example<T: Float>() -> T {
 ///     num_approx!(16_777_217)
@@ -701,7 +1636,7 @@ pub fn internal_num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// Example:
 ///
-///```
+///```compile_fail
 /// use newnum::*;
 ///
 /// fn add_alot(value: &mut impl Num) {
@@ -1,12 +1,13 @@
 use derive_syn_parse::Parse;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Lit, Token};
+use syn::{parse_macro_input, Lit, LitFloat, LitInt, Token};
 
 pub fn num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     num_macro_helper(
         "from_int_literal",
         "from_float_literal",
+        true,
         quote! { ::newnum },
         input,
     )
@@ -16,6 +17,7 @@ pub fn num_approx(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     num_macro_helper(
         "approx_from_int_literal",
         "approx_from_float_literal",
+        false,
         quote! { ::newnum },
         input,
     )
@@ -25,6 +27,7 @@ pub fn internal_num(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     num_macro_helper(
         "from_int_literal",
         "from_float_literal",
+        true,
         quote! { crate },
         input,
     )
@@ -34,6 +37,7 @@ pub fn internal_num_approx(input: proc_macro::TokenStream) -> proc_macro::TokenS
     num_macro_helper(
         "approx_from_int_literal",
         "approx_from_float_literal",
+        false,
         quote! { crate },
         input,
     )
@@ -42,6 +46,7 @@ pub fn internal_num_approx(input: proc_macro::TokenStream) -> proc_macro::TokenS
 fn num_macro_helper(
     int_fn_ident: &str,
     float_fn_ident: &str,
+    exact_decimals: bool,
     crate_path: TokenStream,
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
@@ -56,14 +61,46 @@ fn num_macro_helper(
 
     let Input { neg, literal, ty } = parse_macro_input!(input as Input);
 
-    let (literal_ty, from_trait, from_fn) = if let Lit::Float(_) = literal {
-        (quote! { f64 }, quote! { FromFloatLiteral }, float_fn_ident)
-    } else {
-        (quote! { i128 }, quote! { FromIntLiteral }, int_fn_ident)
-    };
+    let ty = ty.unwrap_or_else(|| quote! { _});
+
+    if let Lit::Int(literal) = &literal {
+        if let Some(magnitude) = big_int_literal_magnitude(literal, neg.is_some()) {
+            return big_int_literal(neg.is_some(), magnitude, crate_path, ty);
+        }
+    }
+
+    let (literal_ty, zero, from_trait, from_fn, min_const, max_const) =
+        if let Lit::Float(_) = literal {
+            (
+                quote! { f64 },
+                quote! { 0.0 },
+                quote! { FromFloatLiteral },
+                float_fn_ident,
+                quote! { MIN_FLOAT_LITERAL },
+                quote! { MAX_FLOAT_LITERAL },
+            )
+        } else {
+            (
+                quote! { i128 },
+                quote! { 0 },
+                quote! { FromIntLiteral },
+                int_fn_ident,
+                quote! { MIN_LITERAL },
+                quote! { MAX_LITERAL },
+            )
+        };
 
     let from_fn = format_ident!("{from_fn}");
-    let ty = ty.unwrap_or_else(|| quote! { _});
+
+    // Exact float literals also pass their decimal digits, so types like `Ratio` don't have to go through `f64`.
+    let from_call = match &literal {
+        Lit::Float(literal) if exact_decimals => {
+            let (digits, exponent) = decimal_literal_parts(literal, neg.is_some());
+
+            quote! { from_decimal_literal(MACRO_INPUT, #digits, #exponent) }
+        }
+        _ => quote! { #from_fn(MACRO_INPUT) },
+    };
 
     quote! {
         {
@@ -72,17 +109,21 @@ fn num_macro_helper(
             {
                 fn num_macro_fn<NumMacroType: #crate_path::#from_trait>() -> NumMacroType {
                     if const {
-                        if MACRO_INPUT < <NumMacroType as #crate_path::FromIntLiteral>::MIN_LITERAL as #literal_ty {
+                        if MACRO_INPUT < <NumMacroType as #crate_path::#from_trait>::#min_const {
                             panic!("literal out of range")
                         }
 
-                        if MACRO_INPUT > <NumMacroType as #crate_path::FromIntLiteral>::MAX_LITERAL as #literal_ty {
+                        if MACRO_INPUT > <NumMacroType as #crate_path::#from_trait>::#max_const {
                             panic!("literal out of range")
                         }
 
+                        if MACRO_INPUT == #zero && !<NumMacroType as #crate_path::FromIntLiteral>::ZERO_LITERAL {
+                            panic!("zero literal for a non-zero type")
+                        }
+
                         true
                     } {
-                        unsafe { <NumMacroType as #crate_path::#from_trait>::#from_fn(MACRO_INPUT) }
+                        unsafe { <NumMacroType as #crate_path::#from_trait>::#from_call }
                     } else {
                         unreachable!()
                     }
@@ -94,3 +135,120 @@ fn num_macro_helper(
     }
     .into()
 }
+
+/// Returns the magnitude of an integer literal as little-endian `u64` limbs,
+/// or `None` if the literal is in the range of `i128`.
+fn big_int_literal_magnitude(literal: &LitInt, negative: bool) -> Option<Vec<u64>> {
+    let mut magnitude = Vec::<u64>::new();
+    for digit in literal.base10_digits().bytes() {
+        let mut carry = (digit - b'0') as u128;
+        for limb in &mut magnitude {
+            let value = *limb as u128 * 10 + carry;
+            *limb = value as u64;
+            carry = value >> 64;
+        }
+
+        if carry != 0 {
+            magnitude.push(carry as u64);
+        }
+    }
+
+    let magnitude_u128 = match magnitude.as_slice() {
+        [] => 0,
+        [low] => *low as u128,
+        [low, high] => (*high as u128) << 64 | *low as u128,
+        _ => return Some(magnitude),
+    };
+
+    if magnitude_u128 <= i128::MAX as u128 || negative && magnitude_u128 == i128::MIN.unsigned_abs()
+    {
+        None
+    } else {
+        Some(magnitude)
+    }
+}
+
+/// Returns the exact value of a float literal as `(digits, exponent)` where `value = digits * 10^exponent`.
+/// Literals with more than 38 significant digits are rounded to 38 digits so that `digits` fits in `i128`.
+fn decimal_literal_parts(literal: &LitFloat, negative: bool) -> (i128, i32) {
+    const MAX_DIGITS: usize = 38;
+
+    let text = literal.base10_digits();
+    let (mantissa, mut exponent) = match text.find(['e', 'E']) {
+        Some(index) => (
+            &text[..index],
+            text[index + 1..]
+                .parse::<i32>()
+                .expect("float literal exponent out of range"),
+        ),
+        None => (text, 0),
+    };
+
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    exponent = exponent.saturating_sub(fraction.len() as i32);
+
+    let significant_digits = whole
+        .bytes()
+        .chain(fraction.bytes())
+        .skip_while(|&digit| digit == b'0')
+        .collect::<Vec<u8>>();
+
+    let mut digits = 0_i128;
+    for &digit in significant_digits.iter().take(MAX_DIGITS) {
+        digits = digits * 10 + (digit - b'0') as i128;
+    }
+
+    if let Some(&first_dropped) = significant_digits.get(MAX_DIGITS) {
+        exponent = exponent.saturating_add((significant_digits.len() - MAX_DIGITS) as i32);
+
+        // `10^38 - 1 + 1` still fits in `i128`.
+        if first_dropped >= b'5' {
+            digits += 1;
+        }
+    }
+
+    if negative {
+        (-digits, exponent)
+    } else {
+        (digits, exponent)
+    }
+}
+
+fn big_int_literal(
+    negative: bool,
+    magnitude: Vec<u64>,
+    crate_path: TokenStream,
+    ty: TokenStream,
+) -> proc_macro::TokenStream {
+    let range_check = if negative {
+        quote! { <NumMacroType as #crate_path::FromIntLiteral>::MIN_LITERAL != i128::MIN }
+    } else {
+        quote! { <NumMacroType as #crate_path::FromIntLiteral>::MAX_LITERAL != i128::MAX }
+    };
+
+    quote! {
+        {
+            fn num_macro_fn<NumMacroType: #crate_path::FromBigIntLiteral>() -> NumMacroType {
+                if const {
+                    if #range_check {
+                        panic!("literal out of range")
+                    }
+
+                    true
+                } {
+                    unsafe {
+                        <NumMacroType as #crate_path::FromBigIntLiteral>::from_big_int_literal(
+                            #negative,
+                            &[#(#magnitude),*],
+                        )
+                    }
+                } else {
+                    unreachable!()
+                }
+            }
+
+            num_macro_fn::<#ty>()
+        }
+    }
+    .into()
+}
@@ -23,7 +23,7 @@ pub fn derive_split_generics<'a>(
                     bounds,
                 } = attr.parse_args().ok()?;
 
-                if target_derive.to_string() == trait_ident {
+                if target_derive == trait_ident {
                     Some(bounds.into_iter())
                 } else {
                     None
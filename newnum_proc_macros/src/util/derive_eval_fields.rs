@@ -36,8 +36,7 @@ pub fn derive_eval_fields(
                     selected_variants.get(2).span(),
                     format!("`{variant_attr}` must be used on exactly one variant"),
                 )
-                .to_compile_error()
-                .into();
+                .to_compile_error();
             }
 
             let variant = selected_variants.into_iter().next().unwrap();
@@ -57,7 +56,6 @@ pub fn derive_eval_fields(
             Span::call_site(),
             format!("`{trait_ident}` cannot be derived for unions"),
         )
-        .to_compile_error()
-        .into(),
+        .to_compile_error(),
     }
 }
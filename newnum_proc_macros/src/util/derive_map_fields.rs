@@ -84,7 +84,6 @@ pub fn derive_map_fields(
             Span::call_site(),
             format!("`{trait_ident}` cannot be derived for unions"),
         )
-        .to_compile_error()
-        .into(),
+        .to_compile_error(),
     }
 }
@@ -35,13 +35,11 @@ pub fn derive_rhs_map_fields(
             Span::call_site(),
             format!("`{trait_ident}` cannot be derived for enums"),
         )
-        .to_compile_error()
-        .into(),
+        .to_compile_error(),
         Data::Union(_) => Error::new(
             Span::call_site(),
             format!("`{trait_ident}` cannot be derived for unions"),
         )
-        .to_compile_error()
-        .into(),
+        .to_compile_error(),
     }
 }
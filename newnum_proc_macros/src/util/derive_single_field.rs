@@ -5,6 +5,23 @@ use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Type};
 pub fn derive_map_single_field_ref(
     input: &DeriveInput,
     trait_ident: &str,
+    map: impl FnMut(&TokenStream, &Type) -> TokenStream,
+) -> TokenStream {
+    derive_map_single_field_helper(input, trait_ident, quote! { & }, map)
+}
+
+pub fn derive_map_single_field(
+    input: &DeriveInput,
+    trait_ident: &str,
+    map: impl FnMut(&TokenStream, &Type) -> TokenStream,
+) -> TokenStream {
+    derive_map_single_field_helper(input, trait_ident, quote! {}, map)
+}
+
+fn derive_map_single_field_helper(
+    input: &DeriveInput,
+    trait_ident: &str,
+    field_prefix: TokenStream,
     mut map: impl FnMut(&TokenStream, &Type) -> TokenStream,
 ) -> TokenStream {
     match &input.data {
@@ -20,7 +37,7 @@ pub fn derive_map_single_field_ref(
             let field = data.fields.iter().next().unwrap();
             let member = data.fields.members().next().unwrap();
 
-            map(&quote! { &self.#member }, &field.ty)
+            map(&quote! { #field_prefix self.#member }, &field.ty)
         }
         Data::Enum(data) => {
             for variant in &data.variants {
@@ -63,12 +80,10 @@ pub fn derive_map_single_field_ref(
                 )*}
             }
         }
-        Data::Union(_) => {
-            return Error::new(
-                input.span(),
-                format!("`{trait_ident}` cannot be derived for unions",),
-            )
-            .to_compile_error();
-        }
+        Data::Union(_) => Error::new(
+            input.span(),
+            format!("`{trait_ident}` cannot be derived for unions",),
+        )
+        .to_compile_error(),
     }
 }